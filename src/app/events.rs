@@ -1,5 +1,6 @@
 //! Event types for daemon communication.
 
+use crate::ipc::LaunchMode;
 use tokio::sync::oneshot;
 
 /// Response type for IPC operations.
@@ -19,6 +20,7 @@ pub enum DaemonEvent {
 
     /// Show the launcher window
     Show {
+        mode: LaunchMode,
         response_tx: oneshot::Sender<IpcResponse>,
     },
 
@@ -29,6 +31,7 @@ pub enum DaemonEvent {
 
     /// Toggle the launcher window visibility
     Toggle {
+        mode: LaunchMode,
         response_tx: oneshot::Sender<IpcResponse>,
     },
 
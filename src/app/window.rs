@@ -1,5 +1,6 @@
 use crate::app::{DaemonEvent, DaemonEventSender, WindowEvent};
 use crate::compositor::Compositor;
+use crate::ipc::LaunchMode;
 use crate::items::{ApplicationItem, ListItem, WindowItem};
 use crate::ui::LauncherView;
 use gpui::{
@@ -22,8 +23,13 @@ pub fn create_and_show_window(
     applications: Vec<ApplicationItem>,
     compositor: Arc<dyn Compositor>,
     event_tx: DaemonEventSender,
+    mode: LaunchMode,
     cx: &mut App,
 ) -> anyhow::Result<LauncherWindow> {
+    // Pick up light/dark changes (e.g. of the system preference in auto mode)
+    crate::ui::theme::sync_theme_from_config();
+    crate::ui::theme::apply_component_theme(cx);
+
     // Fetch open windows from compositor
     let windows = fetch_windows(compositor.as_ref());
 
@@ -59,7 +65,9 @@ pub fn create_and_show_window(
         kind: WindowKind::LayerShell(LayerShellOptions {
             namespace: "zlaunch".to_string(),
             layer: Layer::Overlay,
-            // Anchor to all edges = fullscreen overlay
+            // Anchor to all edges = fullscreen overlay, the panel is placed
+            // inside it according to `window_anchor`. No output is set, so the
+            // compositor opens it on the focused monitor.
             anchor: Anchor::TOP | Anchor::BOTTOM | Anchor::LEFT | Anchor::RIGHT,
             // Exclusive keyboard so typing works immediately
             keyboard_interactivity: KeyboardInteractivity::Exclusive,
@@ -78,9 +86,10 @@ pub fn create_and_show_window(
         };
         let view = cx.new(|cx| LauncherView::new(items, compositor.clone(), on_hide, window, cx));
 
-        // Auto-focus the list/search input
+        // Auto-focus the list/search input, then open the requested view
         view.update(cx, |launcher: &mut LauncherView, cx| {
             launcher.focus(window, cx);
+            launcher.open_mode(mode, window, cx);
         });
 
         // Store the view entity for later access
@@ -103,10 +112,19 @@ pub fn create_and_show_window(
     })
 }
 
+/// Switch an open launcher window to the given view.
+pub fn open_mode(launcher_window: &LauncherWindow, mode: LaunchMode, cx: &mut App) {
+    let view = launcher_window.launcher_view.clone();
+    let _ = launcher_window.handle.update(cx, |_root, window, cx| {
+        view.update(cx, |launcher, cx| launcher.open_mode(mode, window, cx));
+    });
+}
+
 pub fn close_window(handle: &WindowHandle<Root>, cx: &mut App) {
     let _ = handle.update(cx, |_root, window, _cx| {
         window.remove_window();
     });
+    crate::ui::views::clipboard_rendering::release_image_previews(cx);
 }
 
 /// Fetch open windows from the compositor and convert to WindowItems.
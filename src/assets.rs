@@ -65,6 +65,8 @@ pub enum PhosphorIcon {
     File,
     FileText,
     FileImage,
+    Folder,
+    AppWindow,
     Image,
     MagnifyingGlass,
     Globe,
@@ -72,6 +74,12 @@ pub enum PhosphorIcon {
     YoutubeLogo,
     Brain,
     Palette,
+    PushPin,
+    Link,
+    Code,
+    WifiHigh,
+    MapPin,
+    User,
 }
 
 impl PhosphorIcon {
@@ -90,6 +98,8 @@ impl PhosphorIcon {
             Self::File => "icons/file-bold.svg",
             Self::FileText => "icons/file-text-bold.svg",
             Self::FileImage => "icons/file-image-bold.svg",
+            Self::Folder => "icons/folder-bold.svg",
+            Self::AppWindow => "icons/app-window-bold.svg",
             Self::Image => "icons/image-bold.svg",
             Self::MagnifyingGlass => "icons/magnifying-glass-bold.svg",
             Self::Globe => "icons/globe-bold.svg",
@@ -97,6 +107,12 @@ impl PhosphorIcon {
             Self::YoutubeLogo => "icons/youtube-logo-bold.svg",
             Self::Brain => "icons/brain-bold.svg",
             Self::Palette => "icons/palette-bold.svg",
+            Self::PushPin => "icons/push-pin-bold.svg",
+            Self::Link => "icons/link-bold.svg",
+            Self::Code => "icons/code-bold.svg",
+            Self::WifiHigh => "icons/wifi-high-bold.svg",
+            Self::MapPin => "icons/map-pin-bold.svg",
+            Self::User => "icons/user-bold.svg",
         }
     }
 
@@ -115,6 +131,8 @@ impl PhosphorIcon {
             "file" => Some(Self::File),
             "file-text" => Some(Self::FileText),
             "file-image" => Some(Self::FileImage),
+            "folder" => Some(Self::Folder),
+            "app-window" => Some(Self::AppWindow),
             "image" => Some(Self::Image),
             "magnifying-glass" => Some(Self::MagnifyingGlass),
             "globe" => Some(Self::Globe),
@@ -122,6 +140,9 @@ impl PhosphorIcon {
             "youtube-logo" => Some(Self::YoutubeLogo),
             "brain" => Some(Self::Brain),
             "palette" => Some(Self::Palette),
+            "push-pin" => Some(Self::PushPin),
+            "link" => Some(Self::Link),
+            "code" => Some(Self::Code),
             _ => None,
         }
     }
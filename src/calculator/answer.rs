@@ -0,0 +1,94 @@
+//! The previous result, referenced as `ans`.
+//!
+//! The answer is kept for the lifetime of the daemon and updated whenever a
+//! calculator result is copied.
+
+use crate::items::CalculatorItem;
+use std::sync::Mutex;
+
+/// The keyword standing for the previous result.
+const ANSWER_KEYWORD: &str = "ans";
+
+static LAST_ANSWER: Mutex<Option<String>> = Mutex::new(None);
+
+/// Remember a copied calculator result as the answer for `ans`.
+///
+/// The main result is remembered whichever representation was copied, so
+/// `ans` stays a plain number.
+pub fn remember_answer(item: &CalculatorItem) {
+    if item.is_error {
+        return;
+    }
+    if let Some(result) = &item.clipboard_result {
+        *LAST_ANSWER.lock().unwrap() = Some(result.clone());
+    }
+}
+
+/// Check whether the expression uses `ans` and there is an answer to use.
+pub fn has_answer_reference(expression: &str) -> bool {
+    LAST_ANSWER.lock().unwrap().is_some() && replace_answer(expression, "0").is_some()
+}
+
+/// Substitute `ans` with the previous result (0 if there is none).
+///
+/// Returns None if the expression doesn't use `ans`.
+pub fn resolve_answer(expression: &str) -> Option<String> {
+    let answer = LAST_ANSWER.lock().unwrap().clone();
+    replace_answer(expression, answer.as_deref().unwrap_or("0"))
+}
+
+/// Replace every standalone `ans` (ignoring case) with the answer.
+fn replace_answer(expression: &str, answer: &str) -> Option<String> {
+    // Negative answers are parenthesized so "2 - ans" stays correct
+    let answer = if answer.starts_with('-') {
+        format!("({})", answer)
+    } else {
+        answer.to_string()
+    };
+
+    let lower = expression.to_ascii_lowercase();
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut result = String::with_capacity(expression.len());
+    let mut last = 0;
+    for (idx, _) in lower.match_indices(ANSWER_KEYWORD) {
+        let end = idx + ANSWER_KEYWORD.len();
+        let standalone =
+            !lower[..idx].ends_with(is_word_char) && !lower[end..].starts_with(is_word_char);
+        if standalone {
+            result.push_str(&expression[last..idx]);
+            result.push_str(&answer);
+            last = end;
+        }
+    }
+
+    if last == 0 {
+        return None;
+    }
+    result.push_str(&expression[last..]);
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_answer() {
+        assert_eq!(replace_answer("ans * 2", "21").as_deref(), Some("21 * 2"));
+        assert_eq!(
+            replace_answer("(ANS + 1) / ans", "3").as_deref(),
+            Some("(3 + 1) / 3")
+        );
+        assert_eq!(
+            replace_answer("10 - ans", "-4").as_deref(),
+            Some("10 - (-4)")
+        );
+    }
+
+    #[test]
+    fn test_replace_answer_needs_whole_word() {
+        assert_eq!(replace_answer("answer * 2", "21"), None);
+        assert_eq!(replace_answer("2 * trans", "21"), None);
+        assert_eq!(replace_answer("2 + 2", "21"), None);
+    }
+}
@@ -0,0 +1,256 @@
+//! Date and time arithmetic like "today + 30 days" or "2024-12-25 - today".
+//!
+//! Dates are `today`, `tomorrow`, `yesterday`, `now` (with the time) and
+//! `YYYY-MM-DD`; durations are a number with a unit of minutes, hours, days
+//! or weeks. Adding or subtracting durations gives a date, subtracting two
+//! dates gives the time between them.
+
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime};
+
+/// Duration units with their accepted spellings (plurals by a trailing "s")
+/// and length in minutes.
+const DURATION_UNITS: &[(&[&str], i64)] = &[
+    (&["min", "minute"], 1),
+    (&["h", "hr", "hour"], 60),
+    (&["d", "day"], 24 * 60),
+    (&["w", "wk", "week"], 7 * 24 * 60),
+];
+
+/// A value of date arithmetic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    /// A point in time
+    Date(NaiveDateTime),
+    /// A span of time
+    Duration(Duration),
+}
+
+/// A token of a date expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Value(Value),
+    Plus,
+    Minus,
+}
+
+/// Evaluate date arithmetic, returning the formatted date or duration.
+///
+/// The expression must use at least one date and one operator; None for
+/// anything else (a lone date, plain numbers without a unit, unknown words),
+/// so it can be evaluated as normal arithmetic.
+pub fn evaluate(input: &str) -> Option<String> {
+    evaluate_at(input, Local::now().naive_local())
+}
+
+/// Evaluate date arithmetic with `now` as the current time.
+fn evaluate_at(input: &str, now: NaiveDateTime) -> Option<String> {
+    let input = input.to_lowercase();
+    let tokens = tokenize(&input, now)?;
+    let has_date = tokens
+        .iter()
+        .any(|token| matches!(token, Token::Value(Value::Date(_))));
+    // Results show the time of day only when it can be other than midnight
+    let has_time = input.contains("now")
+        || tokens.iter().any(|token| {
+            matches!(token, Token::Value(Value::Duration(d)) if d.num_minutes() % (24 * 60) != 0)
+        });
+    if !has_date || tokens.len() < 3 {
+        return None;
+    }
+
+    let mut tokens = tokens.into_iter();
+    let Some(Token::Value(mut result)) = tokens.next() else {
+        return None;
+    };
+    while let Some(op) = tokens.next() {
+        let Some(Token::Value(rhs)) = tokens.next() else {
+            return None;
+        };
+        result = match (op, result, rhs) {
+            (Token::Plus, Value::Date(date), Value::Duration(d))
+            | (Token::Plus, Value::Duration(d), Value::Date(date)) => {
+                Value::Date(date.checked_add_signed(d)?)
+            }
+            (Token::Minus, Value::Date(date), Value::Duration(d)) => {
+                Value::Date(date.checked_sub_signed(d)?)
+            }
+            (Token::Minus, Value::Date(a), Value::Date(b)) => Value::Duration(a - b),
+            (Token::Plus, Value::Duration(a), Value::Duration(b)) => {
+                Value::Duration(a.checked_add(&b)?)
+            }
+            (Token::Minus, Value::Duration(a), Value::Duration(b)) => {
+                Value::Duration(a.checked_sub(&b)?)
+            }
+            _ => return None,
+        };
+    }
+
+    Some(match result {
+        Value::Date(date) if has_time => date.format("%Y-%m-%d %H:%M").to_string(),
+        Value::Date(date) => date.format("%Y-%m-%d").to_string(),
+        Value::Duration(duration) => format_duration(duration, has_time),
+    })
+}
+
+/// Split an expression into dates, durations and operators.
+fn tokenize(input: &str, now: NaiveDateTime) -> Option<Vec<Token>> {
+    let today = now.date().and_hms_opt(0, 0, 0)?;
+    let mut tokens = Vec::new();
+    let mut rest = input.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        let (token, len) = if c == '+' {
+            (Token::Plus, 1)
+        } else if c == '-' {
+            (Token::Minus, 1)
+        } else if let Some(date) = rest
+            .get(..10)
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+        {
+            (Token::Value(Value::Date(date.and_hms_opt(0, 0, 0)?)), 10)
+        } else if c.is_ascii_digit() || c == '.' {
+            let number_len = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .unwrap_or(rest.len());
+            let amount: f64 = rest[..number_len].parse().ok()?;
+            let after = rest[number_len..].trim_start();
+            let unit_len = after
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(after.len());
+            let minutes = duration_unit(&after[..unit_len])?;
+            // Out of range durations (e.g. "200000000000 weeks") aren't dates
+            let seconds = (amount * minutes as f64 * 60.0).round();
+            if !seconds.is_finite() || seconds.abs() >= i64::MAX as f64 {
+                return None;
+            }
+            let duration = Duration::try_seconds(seconds as i64)?;
+            (
+                Token::Value(Value::Duration(duration)),
+                rest.len() - after.len() + unit_len,
+            )
+        } else if c.is_alphabetic() {
+            let len = rest
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(rest.len());
+            let date = match &rest[..len] {
+                "now" => now,
+                "today" => today,
+                "tomorrow" => today.checked_add_signed(Duration::days(1))?,
+                "yesterday" => today.checked_sub_signed(Duration::days(1))?,
+                _ => return None,
+            };
+            (Token::Value(Value::Date(date)), len)
+        } else {
+            return None;
+        };
+        tokens.push(token);
+        rest = rest[len..].trim_start();
+    }
+
+    Some(tokens)
+}
+
+/// Get the length of a duration unit in minutes.
+fn duration_unit(name: &str) -> Option<i64> {
+    let find = |name: &str| {
+        DURATION_UNITS
+            .iter()
+            .find(|(names, _)| names.contains(&name))
+            .map(|&(_, minutes)| minutes)
+    };
+    find(name).or_else(|| find(name.strip_suffix('s')?))
+}
+
+/// Format a duration as days, or as days, hours and minutes when the time
+/// of day matters ("3 days 4 hours 5 minutes").
+fn format_duration(duration: Duration, with_time: bool) -> String {
+    let plural = |n: i64, unit: &str| {
+        if n.abs() == 1 {
+            format!("{} {}", n, unit)
+        } else {
+            format!("{} {}s", n, unit)
+        }
+    };
+    if !with_time {
+        return plural(duration.num_days(), "day");
+    }
+
+    let minutes = duration.num_minutes();
+    let sign = if minutes < 0 { "-" } else { "" };
+    let minutes = minutes.abs();
+    let parts: Vec<String> = [
+        (minutes / (24 * 60), "day"),
+        (minutes / 60 % 24, "hour"),
+        (minutes % 60, "minute"),
+    ]
+    .into_iter()
+    .filter(|&(n, _)| n > 0)
+    .map(|(n, unit)| plural(n, unit))
+    .collect();
+    if parts.is_empty() {
+        return plural(0, "minute");
+    }
+    format!("{}{}", sign, parts.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, 16)
+            .unwrap()
+            .and_hms_opt(14, 5, 30)
+            .unwrap()
+    }
+
+    fn eval(input: &str) -> Option<String> {
+        evaluate_at(input, now())
+    }
+
+    #[test]
+    fn test_date_plus_duration() {
+        assert_eq!(eval("today + 30 days").as_deref(), Some("2026-11-15"));
+        assert_eq!(eval("Tomorrow - 2 weeks").as_deref(), Some("2026-10-03"));
+        assert_eq!(eval("2024-02-28 + 1d").as_deref(), Some("2024-02-29"));
+        assert_eq!(eval("now + 1.5 hours").as_deref(), Some("2026-10-16 15:35"));
+        assert_eq!(
+            eval("today + 36 hours").as_deref(),
+            Some("2026-10-17 12:00")
+        );
+    }
+
+    #[test]
+    fn test_days_between_dates() {
+        assert_eq!(eval("2026-12-25 - today").as_deref(), Some("70 days"));
+        assert_eq!(eval("yesterday - today").as_deref(), Some("-1 day"));
+        assert_eq!(
+            eval("2026-10-18 - now").as_deref(),
+            Some("1 day 9 hours 54 minutes")
+        );
+    }
+
+    #[test]
+    fn test_not_date_math() {
+        // Left to normal arithmetic
+        assert_eq!(eval("2026-12-25"), None);
+        assert_eq!(eval("2026-12-25 - 5"), None);
+        assert_eq!(eval("3 days + 4 days"), None);
+        assert_eq!(eval("today + 1 month"), None);
+        assert_eq!(eval("today + today"), None);
+        assert_eq!(eval("10 - 2"), None);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(eval("today + 200000000000 weeks"), None);
+        assert_eq!(eval("today + 100000000 weeks"), None);
+        assert_eq!(eval("today - 100000000 weeks"), None);
+        // Durations that only overflow when added up
+        assert_eq!(eval("10000000000 weeks + 10000000000 weeks + today"), None);
+        assert_eq!(
+            eval("2026-01-01 - today - 10000000000 weeks - 10000000000 weeks"),
+            None
+        );
+    }
+}
@@ -3,6 +3,12 @@
 //! Wraps fend to provide a simple interface for evaluating
 //! mathematical expressions and formatting results.
 
+use super::answer::resolve_answer;
+use super::dates;
+use super::formats::alternative_formats;
+use super::integer;
+use super::percent::rewrite_percentage;
+use super::units::convert;
 use crate::items::CalculatorItem;
 use fend_core::Context;
 use std::sync::{Mutex, OnceLock};
@@ -11,25 +17,98 @@ static CONTEXT: OnceLock<Mutex<Context>> = OnceLock::new();
 
 /// Evaluate a mathematical expression.
 ///
+/// Date arithmetic ("today + 30 days"), unit conversions like "10 km to mi"
+/// and integer expressions with base literals or bitwise operators
+/// ("0xFF & 0b1010 in hex") are handled first, anything else is evaluated
+/// by fend. Percentages ("20% of 250") are
+/// rewritten into plain arithmetic for it. `ans` stands for the previous
+/// result.
+///
 /// Returns `Ok(CalculatorItem)` if the expression can be parsed,
 /// or `None` if parsing fails entirely.
 pub fn evaluate_expression(input: &str) -> Result<CalculatorItem, String> {
     let expression = input.trim().to_string();
+    let resolved = resolve_answer(&expression);
+    let evaluated = resolved.as_deref().unwrap_or(&expression);
+
+    if let Some(result) = dates::evaluate(evaluated) {
+        return Ok(CalculatorItem {
+            id: "calculator-result".to_string(),
+            expression,
+            display_result: result.clone(),
+            clipboard_result: Some(result),
+            is_error: false,
+            alternatives: Vec::new(),
+            format_index: 0,
+        });
+    }
 
+    if let Some(conversion) = convert(evaluated) {
+        let number = format_number(conversion.value);
+        let clipboard_result = if crate::config::config().calculator_copy_unit {
+            format!("{} {}", number, conversion.unit)
+        } else {
+            number
+        };
+        return Ok(CalculatorItem {
+            id: "calculator-result".to_string(),
+            expression,
+            display_result: format!(
+                "{} {}",
+                format_display(&conversion.value.to_string()),
+                conversion.unit
+            ),
+            clipboard_result: Some(clipboard_result),
+            is_error: false,
+            alternatives: Vec::new(),
+            format_index: 0,
+        });
+    }
+
+    if let Some(result) = integer::evaluate(evaluated) {
+        let result = result?;
+        // A requested base is shown and copied as is
+        let (display_result, clipboard_result) = match result.format_in_base() {
+            Some(formatted) => (formatted.clone(), formatted),
+            None => (
+                format_with_separators(result.value),
+                result.value.to_string(),
+            ),
+        };
+        return Ok(CalculatorItem {
+            id: "calculator-result".to_string(),
+            expression,
+            display_result,
+            clipboard_result: Some(clipboard_result),
+            is_error: false,
+            alternatives: Vec::new(),
+            format_index: 0,
+        });
+    }
+
+    let rewritten = rewrite_percentage(evaluated);
     let mut context = CONTEXT
         .get_or_init(|| Mutex::new(Context::new()))
         .lock()
         .unwrap();
-    match fend_core::evaluate(&expression, &mut context) {
+    match fend_core::evaluate(rewritten.as_deref().unwrap_or(evaluated), &mut context) {
         Ok(value) => {
             let value = value.get_main_result();
             let calc_value = value.trim_start_matches("approx. ");
+            let alternatives = match calc_value.parse::<f64>() {
+                Ok(number) if crate::config::config().calculator_formats => {
+                    alternative_formats(number)
+                }
+                _ => Vec::new(),
+            };
             Ok(CalculatorItem {
                 id: "calculator-result".to_string(),
                 expression,
                 display_result: format_display(value),
                 clipboard_result: Some(calc_value.to_string()),
                 is_error: false,
+                alternatives,
+                format_index: 0,
             })
         }
         Err(err) => {
@@ -40,6 +119,8 @@ pub fn evaluate_expression(input: &str) -> Result<CalculatorItem, String> {
                     display_result: "Infinity".to_string(),
                     clipboard_result: None,
                     is_error: true,
+                    alternatives: Vec::new(),
+                    format_index: 0,
                 })
             } else {
                 Err(err.to_string())
@@ -56,26 +137,41 @@ fn format_display(value: &str) -> String {
     };
     if value.fract() == 0.0 && value.abs() < 1e15 {
         // Integer display with thousand separators
-        format_with_separators(value as i64)
+        format_with_separators(value as i128)
     } else {
         // Decimal display
-        let formatted = format!("{:.10}", value);
-        let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+        let formatted = format_number(value);
+        // The sign is kept apart so values like -0.5 don't lose it
+        let (sign, trimmed) = match formatted.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", formatted.as_str()),
+        };
 
         // Add thousand separators to the integer part
         if let Some(dot_pos) = trimmed.find('.') {
             let (int_part, dec_part) = trimmed.split_at(dot_pos);
-            let int_val: i64 = int_part.parse().unwrap_or(0);
-            format!("{}{}", format_with_separators(int_val), dec_part)
+            let int_val: i128 = int_part.parse().unwrap_or(0);
+            format!("{}{}{}", sign, format_with_separators(int_val), dec_part)
         } else {
-            let int_val: i64 = trimmed.parse().unwrap_or(0);
-            format_with_separators(int_val)
+            let int_val: i128 = trimmed.parse().unwrap_or(0);
+            format!("{}{}", sign, format_with_separators(int_val))
         }
     }
 }
 
+/// Format a number without separators, to at most 10 decimal places.
+fn format_number(value: f64) -> String {
+    let formatted = format!("{:.10}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    if trimmed == "-0" {
+        "0".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
 /// Format an integer with thousand separators.
-fn format_with_separators(value: i64) -> String {
+fn format_with_separators(value: i128) -> String {
     let is_negative = value < 0;
     let abs_value = value.abs();
     let s = abs_value.to_string();
@@ -98,7 +194,7 @@ fn format_with_separators(value: i64) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::evaluate_expression;
+    use super::{alternative_formats, evaluate_expression};
 
     #[test]
     fn test_basic_evaluation() {
@@ -121,6 +217,26 @@ mod tests {
         assert!(result.display_result.starts_with("approx. 0.333"));
     }
 
+    #[test]
+    fn test_result_formats() {
+        let mut result = evaluate_expression("1 / 3").unwrap();
+        // Only offered with `calculator_formats` enabled
+        assert!(result.alternatives.is_empty());
+        result.alternatives = alternative_formats(1.0 / 3.0);
+        assert_eq!(result.alternatives, vec!["1/3", "0.33", "3.3333e-1"]);
+        assert!(result.text_for_clipboard().starts_with("0.333"));
+
+        result.cycle_format();
+        assert_eq!(result.text_for_clipboard(), "1/3");
+        result.cycle_format();
+        result.cycle_format();
+        result.cycle_format();
+        assert!(result.text_for_clipboard().starts_with("0.333"));
+
+        let result = evaluate_expression("6 * 7").unwrap();
+        assert!(result.alternatives.is_empty());
+    }
+
     #[test]
     fn test_division_by_zero() {
         let result = evaluate_expression("1 / 0").unwrap();
@@ -141,6 +257,54 @@ mod tests {
         assert_eq!(result.display_result, "4");
     }
 
+    #[test]
+    fn test_unit_conversion() {
+        let result = evaluate_expression("10 km to mi").unwrap();
+        assert_eq!(result.display_result, "6.2137119224 mi");
+        assert_eq!(result.text_for_clipboard(), "6.2137119224");
+
+        let result = evaluate_expression("5000 ft in m").unwrap();
+        assert_eq!(result.display_result, "1,524 m");
+        assert_eq!(result.text_for_clipboard(), "1524");
+
+        let result = evaluate_expression("31 f to c").unwrap();
+        assert_eq!(result.display_result, "-0.5555555556 °C");
+    }
+
+    #[test]
+    fn test_base_conversion() {
+        let result = evaluate_expression("0xFF + 0b1010").unwrap();
+        assert_eq!(result.display_result, "265");
+        assert_eq!(result.text_for_clipboard(), "265");
+
+        let result = evaluate_expression("0xF0 | 0b1010 in hex").unwrap();
+        assert_eq!(result.display_result, "0xFA");
+        assert_eq!(result.text_for_clipboard(), "0xFA");
+    }
+
+    #[test]
+    fn test_percentages() {
+        let result = evaluate_expression("20% of 250").unwrap();
+        assert_eq!(result.display_result, "50");
+        assert_eq!(result.expression, "20% of 250");
+
+        let result = evaluate_expression("250 + 15%").unwrap();
+        assert_eq!(result.display_result, "287.5");
+
+        let result = evaluate_expression("250 - 10%").unwrap();
+        assert_eq!(result.display_result, "225");
+    }
+
+    #[test]
+    fn test_previous_answer() {
+        let result = evaluate_expression("6 * 7").unwrap();
+        crate::calculator::remember_answer(&result);
+
+        let result = evaluate_expression("ans / 2").unwrap();
+        assert_eq!(result.display_result, "21");
+        assert_eq!(result.expression, "ans / 2");
+    }
+
     #[test]
     fn test_trig_functions() {
         let result = evaluate_expression("sin(0)").unwrap();
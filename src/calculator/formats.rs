@@ -0,0 +1,104 @@
+//! Alternative representations of calculator results.
+//!
+//! Results with a fractional part are also offered as the fraction they
+//! equal (when there is a simple one), rounded to two decimals and in
+//! scientific notation.
+
+/// Largest denominator tried when looking for the fraction of a result.
+const MAX_DENOMINATOR: i64 = 1000;
+
+/// Decimal places of the rounded representation.
+const ROUNDED_DECIMALS: usize = 2;
+
+/// Significant decimals of the scientific notation mantissa.
+const SCIENTIFIC_DECIMALS: usize = 4;
+
+/// Get the alternative representations of a result.
+///
+/// Integers have none, they're already exact and short.
+pub fn alternative_formats(value: f64) -> Vec<String> {
+    if !value.is_finite() || value.fract() == 0.0 {
+        return Vec::new();
+    }
+
+    let mut formats = Vec::new();
+    if let Some((numerator, denominator)) = as_fraction(value) {
+        formats.push(format!("{}/{}", numerator, denominator));
+    }
+    formats.push(format!("{:.*}", ROUNDED_DECIMALS, value));
+    formats.push(scientific(value));
+    formats
+}
+
+/// Find the fraction with the smallest denominator equal to the value.
+///
+/// Walks the continued fraction convergents of the value. The result is
+/// parsed from a rounded decimal, so it only has to match to 1e-9.
+fn as_fraction(value: f64) -> Option<(i64, i64)> {
+    let target = value.abs();
+    let (mut prev_num, mut num) = (0i64, 1i64);
+    let (mut prev_den, mut den) = (1i64, 0i64);
+    let mut rest = target;
+
+    loop {
+        let term = rest.floor();
+        if term >= (i64::MAX / 2) as f64 {
+            return None;
+        }
+        let term = term as i64;
+        let next_num = term.checked_mul(num)?.checked_add(prev_num)?;
+        let next_den = term.checked_mul(den)?.checked_add(prev_den)?;
+        if next_den > MAX_DENOMINATOR {
+            return None;
+        }
+        if (next_num as f64 / next_den as f64 - target).abs() <= 1e-9 * target.max(1.0) {
+            // A whole number isn't worth showing as a fraction
+            return (next_den > 1).then(|| (next_num * value.signum() as i64, next_den));
+        }
+
+        let fract = rest - term as f64;
+        if fract < 1e-12 {
+            return None;
+        }
+        rest = 1.0 / fract;
+        (prev_num, num) = (num, next_num);
+        (prev_den, den) = (den, next_den);
+    }
+}
+
+/// Format a value in scientific notation without trailing zeros, e.g. "3.3333e-1".
+fn scientific(value: f64) -> String {
+    let formatted = format!("{:.*e}", SCIENTIFIC_DECIMALS, value);
+    match formatted.split_once('e') {
+        Some((mantissa, exponent)) if mantissa.contains('.') => format!(
+            "{}e{}",
+            mantissa.trim_end_matches('0').trim_end_matches('.'),
+            exponent
+        ),
+        _ => formatted,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_fraction() {
+        assert_eq!(as_fraction(0.3333333333), Some((1, 3)));
+        assert_eq!(as_fraction(2.5), Some((5, 2)));
+        assert_eq!(as_fraction(-0.1428571429), Some((-1, 7)));
+        assert_eq!(as_fraction(3.1415926536), None);
+    }
+
+    #[test]
+    fn test_alternative_formats() {
+        assert_eq!(
+            alternative_formats(0.3333333333),
+            vec!["1/3", "0.33", "3.3333e-1"]
+        );
+        assert_eq!(alternative_formats(2.5), vec!["5/2", "2.50", "2.5e0"]);
+        assert_eq!(alternative_formats(3.1415926536), vec!["3.14", "3.1416e0"]);
+        assert!(alternative_formats(42.0).is_empty());
+    }
+}
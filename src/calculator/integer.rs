@@ -0,0 +1,324 @@
+//! Integer arithmetic for programmers.
+//!
+//! Handles expressions fend doesn't: hex (`0x`), binary (`0b`) and octal
+//! (`0o`) literals, bitwise operators and a trailing `in hex` / `in bin` /
+//! `in oct` to pick the output base. Only expressions using one of those are
+//! evaluated here; there `^` is XOR rather than a power.
+
+/// Output base of an integer result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base {
+    Binary,
+    Octal,
+    Decimal,
+    Hexadecimal,
+}
+
+impl Base {
+    /// Parse the target of a trailing `in <base>`.
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "bin" | "binary" => Some(Self::Binary),
+            "oct" | "octal" => Some(Self::Octal),
+            "dec" | "decimal" => Some(Self::Decimal),
+            "hex" | "hexadecimal" => Some(Self::Hexadecimal),
+            _ => None,
+        }
+    }
+
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Binary => "0b",
+            Self::Octal => "0o",
+            Self::Decimal => "",
+            Self::Hexadecimal => "0x",
+        }
+    }
+}
+
+/// The result of an integer expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegerResult {
+    pub value: i128,
+    /// The base requested with `in <base>`, if any
+    pub base: Option<Base>,
+}
+
+impl IntegerResult {
+    /// Format the value in the requested base (e.g. "0xFF"), or None if no
+    /// base was requested.
+    pub fn format_in_base(&self) -> Option<String> {
+        let base = self.base?;
+        let digits = match base {
+            Base::Binary => format!("{:b}", self.value.unsigned_abs()),
+            Base::Octal => format!("{:o}", self.value.unsigned_abs()),
+            Base::Decimal => self.value.unsigned_abs().to_string(),
+            Base::Hexadecimal => format!("{:X}", self.value.unsigned_abs()),
+        };
+        let sign = if self.value < 0 { "-" } else { "" };
+        Some(format!("{}{}{}", sign, base.prefix(), digits))
+    }
+}
+
+/// Evaluate an integer expression.
+///
+/// Returns None if the input uses no base literal, bitwise operator or
+/// `in <base>` suffix (it's left to fend), and an error if it does but can't
+/// be evaluated.
+pub fn evaluate(input: &str) -> Option<Result<IntegerResult, String>> {
+    let (expression, base) = split_base_suffix(input);
+    if base.is_none() && !uses_integer_syntax(expression) {
+        return None;
+    }
+
+    Some(tokenize(expression).and_then(|tokens| {
+        let mut parser = Parser { tokens, pos: 0 };
+        let value = parser.parse_or()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(IntegerResult { value, base }),
+            Some(token) => Err(format!("unexpected {:?}", token)),
+        }
+    }))
+}
+
+/// Split off a trailing `in hex` / `to bin` style base request.
+fn split_base_suffix(input: &str) -> (&str, Option<Base>) {
+    let trimmed = input.trim();
+    let mut words = trimmed.rsplitn(3, char::is_whitespace);
+    if let (Some(target), Some(connective), Some(expression)) =
+        (words.next(), words.next(), words.next())
+        && matches!(connective.to_lowercase().as_str(), "in" | "to" | "as")
+        && let Some(base) = Base::from_name(target)
+    {
+        return (expression.trim(), Some(base));
+    }
+    (trimmed, None)
+}
+
+/// Check for syntax fend doesn't handle the way programmers expect.
+fn uses_integer_syntax(expression: &str) -> bool {
+    let lower = expression.to_lowercase();
+    let has_literal = ["0x", "0b", "0o"].iter().any(|prefix| {
+        lower.match_indices(prefix).any(|(idx, _)| {
+            // The prefix must start a number, not end one (e.g. "10x")
+            !lower[..idx].ends_with(|c: char| c.is_ascii_alphanumeric())
+        })
+    });
+    has_literal
+        || ["&", "|", "<<", ">>", "~"]
+            .iter()
+            .any(|op| lower.contains(op))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Number(i128),
+    Op(&'static str),
+    Open,
+    Close,
+}
+
+const OPERATORS: &[&str] = &["<<", ">>", "+", "-", "*", "/", "%", "&", "|", "^", "~"];
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = expression.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        if c.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Number(parse_literal(&rest[..len])?));
+            rest = &rest[len..];
+        } else if c == '(' || c == ')' {
+            tokens.push(if c == '(' { Token::Open } else { Token::Close });
+            rest = &rest[1..];
+        } else if let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(*op)) {
+            tokens.push(Token::Op(op));
+            rest = &rest[op.len()..];
+        } else {
+            return Err(format!("unexpected character {:?}", c));
+        }
+        rest = rest.trim_start();
+    }
+    Ok(tokens)
+}
+
+/// Parse a decimal, `0x`, `0b` or `0o` literal (underscores allowed).
+fn parse_literal(literal: &str) -> Result<i128, String> {
+    let digits = literal.replace('_', "");
+    let lower = digits.to_lowercase();
+    let (digits, radix) = match lower.get(..2) {
+        Some("0x") => (&lower[2..], 16),
+        Some("0b") => (&lower[2..], 2),
+        Some("0o") => (&lower[2..], 8),
+        _ => (lower.as_str(), 10),
+    };
+    i128::from_str_radix(digits, radix).map_err(|_| format!("invalid number {:?}", literal))
+}
+
+/// Recursive descent parser, loosest binding first: `|`, `^`, `&`,
+/// shifts, `+ -`, `* / %`, then unary `-` and `~`.
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn eat(&mut self, ops: &[&'static str]) -> Option<&'static str> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Op(op)) if ops.contains(op) => {
+                self.pos += 1;
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse a left-associative chain of binary operators.
+    fn binary(
+        &mut self,
+        ops: &[&'static str],
+        operand: fn(&mut Self) -> Result<i128, String>,
+    ) -> Result<i128, String> {
+        let mut value = operand(self)?;
+        while let Some(op) = self.eat(ops) {
+            let rhs = operand(self)?;
+            value = apply(op, value, rhs)
+                .ok_or_else(|| format!("overflow or division by zero in {}", op))?;
+        }
+        Ok(value)
+    }
+
+    fn parse_or(&mut self) -> Result<i128, String> {
+        self.binary(&["|"], Self::parse_xor)
+    }
+
+    fn parse_xor(&mut self) -> Result<i128, String> {
+        self.binary(&["^"], Self::parse_and)
+    }
+
+    fn parse_and(&mut self) -> Result<i128, String> {
+        self.binary(&["&"], Self::parse_shift)
+    }
+
+    fn parse_shift(&mut self) -> Result<i128, String> {
+        self.binary(&["<<", ">>"], Self::parse_sum)
+    }
+
+    fn parse_sum(&mut self) -> Result<i128, String> {
+        self.binary(&["+", "-"], Self::parse_product)
+    }
+
+    fn parse_product(&mut self) -> Result<i128, String> {
+        self.binary(&["*", "/", "%"], Self::parse_unary)
+    }
+
+    fn parse_unary(&mut self) -> Result<i128, String> {
+        match self.eat(&["-", "~", "+"]) {
+            Some("-") => self
+                .parse_unary()?
+                .checked_neg()
+                .ok_or_else(|| "overflow".to_string()),
+            Some("~") => Ok(!self.parse_unary()?),
+            Some(_) => self.parse_unary(),
+            None => self.parse_atom(),
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<i128, String> {
+        let token = self.tokens.get(self.pos).copied();
+        self.pos += 1;
+        match token {
+            Some(Token::Number(value)) => Ok(value),
+            Some(Token::Open) => {
+                let value = self.parse_or()?;
+                match self.tokens.get(self.pos) {
+                    Some(Token::Close) => {
+                        self.pos += 1;
+                        Ok(value)
+                    }
+                    _ => Err("missing )".to_string()),
+                }
+            }
+            Some(token) => Err(format!("unexpected {:?}", token)),
+            None => Err("unexpected end of expression".to_string()),
+        }
+    }
+}
+
+/// Apply a binary operator, None on overflow or division by zero.
+fn apply(op: &str, lhs: i128, rhs: i128) -> Option<i128> {
+    match op {
+        "+" => lhs.checked_add(rhs),
+        "-" => lhs.checked_sub(rhs),
+        "*" => lhs.checked_mul(rhs),
+        "/" => lhs.checked_div(rhs),
+        "%" => lhs.checked_rem(rhs),
+        "&" => Some(lhs & rhs),
+        "|" => Some(lhs | rhs),
+        "^" => Some(lhs ^ rhs),
+        "<<" => lhs.checked_shl(u32::try_from(rhs).ok()?),
+        ">>" => lhs.checked_shr(u32::try_from(rhs).ok()?),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(input: &str) -> IntegerResult {
+        evaluate(input)
+            .unwrap_or_else(|| panic!("{input:?} isn't an integer expression"))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_mixed_base_arithmetic() {
+        assert_eq!(eval("0xFF + 0b1010").value, 265);
+        assert_eq!(eval("0o17 * 2 - 0x0a").value, 20);
+        assert_eq!(eval("0x1_000 / 0b100").value, 1024);
+    }
+
+    #[test]
+    fn test_bitwise_operators() {
+        assert_eq!(eval("0xF0 | 0x0F").value, 0xFF);
+        assert_eq!(eval("0xFF & 0b1010").value, 10);
+        assert_eq!(eval("0b1100 ^ 0b1010").value, 0b0110);
+        assert_eq!(eval("1 << 4 + 1").value, 32);
+        assert_eq!(eval("(1 << 4) + 1").value, 17);
+        assert_eq!(eval("256 >> 2").value, 64);
+        assert_eq!(eval("~0 & 0xF").value, 15);
+    }
+
+    #[test]
+    fn test_output_base() {
+        let result = eval("255 in hex");
+        assert_eq!(result.value, 255);
+        assert_eq!(result.format_in_base().as_deref(), Some("0xFF"));
+        assert_eq!(
+            eval("0xF0 | 0x0F to bin").format_in_base().as_deref(),
+            Some("0b11111111")
+        );
+        assert_eq!(eval("-8 in oct").format_in_base().as_deref(), Some("-0o10"));
+        assert_eq!(eval("0x10 in dec").format_in_base().as_deref(), Some("16"));
+        assert_eq!(eval("0x10").format_in_base(), None);
+    }
+
+    #[test]
+    fn test_left_to_fend() {
+        assert_eq!(evaluate("2 + 2"), None);
+        assert_eq!(evaluate("2^10"), None);
+        assert_eq!(evaluate("10 km in mi"), None);
+    }
+
+    #[test]
+    fn test_invalid_integer_expression() {
+        assert!(evaluate("0xZZ + 1").unwrap().is_err());
+        assert!(evaluate("0x10 / 0").unwrap().is_err());
+        assert!(evaluate("(0x10 + 1").unwrap().is_err());
+        assert!(evaluate("1.5 in hex").unwrap().is_err());
+    }
+}
@@ -2,7 +2,20 @@
 //!
 //! This module provides functionality to:
 //! - Evaluate expressions using fend
+//! - Convert between units ("10 km to mi")
+//! - Evaluate integer expressions with base literals and bitwise operators
+//! - Evaluate percentages ("20% of 250", "250 + 15%")
+//! - Do date arithmetic ("today + 30 days", "2024-12-25 - today")
+//! - Reference the previous result with `ans`
+//! - Offer results as fractions, rounded and in scientific notation
 
+mod answer;
+mod dates;
 mod evaluation;
+mod formats;
+mod integer;
+mod percent;
+mod units;
 
+pub use answer::{has_answer_reference, remember_answer};
 pub use evaluation::evaluate_expression;
@@ -0,0 +1,83 @@
+//! Percentage expressions like "20% of 250" and "250 + 15%".
+//!
+//! They are rewritten into plain arithmetic before evaluation. Any other `%`
+//! is left alone, so it keeps its meaning as modulo.
+
+/// Rewrite a percentage expression into plain arithmetic.
+///
+/// - `X% of Y` becomes `(Y) * X / 100`
+/// - `Y + X%` and `Y - X%` become `(Y) * (1 ± X / 100)`
+///
+/// The percentage X must be a plain number. Returns None for anything else.
+pub fn rewrite_percentage(expression: &str) -> Option<String> {
+    let expression = expression.trim();
+
+    if let Some((percent, base)) = split_of(expression) {
+        let percent = parse_percent(percent)?;
+        return Some(format!("({}) * {} / 100", base, percent));
+    }
+
+    let body = expression.strip_suffix('%')?;
+    let (base, percent) = body.rsplit_once(['+', '-'])?;
+    let op = &body[base.len()..=base.len()];
+    let percent = percent.trim();
+    percent.parse::<f64>().ok()?;
+
+    // "5 * -3%" has no base to add to
+    let base = base.trim();
+    if base.is_empty() || base.ends_with(['+', '-', '*', '/', '%', '^', '(']) {
+        return None;
+    }
+    Some(format!("({}) * (1 {} {} / 100)", base, op, percent))
+}
+
+/// Split "X% of Y" into "X%" and "Y".
+fn split_of(expression: &str) -> Option<(&str, &str)> {
+    let idx = expression.to_ascii_lowercase().find(" of ")?;
+    let base = expression[idx + " of ".len()..].trim();
+    (!base.is_empty()).then(|| (&expression[..idx], base))
+}
+
+/// Get the number of a "X%" term.
+fn parse_percent(term: &str) -> Option<&str> {
+    let number = term.trim().strip_suffix('%')?.trim_end();
+    number.parse::<f64>().ok().map(|_| number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_of() {
+        assert_eq!(
+            rewrite_percentage("20% of 250").as_deref(),
+            Some("(250) * 20 / 100")
+        );
+        assert_eq!(
+            rewrite_percentage("12.5 % OF 80 + 20").as_deref(),
+            Some("(80 + 20) * 12.5 / 100")
+        );
+    }
+
+    #[test]
+    fn test_add_and_subtract_percent() {
+        assert_eq!(
+            rewrite_percentage("250 + 15%").as_deref(),
+            Some("(250) * (1 + 15 / 100)")
+        );
+        assert_eq!(
+            rewrite_percentage("2 * 50 - 10 %").as_deref(),
+            Some("(2 * 50) * (1 - 10 / 100)")
+        );
+    }
+
+    #[test]
+    fn test_modulo_is_untouched() {
+        assert_eq!(rewrite_percentage("10 % 3"), None);
+        assert_eq!(rewrite_percentage("10 + 5 % 3"), None);
+        assert_eq!(rewrite_percentage("5 * -3%"), None);
+        assert_eq!(rewrite_percentage("20% of"), None);
+        assert_eq!(rewrite_percentage("x% of 250"), None);
+    }
+}
@@ -0,0 +1,188 @@
+//! Unit conversions like "10 km to mi".
+//!
+//! Supports length, mass, temperature and time units. Each unit is
+//! converted through the base unit of its dimension (meter, kilogram,
+//! kelvin, second).
+
+/// The physical dimension of a unit; only units of the same one convert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dimension {
+    Length,
+    Mass,
+    Temperature,
+    Time,
+}
+
+/// A unit, converted to the base unit as `value * factor + offset`.
+struct Unit {
+    /// Symbol shown after the result
+    symbol: &'static str,
+    /// Accepted spellings (lowercase)
+    names: &'static [&'static str],
+    dimension: Dimension,
+    factor: f64,
+    offset: f64,
+}
+
+impl Unit {
+    const fn new(symbol: &'static str, dimension: Dimension, factor: f64) -> Self {
+        Self {
+            symbol,
+            names: &[],
+            dimension,
+            factor,
+            offset: 0.0,
+        }
+    }
+
+    const fn names(mut self, names: &'static [&'static str]) -> Self {
+        self.names = names;
+        self
+    }
+
+    const fn offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+use Dimension::{Length, Mass, Temperature, Time};
+
+/// Known units. Plurals ending in "s" don't need to be listed.
+const UNITS: &[Unit] = &[
+    // Length (meter)
+    Unit::new("mm", Length, 0.001).names(&["mm", "millimeter", "millimetre"]),
+    Unit::new("cm", Length, 0.01).names(&["cm", "centimeter", "centimetre"]),
+    Unit::new("m", Length, 1.0).names(&["m", "meter", "metre"]),
+    Unit::new("km", Length, 1000.0).names(&["km", "kilometer", "kilometre"]),
+    Unit::new("in", Length, 0.0254).names(&["in", "inch", "inches"]),
+    Unit::new("ft", Length, 0.3048).names(&["ft", "foot", "feet"]),
+    Unit::new("yd", Length, 0.9144).names(&["yd", "yard"]),
+    Unit::new("mi", Length, 1609.344).names(&["mi", "mile"]),
+    Unit::new("nmi", Length, 1852.0).names(&["nmi"]),
+    // Mass (kilogram)
+    Unit::new("mg", Mass, 1e-6).names(&["mg", "milligram"]),
+    Unit::new("g", Mass, 0.001).names(&["g", "gram"]),
+    Unit::new("kg", Mass, 1.0).names(&["kg", "kilogram", "kilo"]),
+    Unit::new("t", Mass, 1000.0).names(&["t", "tonne"]),
+    Unit::new("oz", Mass, 0.028_349_523_125).names(&["oz", "ounce"]),
+    Unit::new("lb", Mass, 0.453_592_37).names(&["lb", "pound"]),
+    Unit::new("st", Mass, 6.350_293_18).names(&["st", "stone"]),
+    // Temperature (kelvin)
+    Unit::new("°C", Temperature, 1.0)
+        .names(&["c", "°c", "celsius"])
+        .offset(273.15),
+    Unit::new("°F", Temperature, 5.0 / 9.0)
+        .names(&["f", "°f", "fahrenheit"])
+        .offset(459.67 * 5.0 / 9.0),
+    Unit::new("K", Temperature, 1.0).names(&["k", "kelvin"]),
+    // Time (second)
+    Unit::new("ms", Time, 0.001).names(&["ms", "millisecond"]),
+    Unit::new("s", Time, 1.0).names(&["s", "sec", "second"]),
+    Unit::new("min", Time, 60.0).names(&["min", "minute"]),
+    Unit::new("h", Time, 3600.0).names(&["h", "hr", "hour"]),
+    Unit::new("d", Time, 86_400.0).names(&["d", "day"]),
+    Unit::new("wk", Time, 604_800.0).names(&["wk", "week"]),
+    // Julian year of 365.25 days
+    Unit::new("yr", Time, 31_557_600.0).names(&["yr", "year"]),
+];
+
+/// Words separating the source from the target unit.
+const CONNECTIVES: &[&str] = &["to", "in", "as"];
+
+/// The result of a unit conversion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion {
+    /// The converted value
+    pub value: f64,
+    /// Symbol of the target unit
+    pub unit: &'static str,
+}
+
+/// Look up a unit by any of its spellings or their plural, ignoring case.
+fn find_unit(name: &str) -> Option<&'static Unit> {
+    let find = |name: &str| UNITS.iter().find(|unit| unit.names.contains(&name));
+    let name = name.to_lowercase();
+    find(&name).or_else(|| find(name.strip_suffix('s')?))
+}
+
+/// Convert an expression like "10 km to mi", "72 f to c" or "5ft in cm".
+///
+/// Returns None if the input isn't a conversion between two known units of
+/// the same dimension, so it can be evaluated as normal arithmetic.
+pub fn convert(input: &str) -> Option<Conversion> {
+    let tokens: Vec<&str> = input.split_whitespace().collect();
+    let [source @ .., connective, target] = tokens.as_slice() else {
+        return None;
+    };
+    if !CONNECTIVES.contains(&connective.to_lowercase().as_str()) {
+        return None;
+    }
+
+    // The amount and source unit may be separate ("10 km") or joined ("10km")
+    let (amount, source_unit) = match source {
+        [amount, unit] => (*amount, *unit),
+        [joined] => {
+            let split = joined
+                .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+                .filter(|&idx| idx > 0)?;
+            joined.split_at(split)
+        }
+        _ => return None,
+    };
+
+    let amount: f64 = amount.parse().ok()?;
+    let from = find_unit(source_unit)?;
+    let to = find_unit(target)?;
+    if from.dimension != to.dimension {
+        return None;
+    }
+
+    let base = amount * from.factor + from.offset;
+    Some(Conversion {
+        value: (base - to.offset) / to.factor,
+        unit: to.symbol,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_converts(input: &str, value: f64, unit: &str) {
+        let conversion = convert(input).unwrap_or_else(|| panic!("{input:?} didn't convert"));
+        assert!(
+            (conversion.value - value).abs() < 1e-6,
+            "{input:?} gave {}",
+            conversion.value
+        );
+        assert_eq!(conversion.unit, unit);
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_converts("10 km to mi", 6.213_711_922, "mi");
+        assert_converts("5 ft in cm", 152.4, "cm");
+        assert_converts("5ft in cm", 152.4, "cm");
+        assert_converts("2 Pounds as kg", 0.907_184_74, "kg");
+        assert_converts("90 min to h", 1.5, "h");
+        // "in" works as both unit and connective
+        assert_converts("12 in in ft", 1.0, "ft");
+    }
+
+    #[test]
+    fn test_convert_temperature() {
+        assert_converts("72 f to c", 22.222_222_222, "°C");
+        assert_converts("-40 °C to °F", -40.0, "°F");
+        assert_converts("0 c to k", 273.15, "K");
+    }
+
+    #[test]
+    fn test_not_a_conversion() {
+        assert_eq!(convert("2 + 2"), None);
+        assert_eq!(convert("10 km"), None);
+        assert_eq!(convert("10 km to kg"), None);
+        assert_eq!(convert("10 parsecs to km"), None);
+        assert_eq!(convert("ten km to mi"), None);
+    }
+}
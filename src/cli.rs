@@ -1,7 +1,7 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 
-use crate::ipc::client;
+use crate::ipc::{LaunchMode, client};
 
 #[derive(Parser)]
 #[command(name = "zlaunch")]
@@ -9,16 +9,28 @@ use crate::ipc::client;
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Commands>,
+    /// View to show. Without a subcommand, the daemon shows it once started,
+    /// or the running daemon is toggled into it.
+    #[arg(long, value_enum)]
+    pub mode: Option<LaunchMode>,
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
     /// Show the launcher window
-    Show,
+    Show {
+        /// View to open
+        #[arg(long, value_enum, default_value_t)]
+        mode: LaunchMode,
+    },
     /// Hide the launcher window
     Hide,
     /// Toggle the launcher window visibility
-    Toggle,
+    Toggle {
+        /// View to open when showing the window
+        #[arg(long, value_enum, default_value_t)]
+        mode: LaunchMode,
+    },
     /// Quit the daemon
     Quit,
     /// Theme management
@@ -46,14 +58,14 @@ pub fn handle_client_command(cmd: Commands) -> Result<()> {
     }
 
     match cmd {
-        Commands::Show => {
-            client::show()?;
+        Commands::Show { mode } => {
+            client::show(mode)?;
         }
         Commands::Hide => {
             client::hide()?;
         }
-        Commands::Toggle => {
-            client::toggle()?;
+        Commands::Toggle { mode } => {
+            client::toggle(mode)?;
         }
         Commands::Quit => {
             client::quit()?;
@@ -1,6 +1,7 @@
 //! Clipboard copy utilities.
 
-use arboard::Clipboard;
+use super::item::{ClipboardContent, ClipboardItem, ClipboardSelection};
+use arboard::{Clipboard, LinuxClipboardKind, SetExtLinux};
 
 /// Copy text to the system clipboard.
 ///
@@ -14,6 +15,48 @@ pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
         .map_err(|e| format!("Failed to copy to clipboard: {}", e))
 }
 
+/// Copy text to the given selection (regular clipboard or primary selection).
+///
+/// Returns `Ok(())` on success, or an error message on failure.
+pub fn copy_to_selection(text: &str, selection: ClipboardSelection) -> Result<(), String> {
+    let kind = match selection {
+        ClipboardSelection::Clipboard => return copy_to_clipboard(text),
+        ClipboardSelection::Primary => LinuxClipboardKind::Primary,
+    };
+
+    let mut clipboard =
+        Clipboard::new().map_err(|e| format!("Failed to access clipboard: {}", e))?;
+
+    clipboard
+        .set()
+        .clipboard(kind)
+        .text(text.to_string())
+        .map_err(|e| format!("Failed to copy to primary selection: {}", e))
+}
+
+/// Copy HTML to the given selection, with `plain` for apps that only accept text.
+///
+/// Returns `Ok(())` on success, or an error message on failure.
+pub fn copy_html_to_selection(
+    html: &str,
+    plain: &str,
+    selection: ClipboardSelection,
+) -> Result<(), String> {
+    let kind = match selection {
+        ClipboardSelection::Clipboard => LinuxClipboardKind::Clipboard,
+        ClipboardSelection::Primary => LinuxClipboardKind::Primary,
+    };
+
+    let mut clipboard =
+        Clipboard::new().map_err(|e| format!("Failed to access clipboard: {}", e))?;
+
+    clipboard
+        .set()
+        .clipboard(kind)
+        .html(html, Some(plain))
+        .map_err(|e| format!("Failed to copy rich text to clipboard: {}", e))
+}
+
 /// Copy an RGBA image to the system clipboard.
 ///
 /// Returns `Ok(())` on success, or an error message on failure.
@@ -35,3 +78,36 @@ pub fn copy_image_to_clipboard(
         .set_image(image_data)
         .map_err(|e| format!("Failed to copy image to clipboard: {}", e))
 }
+
+/// Copy a clipboard history item back into the given selection.
+///
+/// Images always go to the regular clipboard, and rich text is copied as
+/// plain text unless `formatted` is set.
+///
+/// Returns `Ok(())` on success, or an error message on failure.
+pub fn copy_item(
+    item: &ClipboardItem,
+    selection: ClipboardSelection,
+    formatted: bool,
+) -> Result<(), String> {
+    match &item.content {
+        ClipboardContent::Text(text) => copy_to_selection(text, selection),
+        ClipboardContent::Image {
+            width,
+            height,
+            rgba_bytes,
+        } => copy_image_to_clipboard(*width, *height, rgba_bytes),
+        ClipboardContent::FilePaths(paths) => {
+            let text = paths
+                .iter()
+                .filter_map(|p| p.to_str())
+                .collect::<Vec<_>>()
+                .join("\n");
+            copy_to_selection(&text, selection)
+        }
+        ClipboardContent::RichText { plain, html } if formatted => {
+            copy_html_to_selection(html, plain, selection)
+        }
+        ClipboardContent::RichText { plain, .. } => copy_to_selection(plain, selection),
+    }
+}
@@ -1,37 +1,178 @@
 //! Clipboard history data storage and search.
 
-use super::item::{ClipboardContent, ClipboardItem};
+use super::item::{ClipboardContent, ClipboardItem, ClipboardSelection};
+use super::persist;
 use fuzzy_matcher::FuzzyMatcher;
 use fuzzy_matcher::skim::SkimMatcherV2;
-use std::collections::VecDeque;
-use std::sync::RwLock;
+use regex::Regex;
+use std::collections::{HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::SystemTime;
 
 /// Global clipboard history storage.
 static CLIPBOARD_HISTORY: RwLock<Option<VecDeque<ClipboardItem>>> = RwLock::new(None);
 
-/// Initialize the clipboard history storage.
+/// Number of times items were evicted, so open views can tell they're stale.
+static EVICTIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Compiled exclude patterns, with the configured patterns they came from.
+static EXCLUDE_REGEXES: Mutex<Option<(Vec<String>, Vec<Regex>)>> = Mutex::new(None);
+
+/// Initialize the clipboard history storage, restoring the persisted history.
 pub fn init() {
     let mut history = CLIPBOARD_HISTORY.write().unwrap();
     if history.is_none() {
-        *history = Some(VecDeque::new());
+        *history = Some(persist::load());
     }
 }
 
+/// Write a snapshot of the history to disk (in the background).
+fn persist_history(history: &VecDeque<ClipboardItem>) {
+    persist::save(history.iter().cloned().collect());
+}
+
 /// Add a new item to clipboard history.
-/// If the item is identical to the most recent one, it won't be added.
-pub fn add_item(content: ClipboardContent) {
+/// If identical content from the same selection is already in history, the
+/// existing item is moved to the top instead of adding a duplicate.
+pub fn add_item(content: ClipboardContent, source: ClipboardSelection) {
+    if is_excluded(&content, &exclude_regexes()) {
+        tracing::debug!("Skipping clipboard entry matching an exclude pattern");
+        return;
+    }
+
     let mut history = CLIPBOARD_HISTORY.write().unwrap();
     let history = history.as_mut().expect("Clipboard history not initialized");
 
-    // Don't add duplicate consecutive items
-    if let Some(last) = history.front()
-        && is_same_content(&last.content, &content)
+    let max_items = crate::config::config().clipboard_max_items;
+    if insert_item(history, content, source, max_items) {
+        EVICTIONS.fetch_add(1, Ordering::Relaxed);
+    }
+    persist_history(history);
+}
+
+/// Get the number of times items were evicted from the history.
+pub fn eviction_count() -> u64 {
+    EVICTIONS.load(Ordering::Relaxed)
+}
+
+/// Get the compiled exclude patterns of the current config.
+///
+/// Patterns are only compiled again when the config changes, so each
+/// invalid pattern is logged once.
+fn exclude_regexes() -> Vec<Regex> {
+    let patterns = crate::config::config().clipboard_exclude_patterns;
+    let mut cache = EXCLUDE_REGEXES.lock().unwrap();
+    match cache.as_ref() {
+        Some((source, regexes)) if *source == patterns => regexes.clone(),
+        _ => {
+            let regexes = compile_patterns(&patterns);
+            *cache = Some((patterns, regexes.clone()));
+            regexes
+        }
+    }
+}
+
+/// Compile exclude patterns, logging and skipping invalid ones.
+fn compile_patterns(patterns: &[String]) -> Vec<Regex> {
+    patterns
+        .iter()
+        .filter_map(|pattern| match Regex::new(pattern) {
+            Ok(regex) => Some(regex),
+            Err(e) => {
+                tracing::warn!(%e, "Invalid clipboard exclude pattern {:?}", pattern);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Check whether text content matches one of the exclude patterns.
+fn is_excluded(content: &ClipboardContent, regexes: &[Regex]) -> bool {
+    let text = match content {
+        ClipboardContent::Text(text) => text,
+        ClipboardContent::RichText { plain, .. } => plain,
+        ClipboardContent::Image { .. } | ClipboardContent::FilePaths(_) => return false,
+    };
+
+    regexes.iter().any(|regex| regex.is_match(text))
+}
+
+/// Insert content at the top of the history, de-duplicating and evicting.
+///
+/// Returns whether older items were evicted.
+fn insert_item(
+    history: &mut VecDeque<ClipboardItem>,
+    content: ClipboardContent,
+    source: ClipboardSelection,
+    max_items: usize,
+) -> bool {
+    // Move an identical existing item to the top and refresh its timestamp
+    if let Some(pos) = history
+        .iter()
+        .position(|item| item.source == source && is_same_content(&item.content, &content))
     {
-        return;
+        if let Some(mut existing) = history.remove(pos) {
+            existing.timestamp = SystemTime::now();
+            history.push_front(existing);
+        }
+        return false;
     }
 
-    let item = ClipboardItem::new(content);
+    let item = ClipboardItem::new(content).with_source(source);
     history.push_front(item);
+    evict_overflow(history, max_items)
+}
+
+/// Drop the oldest unpinned items once there are more than `max_items`.
+///
+/// Returns whether any item was dropped.
+fn evict_overflow(history: &mut VecDeque<ClipboardItem>, max_items: usize) -> bool {
+    let mut unpinned = history.iter().filter(|item| !item.pinned).count();
+    let evicted = unpinned > max_items;
+    while unpinned > max_items {
+        if let Some(pos) = history.iter().rposition(|item| !item.pinned) {
+            history.remove(pos);
+        }
+        unpinned -= 1;
+    }
+    evicted
+}
+
+/// Set the pinned flag of the item with the given id.
+/// Returns false if no such item exists.
+pub fn set_pinned(id: u64, pinned: bool) -> bool {
+    let mut history = CLIPBOARD_HISTORY.write().unwrap();
+    let Some(history) = history.as_mut() else {
+        return false;
+    };
+
+    match history.iter_mut().find(|item| item.id == id) {
+        Some(item) => {
+            item.pinned = pinned;
+            persist_history(history);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Replace the tags of the item with the given id.
+/// Returns false if no such item exists.
+pub fn set_tags(id: u64, tags: Vec<String>) -> bool {
+    let mut history = CLIPBOARD_HISTORY.write().unwrap();
+    let Some(history) = history.as_mut() else {
+        return false;
+    };
+
+    match history.iter_mut().find(|item| item.id == id) {
+        Some(item) => {
+            item.tags = tags;
+            persist_history(history);
+            true
+        }
+        None => false,
+    }
 }
 
 /// Check if two clipboard contents are the same.
@@ -65,6 +206,24 @@ fn is_same_content(a: &ClipboardContent, b: &ClipboardContent) -> bool {
     }
 }
 
+/// Remove the item with the given id from history.
+/// Returns false if no such item exists.
+pub fn remove_item(id: u64) -> bool {
+    let mut history = CLIPBOARD_HISTORY.write().unwrap();
+    let Some(history) = history.as_mut() else {
+        return false;
+    };
+
+    match history.iter().position(|item| item.id == id) {
+        Some(pos) => {
+            history.remove(pos);
+            persist_history(history);
+            true
+        }
+        None => false,
+    }
+}
+
 /// Get all clipboard items, optionally filtered by a search query.
 pub fn search_items(query: &str) -> Vec<ClipboardItem> {
     let history = CLIPBOARD_HISTORY.read().unwrap();
@@ -99,6 +258,48 @@ pub fn search_items(query: &str) -> Vec<ClipboardItem> {
     scored.into_iter().map(|(item, _)| item).collect()
 }
 
+/// Get the most recent items in history, newest first.
+///
+/// Empty while the history isn't initialized.
+pub fn recent_items(count: usize) -> Vec<ClipboardItem> {
+    let history = CLIPBOARD_HISTORY.read().unwrap();
+    history
+        .as_ref()
+        .map(|h| h.iter().take(count).cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Get the text of the latest clipboard item (not the primary selection).
+///
+/// None if the history is empty or the latest item isn't text.
+pub fn latest_text() -> Option<String> {
+    let history = CLIPBOARD_HISTORY.read().unwrap();
+    let item = history
+        .as_ref()?
+        .iter()
+        .find(|item| item.source == ClipboardSelection::Clipboard)?;
+    match &item.content {
+        ClipboardContent::Text(text) => Some(text.clone()),
+        ClipboardContent::RichText { plain, .. } => Some(plain.clone()),
+        ClipboardContent::Image { .. } | ClipboardContent::FilePaths(_) => None,
+    }
+}
+
+/// Get the item with the given id, if it's still in history.
+pub fn get_item(id: u64) -> Option<ClipboardItem> {
+    let history = CLIPBOARD_HISTORY.read().unwrap();
+    history.as_ref()?.iter().find(|item| item.id == id).cloned()
+}
+
+/// Get the ids of all items in history.
+pub fn item_ids() -> HashSet<u64> {
+    let history = CLIPBOARD_HISTORY.read().unwrap();
+    history
+        .as_ref()
+        .map(|h| h.iter().map(|item| item.id).collect())
+        .unwrap_or_default()
+}
+
 /// Get the total number of items in history.
 pub fn item_count() -> usize {
     let history = CLIPBOARD_HISTORY.read().unwrap();
@@ -106,10 +307,128 @@ pub fn item_count() -> usize {
 }
 
 /// Clear all clipboard history.
-#[allow(dead_code)]
 pub fn clear_history() {
     let mut history = CLIPBOARD_HISTORY.write().unwrap();
     if let Some(h) = history.as_mut() {
         h.clear();
+        persist_history(h);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_text_is_not_added_twice() {
+        let mut history = VecDeque::new();
+        let text = || ClipboardContent::Text("hello".to_string());
+
+        insert_item(&mut history, text(), ClipboardSelection::Clipboard, 200);
+        insert_item(&mut history, text(), ClipboardSelection::Clipboard, 200);
+
+        assert_eq!(history.len(), 1);
+    }
+
+    #[test]
+    fn test_duplicate_moves_to_top() {
+        let mut history = VecDeque::new();
+        let text = |s: &str| ClipboardContent::Text(s.to_string());
+
+        insert_item(&mut history, text("a"), ClipboardSelection::Clipboard, 200);
+        insert_item(&mut history, text("b"), ClipboardSelection::Clipboard, 200);
+        let id = history[1].id;
+        insert_item(&mut history, text("a"), ClipboardSelection::Clipboard, 200);
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].id, id);
+        assert!(matches!(&history[0].content, ClipboardContent::Text(t) if t == "a"));
+    }
+
+    #[test]
+    fn test_duplicate_image_and_rich_text() {
+        let mut history = VecDeque::new();
+        let image = |b: u8| ClipboardContent::Image {
+            width: 1,
+            height: 1,
+            rgba_bytes: vec![b, b, b, 255].into(),
+        };
+        let rich = |html: &str| ClipboardContent::RichText {
+            plain: "text".to_string(),
+            html: html.to_string(),
+        };
+
+        insert_item(&mut history, image(1), ClipboardSelection::Clipboard, 200);
+        insert_item(&mut history, image(1), ClipboardSelection::Clipboard, 200);
+        insert_item(&mut history, image(2), ClipboardSelection::Clipboard, 200);
+        insert_item(
+            &mut history,
+            rich("<b>text</b>"),
+            ClipboardSelection::Clipboard,
+            200,
+        );
+        insert_item(
+            &mut history,
+            rich("<i>text</i>"),
+            ClipboardSelection::Clipboard,
+            200,
+        );
+        insert_item(
+            &mut history,
+            rich("<b>text</b>"),
+            ClipboardSelection::Clipboard,
+            200,
+        );
+
+        assert_eq!(history.len(), 4);
+    }
+
+    #[test]
+    fn test_evicts_oldest_unpinned() {
+        let mut history = VecDeque::new();
+        let text = |s: &str| ClipboardContent::Text(s.to_string());
+
+        assert!(!insert_item(
+            &mut history,
+            text("a"),
+            ClipboardSelection::Clipboard,
+            2
+        ));
+        history[0].pinned = true;
+        insert_item(&mut history, text("b"), ClipboardSelection::Clipboard, 2);
+        insert_item(&mut history, text("c"), ClipboardSelection::Clipboard, 2);
+        assert!(insert_item(
+            &mut history,
+            text("d"),
+            ClipboardSelection::Clipboard,
+            2
+        ));
+
+        let texts: Vec<_> = history
+            .iter()
+            .map(|item| match &item.content {
+                ClipboardContent::Text(t) => t.as_str(),
+                _ => "",
+            })
+            .collect();
+        assert_eq!(texts, vec!["d", "c", "a"]);
+    }
+
+    #[test]
+    fn test_exclude_patterns() {
+        let patterns = compile_patterns(&[r"^\d{6}$".to_string(), "(invalid".to_string()]);
+        assert_eq!(patterns.len(), 1);
+        let text = |s: &str| ClipboardContent::Text(s.to_string());
+
+        assert!(is_excluded(&text("123456"), &patterns));
+        assert!(!is_excluded(&text("1234567"), &patterns));
+        assert!(!is_excluded(&text("hello"), &[]));
+        assert!(is_excluded(
+            &ClipboardContent::RichText {
+                plain: "000000".to_string(),
+                html: "<b>000000</b>".to_string(),
+            },
+            &patterns
+        ));
     }
 }
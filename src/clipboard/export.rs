@@ -0,0 +1,80 @@
+//! Saving clipboard images to files.
+
+use std::path::{Path, PathBuf};
+
+/// Get the directory clipboard images are saved to.
+///
+/// This is `screenshots_dir` from the config (a leading `~` is the home
+/// directory), or the pictures directory when unset.
+pub fn images_dir() -> Option<PathBuf> {
+    match crate::config::config().screenshots_dir {
+        Some(dir) => match dir.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir().map(|home| home.join(rest)),
+            None if dir == "~" => dirs::home_dir(),
+            None => Some(PathBuf::from(dir)),
+        },
+        None => dirs::picture_dir().or_else(dirs::home_dir),
+    }
+}
+
+/// Save an RGBA image as a timestamped PNG in `dir`, creating the directory
+/// if needed.
+///
+/// Returns the path of the written file, or an error message on failure.
+pub fn save_image(
+    width: usize,
+    height: usize,
+    rgba_bytes: &[u8],
+    dir: &Path,
+) -> Result<PathBuf, String> {
+    let buffer = image::RgbaImage::from_raw(width as u32, height as u32, rgba_bytes.to_vec())
+        .ok_or("Invalid image data")?;
+
+    std::fs::create_dir_all(dir).map_err(|e| format!("Can't create {}: {}", dir.display(), e))?;
+    let path = unique_path(
+        dir,
+        &chrono::Local::now().format("%Y-%m-%d_%H-%M-%S").to_string(),
+    );
+
+    buffer
+        .save_with_format(&path, image::ImageFormat::Png)
+        .map_err(|e| format!("Can't save {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// Get a path for `clipboard-<stamp>.png` in `dir` that isn't taken yet.
+fn unique_path(dir: &Path, stamp: &str) -> PathBuf {
+    let path = dir.join(format!("clipboard-{}.png", stamp));
+    if !path.exists() {
+        return path;
+    }
+    (2..)
+        .map(|n| dir.join(format!("clipboard-{}-{}.png", stamp, n)))
+        .find(|path| !path.exists())
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_image() {
+        let dir = std::env::temp_dir().join(format!("zlaunch-test-export-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let pixels = [255u8, 0, 0, 255].repeat(4);
+        let first = save_image(2, 2, &pixels, &dir).unwrap();
+        let second = save_image(2, 2, &pixels, &dir).unwrap();
+        assert_ne!(first, second);
+
+        let saved = image::open(&first).unwrap().to_rgba8();
+        assert_eq!(saved.dimensions(), (2, 2));
+        assert_eq!(saved.into_raw(), pixels);
+
+        // The pixel data has to match the size
+        assert!(save_image(3, 3, &pixels, &dir).is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
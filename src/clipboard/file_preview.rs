@@ -0,0 +1,273 @@
+//! Reading files for the clipboard preview.
+//!
+//! Files are read on the background executor, since a slow disk or network
+//! filesystem would otherwise stall rendering. Binary files are detected
+//! from their first bytes and summarized instead of read whole.
+
+use crate::ui::utils::format_bytes;
+use std::io::Read;
+use std::path::Path;
+
+/// Largest text file read for the preview.
+pub const MAX_FILE_PREVIEW_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Number of leading bytes checked to tell binary files from text.
+const SNIFF_BYTES: u64 = 8192;
+
+/// Number of leading bytes of a binary file shown as a hex dump.
+const HEX_DUMP_BYTES: usize = 256;
+
+/// Progress of reading a file for the preview.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileReadState {
+    /// The file is still being read
+    Loading,
+    /// The contents of the file
+    Done(FileContent),
+    /// Why the file couldn't be read
+    Failed(String),
+}
+
+/// Contents of a file read for the preview.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileContent {
+    /// The whole text
+    Text(String),
+    /// A summary of a binary file
+    Binary(BinarySummary),
+}
+
+/// What's shown of a binary file instead of its contents.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BinarySummary {
+    /// Size of the file in bytes
+    pub size: u64,
+    /// Kind of file, e.g. "ZIP archive"
+    pub kind: &'static str,
+    /// MIME type of the file
+    pub mime: &'static str,
+    /// The first bytes of the file
+    pub head: Vec<u8>,
+}
+
+/// Known binary formats: offset and bytes of their signature, kind and
+/// MIME type.
+const SIGNATURES: &[(usize, &[u8], &str, &str)] = &[
+    (0, b"\x7fELF", "ELF executable", "application/x-executable"),
+    (
+        0,
+        b"MZ",
+        "Windows executable",
+        "application/vnd.microsoft.portable-executable",
+    ),
+    (0, b"\0asm", "WebAssembly module", "application/wasm"),
+    (0, b"PK\x03\x04", "ZIP archive", "application/zip"),
+    (0, b"\x1f\x8b", "gzip archive", "application/gzip"),
+    (0, b"BZh", "bzip2 archive", "application/x-bzip2"),
+    (0, b"\xfd7zXZ\0", "XZ archive", "application/x-xz"),
+    (
+        0,
+        b"\x28\xb5\x2f\xfd",
+        "Zstandard archive",
+        "application/zstd",
+    ),
+    (
+        0,
+        b"7z\xbc\xaf\x27\x1c",
+        "7-Zip archive",
+        "application/x-7z-compressed",
+    ),
+    (0, b"Rar!\x1a\x07", "RAR archive", "application/vnd.rar"),
+    (257, b"ustar", "tar archive", "application/x-tar"),
+    (0, b"%PDF-", "PDF document", "application/pdf"),
+    (
+        0,
+        b"SQLite format 3\0",
+        "SQLite database",
+        "application/vnd.sqlite3",
+    ),
+    (0, b"\x89PNG", "PNG image", "image/png"),
+    (0, b"\xff\xd8\xff", "JPEG image", "image/jpeg"),
+    (0, b"GIF8", "GIF image", "image/gif"),
+    (0, b"OggS", "Ogg media", "application/ogg"),
+    (0, b"fLaC", "FLAC audio", "audio/flac"),
+    (0, b"ID3", "MP3 audio", "audio/mpeg"),
+    (4, b"ftyp", "MP4 media", "video/mp4"),
+    (0, b"\x1aE\xdf\xa3", "Matroska media", "video/x-matroska"),
+];
+
+/// Read a file for the preview.
+///
+/// Returns the text, a summary if the file is binary, or an error message if
+/// the file can't be read or is a text file larger than `max_bytes`.
+pub fn read_file(path: &Path, max_bytes: u64) -> Result<FileContent, String> {
+    let name = path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().to_string(),
+    );
+    let cant_read = |e: std::io::Error| format!("Can't read {}: {}", name, e);
+
+    let metadata = std::fs::metadata(path).map_err(cant_read)?;
+    if metadata.is_dir() {
+        return Err(format!("{} is a folder", name));
+    }
+    let size = metadata.len();
+
+    let mut head = Vec::new();
+    std::fs::File::open(path)
+        .and_then(|file| file.take(SNIFF_BYTES).read_to_end(&mut head))
+        .map_err(cant_read)?;
+    if is_binary(&head) {
+        return Ok(FileContent::Binary(summarize(head, size)));
+    }
+    if size > max_bytes {
+        return Err(format!(
+            "{} is too large to preview ({})",
+            name,
+            format_bytes(size)
+        ));
+    }
+
+    match std::fs::read_to_string(path) {
+        Ok(text) => Ok(FileContent::Text(text)),
+        // Invalid UTF-8 past the checked bytes
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData => {
+            Ok(FileContent::Binary(summarize(head, size)))
+        }
+        Err(e) => Err(cant_read(e)),
+    }
+}
+
+/// Check whether the first bytes of a file look binary: they contain a NUL
+/// byte or aren't valid UTF-8.
+///
+/// A UTF-8 sequence cut off at the end doesn't count, since `head` may end
+/// in the middle of a character.
+fn is_binary(head: &[u8]) -> bool {
+    head.contains(&0) || std::str::from_utf8(head).is_err_and(|e| e.error_len().is_some())
+}
+
+/// Summarize a binary file from its first bytes.
+fn summarize(mut head: Vec<u8>, size: u64) -> BinarySummary {
+    let (kind, mime) = SIGNATURES
+        .iter()
+        .find(|(offset, magic, _, _)| {
+            head.get(*offset..)
+                .is_some_and(|rest| rest.starts_with(magic))
+        })
+        .map_or(
+            ("Binary file", "application/octet-stream"),
+            |&(_, _, kind, mime)| (kind, mime),
+        );
+    head.truncate(HEX_DUMP_BYTES);
+    BinarySummary {
+        size,
+        kind,
+        mime,
+        head,
+    }
+}
+
+/// Format bytes as a hex dump, 16 bytes a line with their offset and the
+/// printable ASCII characters:
+///
+/// `00000000  7f 45 4c 46 02 01 01 00  00 00 00 00 00 00 00 00  |.ELF............|`
+pub fn hex_dump(bytes: &[u8]) -> String {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(line, chunk)| {
+            let mut hex = String::new();
+            for (idx, byte) in chunk.iter().enumerate() {
+                if idx == 8 {
+                    hex.push(' ');
+                }
+                hex.push_str(&format!("{:02x} ", byte));
+            }
+            let ascii: String = chunk
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii_graphic() || byte == b' ' {
+                        byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            format!("{:08x}  {:<49} |{}|", line * 16, hex, ascii)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_file() {
+        let dir =
+            std::env::temp_dir().join(format!("zlaunch-test-file-preview-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("notes.txt");
+        std::fs::write(&path, "hello\nworld").unwrap();
+
+        assert_eq!(
+            read_file(&path, 100),
+            Ok(FileContent::Text("hello\nworld".to_string()))
+        );
+        assert_eq!(
+            read_file(&path, 4),
+            Err("notes.txt is too large to preview (11 B)".to_string())
+        );
+        assert!(
+            read_file(&dir.join("missing.txt"), 100)
+                .unwrap_err()
+                .starts_with("Can't read missing.txt: ")
+        );
+        assert!(read_file(&dir, 100).unwrap_err().ends_with("is a folder"));
+
+        // Binary files are summarized whatever their size and extension
+        let mut elf = b"\x7fELF\x02\x01\x01".to_vec();
+        elf.resize(1000, 0);
+        std::fs::write(&path, &elf).unwrap();
+        let Ok(FileContent::Binary(summary)) = read_file(&path, 100) else {
+            panic!("expected a binary summary");
+        };
+        assert_eq!(summary.size, 1000);
+        assert_eq!(summary.kind, "ELF executable");
+        assert_eq!(summary.head, elf[..HEX_DUMP_BYTES]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_is_binary() {
+        assert!(!is_binary("grüße\n".as_bytes()));
+        assert!(is_binary(b"text\0more"));
+        assert!(is_binary(&[0xff, 0xfe, b'a']));
+        // A character cut off by the end of the checked bytes
+        assert!(!is_binary(&"ü".as_bytes()[..1]));
+    }
+
+    #[test]
+    fn test_summarize_kind() {
+        let kind = |head: &[u8]| summarize(head.to_vec(), 0).kind;
+        assert_eq!(kind(b"PK\x03\x04rest"), "ZIP archive");
+        assert_eq!(kind(b"\0\0\0\x20ftypisom"), "MP4 media");
+        let mut tar = vec![0; 300];
+        tar[257..262].copy_from_slice(b"ustar");
+        assert_eq!(kind(&tar), "tar archive");
+        assert_eq!(summarize(vec![1, 2, 3], 0).mime, "application/octet-stream");
+    }
+
+    #[test]
+    fn test_hex_dump() {
+        let bytes: Vec<u8> = b"\x7fELF".iter().copied().chain(0x41..0x51).collect();
+        assert_eq!(
+            hex_dump(&bytes),
+            "00000000  7f 45 4c 46 41 42 43 44  45 46 47 48 49 4a 4b 4c  |.ELFABCDEFGHIJKL|\n\
+             00000010  4d 4e 4f 50                                       |MNOP|"
+        );
+    }
+}
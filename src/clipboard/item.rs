@@ -1,25 +1,61 @@
 //! Clipboard item data structures.
 
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::SystemTime;
 
+/// Counter used to hand out unique clipboard item ids.
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
 /// Represents a single clipboard history entry.
 #[derive(Clone, Debug)]
 pub struct ClipboardItem {
+    /// Unique id of this entry (stable while the daemon runs)
+    pub id: u64,
     pub content: ClipboardContent,
     pub timestamp: SystemTime,
+    /// Selection the item was captured from
+    pub source: ClipboardSelection,
+    /// Pinned items are listed first and never evicted
+    pub pinned: bool,
+    /// Lowercase tags assigned by the user (searched with `#tag`)
+    pub tags: Vec<String>,
+}
+
+/// The selection a clipboard item was captured from (or is copied to).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipboardSelection {
+    /// The regular clipboard (Ctrl+C)
+    #[default]
+    Clipboard,
+    /// The primary selection (select to copy, middle-click to paste)
+    Primary,
+}
+
+impl ClipboardSelection {
+    /// Get a human readable label for the selection.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Clipboard => "Clipboard",
+            Self::Primary => "Primary",
+        }
+    }
 }
 
 /// The content type of a clipboard item.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Hash)]
 pub enum ClipboardContent {
     /// Plain text content
     Text(String),
-    /// Image data with dimensions (raw RGBA pixel data)
+    /// Image data with dimensions (raw RGBA pixel data, shared so history
+    /// snapshots don't copy it)
     Image {
         width: usize,
         height: usize,
-        rgba_bytes: Vec<u8>,
+        rgba_bytes: Arc<[u8]>,
     },
     /// File path(s) copied from file manager
     FilePaths(Vec<PathBuf>),
@@ -31,11 +67,21 @@ impl ClipboardItem {
     /// Create a new clipboard item with the current timestamp.
     pub fn new(content: ClipboardContent) -> Self {
         Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
             content,
             timestamp: SystemTime::now(),
+            source: ClipboardSelection::Clipboard,
+            pinned: false,
+            tags: Vec::new(),
         }
     }
 
+    /// Set the selection this item was captured from.
+    pub fn with_source(mut self, source: ClipboardSelection) -> Self {
+        self.source = source;
+        self
+    }
+
     /// Get a short preview string for display in the list.
     pub fn preview(&self) -> String {
         const MAX_LENGTH: usize = 30;
@@ -86,6 +132,23 @@ impl ClipboardItem {
         }
     }
 
+    /// Get the files the item refers to: copied files, or text of
+    /// `file://` URLs (one per line).
+    pub fn file_paths(&self) -> Vec<PathBuf> {
+        match &self.content {
+            ClipboardContent::FilePaths(paths) => paths.clone(),
+            ClipboardContent::Text(text) => text
+                .lines()
+                .filter_map(|line| line.trim().strip_prefix("file://"))
+                .map(|path| match urlencoding::decode(path) {
+                    Ok(decoded) => PathBuf::from(decoded.as_ref()),
+                    Err(_) => PathBuf::from(path),
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
     /// Check if this item is a text file that can be previewed.
     pub fn is_previewable_file(&self) -> bool {
         if let ClipboardContent::FilePaths(paths) = &self.content
@@ -1,9 +1,17 @@
-//! Clipboard history management and copy utilities.
+//! Clipboard history management, persistence and copy utilities.
 
 mod copy;
 pub mod data;
+pub mod export;
+pub mod file_preview;
 pub mod item;
 pub mod monitor;
+pub mod ocr;
+mod persist;
+mod selection_offer;
 
-pub use copy::{copy_image_to_clipboard, copy_to_clipboard};
-pub use item::{ClipboardContent, ClipboardItem};
+pub use copy::{
+    copy_html_to_selection, copy_image_to_clipboard, copy_item, copy_to_clipboard,
+    copy_to_selection,
+};
+pub use item::{ClipboardContent, ClipboardItem, ClipboardSelection};
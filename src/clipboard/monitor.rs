@@ -1,15 +1,25 @@
 //! Clipboard monitoring using Wayland data-control protocol.
+//!
+//! Changes are picked up from data-control events as they happen. Compositors
+//! without the protocol send no events, so there the clipboard can be polled
+//! instead. Polling misses content replaced again within one interval and
+//! costs some reads per tick, so it is opt-in through
+//! `clipboard_poll_interval_ms`.
 
 use super::data;
-use super::item::ClipboardContent;
-use arboard::Clipboard;
+use super::item::{ClipboardContent, ClipboardSelection};
+use super::selection_offer::{Offer, SelectionOffers};
+use arboard::{Clipboard, GetExtLinux, LinuxClipboardKind};
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
 use std::time::Duration;
 use tracing::{debug, error, info};
+use wayland_client::backend::ObjectId;
 use wayland_client::protocol::{wl_registry, wl_seat};
-use wayland_client::{Connection, Dispatch, QueueHandle};
+use wayland_client::{Connection, Dispatch, Proxy, QueueHandle};
 use wayland_protocols_wlr::data_control::v1::client::{
     zwlr_data_control_device_v1, zwlr_data_control_manager_v1, zwlr_data_control_offer_v1,
     zwlr_data_control_source_v1,
@@ -20,9 +30,34 @@ struct ClipboardMonitorState {
     manager: Option<zwlr_data_control_manager_v1::ZwlrDataControlManagerV1>,
     seat: Option<wl_seat::WlSeat>,
     device: Option<zwlr_data_control_device_v1::ZwlrDataControlDeviceV1>,
+    /// Offers carrying a password manager hint, which must not be recorded
+    sensitive_offers: HashSet<ObjectId>,
     running: Arc<AtomicBool>,
 }
 
+impl ClipboardMonitorState {
+    /// Check whether a selection offer was marked as sensitive.
+    ///
+    /// Offers are announced right before the selection event that uses them,
+    /// so the tracked offers are reset afterwards.
+    fn take_sensitive(
+        &mut self,
+        offer: &zwlr_data_control_offer_v1::ZwlrDataControlOfferV1,
+    ) -> bool {
+        let sensitive = self.sensitive_offers.contains(&offer.id());
+        self.sensitive_offers.clear();
+        sensitive
+    }
+}
+
+/// Check whether a MIME type marks the clipboard content as sensitive.
+///
+/// KeePassXC and other password managers set `x-kde-passwordManagerHint`,
+/// some apps use a "concealed" type instead.
+fn is_sensitive_mime_type(mime_type: &str) -> bool {
+    mime_type == "x-kde-passwordManagerHint" || mime_type.contains("concealed")
+}
+
 /// Start monitoring clipboard changes in a background thread.
 pub fn start_monitor() -> Arc<AtomicBool> {
     let running = Arc::new(AtomicBool::new(true));
@@ -31,8 +66,18 @@ pub fn start_monitor() -> Arc<AtomicBool> {
     thread::spawn(move || {
         info!("Starting clipboard monitor");
 
-        if let Err(e) = run_monitor(running_clone) {
-            error!("Clipboard monitor error: {}", e);
+        let Err(e) = run_monitor(running_clone.clone()) else {
+            return;
+        };
+        match crate::config::clipboard_poll_interval() {
+            Some(interval) => {
+                info!(
+                    "Clipboard monitor unavailable ({}), polling every {:?}",
+                    e, interval
+                );
+                run_poller(running_clone, interval);
+            }
+            None => error!("Clipboard monitor error: {}", e),
         }
     });
 
@@ -53,6 +98,7 @@ fn run_monitor(running: Arc<AtomicBool>) -> Result<(), Box<dyn std::error::Error
         manager: None,
         seat: None,
         device: None,
+        sensitive_offers: HashSet::new(),
         running,
     };
 
@@ -152,7 +198,7 @@ impl Dispatch<zwlr_data_control_manager_v1::ZwlrDataControlManagerV1, ()>
 
 impl Dispatch<zwlr_data_control_device_v1::ZwlrDataControlDeviceV1, ()> for ClipboardMonitorState {
     fn event(
-        _: &mut Self,
+        state: &mut Self,
         _: &zwlr_data_control_device_v1::ZwlrDataControlDeviceV1,
         event: zwlr_data_control_device_v1::Event,
         _: &(),
@@ -161,16 +207,31 @@ impl Dispatch<zwlr_data_control_device_v1::ZwlrDataControlDeviceV1, ()> for Clip
     ) {
         match event {
             zwlr_data_control_device_v1::Event::Selection { id } => {
-                if id.is_some() {
+                if let Some(offer) = id {
+                    if state.take_sensitive(&offer) {
+                        debug!("Skipping sensitive clipboard content");
+                        return;
+                    }
                     debug!("Clipboard selection changed");
                     // Clipboard changed, read the new content
-                    if let Err(e) = read_clipboard_content() {
+                    if let Err(e) = read_clipboard_content(ClipboardSelection::Clipboard) {
                         error!("Failed to read clipboard: {}", e);
                     }
                 }
             }
-            zwlr_data_control_device_v1::Event::PrimarySelection { .. } => {
-                // Ignore primary selection for now
+            zwlr_data_control_device_v1::Event::PrimarySelection { id } => {
+                if let Some(offer) = id
+                    && crate::config::config().capture_primary_selection
+                {
+                    if state.take_sensitive(&offer) {
+                        debug!("Skipping sensitive primary selection content");
+                        return;
+                    }
+                    debug!("Primary selection changed");
+                    if let Err(e) = read_clipboard_content(ClipboardSelection::Primary) {
+                        error!("Failed to read primary selection: {}", e);
+                    }
+                }
             }
             _ => {}
         }
@@ -191,13 +252,18 @@ impl Dispatch<zwlr_data_control_device_v1::ZwlrDataControlDeviceV1, ()> for Clip
 
 impl Dispatch<zwlr_data_control_offer_v1::ZwlrDataControlOfferV1, ()> for ClipboardMonitorState {
     fn event(
-        _: &mut Self,
-        _: &zwlr_data_control_offer_v1::ZwlrDataControlOfferV1,
-        _: zwlr_data_control_offer_v1::Event,
+        state: &mut Self,
+        offer: &zwlr_data_control_offer_v1::ZwlrDataControlOfferV1,
+        event: zwlr_data_control_offer_v1::Event,
         _: &(),
         _: &Connection,
         _: &QueueHandle<Self>,
     ) {
+        if let zwlr_data_control_offer_v1::Event::Offer { mime_type } = event
+            && is_sensitive_mime_type(&mime_type)
+        {
+            state.sensitive_offers.insert(offer.id());
+        }
     }
 }
 
@@ -213,40 +279,141 @@ impl Dispatch<zwlr_data_control_source_v1::ZwlrDataControlSourceV1, ()> for Clip
     }
 }
 
-/// Read the current clipboard content and add it to history.
-fn read_clipboard_content() -> Result<(), Box<dyn std::error::Error>> {
+/// Poll the selections for new content, for compositors without data-control.
+///
+/// Content is only added when it differs from the previous read of the same
+/// selection, so unchanged content isn't moved to the top of the history and
+/// written to disk again on every tick. Offers marked as sensitive are
+/// skipped like with data-control.
+fn run_poller(running: Arc<AtomicBool>, interval: Duration) {
+    let mut clipboard = match Clipboard::new() {
+        Ok(clipboard) => clipboard,
+        Err(e) => {
+            error!("Failed to open clipboard for polling: {}", e);
+            return;
+        }
+    };
+    let mut offers = SelectionOffers::connect();
+    if offers.is_none() {
+        info!("Can't query the offered clipboard types, reading the content to poll it");
+    }
+    let mut last_read: HashMap<ClipboardSelection, u64> = HashMap::new();
+
+    while running.load(Ordering::Relaxed) {
+        for source in [ClipboardSelection::Clipboard, ClipboardSelection::Primary] {
+            if source == ClipboardSelection::Primary
+                && !crate::config::config().capture_primary_selection
+            {
+                continue;
+            }
+
+            let offer = offers.as_mut().and_then(|offers| offers.offer(source));
+            if let Some(offer) = &offer
+                && offer.types.iter().any(|t| is_sensitive_mime_type(t))
+            {
+                debug!("Skipping sensitive {:?} content", source);
+                continue;
+            }
+
+            // Skip unchanged content before reading it, images especially
+            let cheap_fingerprint = offer
+                .as_ref()
+                .and_then(|offer| offer_fingerprint(offer, &mut clipboard, source));
+            if cheap_fingerprint.is_some() && last_read.get(&source) == cheap_fingerprint.as_ref() {
+                continue;
+            }
+
+            let Some(content) = read_selection(&mut clipboard, source) else {
+                // Content copied again after the selection was cleared is new
+                last_read.remove(&source);
+                continue;
+            };
+            let fingerprint = cheap_fingerprint.unwrap_or_else(|| fingerprint(&content));
+            if last_read.insert(source, fingerprint) == Some(fingerprint) {
+                continue;
+            }
+            debug!("Polled new {:?} content", source);
+            data::add_item(content, source);
+        }
+        thread::sleep(interval);
+    }
+
+    info!("Clipboard polling stopped");
+}
+
+/// Tell whether the content of a selection changed without reading images.
+///
+/// The copy timestamp changes with every copy, otherwise the offered types
+/// and the text do, unless only an image is offered. None when the content
+/// has to be read to tell.
+fn offer_fingerprint(
+    offer: &Offer,
+    clipboard: &mut Clipboard,
+    source: ClipboardSelection,
+) -> Option<u64> {
+    if offer.timestamp.is_some() {
+        return Some(fingerprint(offer));
+    }
+    if offer.has_image() {
+        return None;
+    }
+    let text = clipboard.get().clipboard(linux_kind(source)).text().ok();
+    Some(fingerprint(&(offer, text)))
+}
+
+/// Hash a value to compare polled content with the previous read.
+fn fingerprint(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Read the current content of a selection and add it to history.
+fn read_clipboard_content(source: ClipboardSelection) -> Result<(), Box<dyn std::error::Error>> {
     // Small delay to let clipboard settle
     thread::sleep(Duration::from_millis(50));
 
     let mut clipboard = Clipboard::new()?;
+    if let Some(content) = read_selection(&mut clipboard, source) {
+        debug!("Adding {:?} content to clipboard history", source);
+        data::add_item(content, source);
+    }
+    Ok(())
+}
+
+/// Get arboard's name of a selection.
+fn linux_kind(source: ClipboardSelection) -> LinuxClipboardKind {
+    match source {
+        ClipboardSelection::Clipboard => LinuxClipboardKind::Clipboard,
+        ClipboardSelection::Primary => LinuxClipboardKind::Primary,
+    }
+}
+
+/// Read the current content of a selection, None if it is empty.
+fn read_selection(
+    clipboard: &mut Clipboard,
+    source: ClipboardSelection,
+) -> Option<ClipboardContent> {
+    let kind = linux_kind(source);
 
     // Try to get image first - browsers often put both image data and HTML markup
     // on the clipboard, and we prefer the actual image over the HTML representation
-    if let Ok(image) = clipboard.get_image()
+    if let Ok(image) = clipboard.get().clipboard(kind).image()
         && !image.bytes.is_empty()
     {
-        debug!(
-            "Adding image to clipboard history: {}×{} ({} bytes)",
-            image.width,
-            image.height,
-            image.bytes.len()
-        );
-        data::add_item(ClipboardContent::Image {
+        return Some(ClipboardContent::Image {
             width: image.width,
             height: image.height,
-            rgba_bytes: image.bytes.to_vec(),
+            rgba_bytes: Arc::from(image.bytes.as_ref()),
         });
-        return Ok(());
     }
 
     // Try to get text
-    if let Ok(text) = clipboard.get_text()
+    if let Ok(text) = clipboard.get().clipboard(kind).text()
         && !text.is_empty()
     {
-        debug!("Adding text to clipboard history: {} chars", text.len());
-        data::add_item(ClipboardContent::Text(text));
-        return Ok(());
+        return Some(ClipboardContent::Text(text));
     }
 
-    Ok(())
+    None
 }
@@ -0,0 +1,98 @@
+//! Text recognition in clipboard images.
+//!
+//! Uses the `tesseract` command, which is only run on demand and is optional:
+//! without it, recognition fails with a message saying it's missing.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Progress of the text recognition for an image.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OcrState {
+    /// Tesseract is still running
+    Running,
+    /// The recognized text
+    Done(String),
+    /// Why no text could be recognized
+    Failed(String),
+}
+
+/// Recognize the text in an RGBA image.
+///
+/// Returns the text, or an error message if tesseract isn't installed,
+/// fails, or finds no text.
+pub fn recognize_text(width: usize, height: usize, rgba_bytes: &[u8]) -> Result<String, String> {
+    let buffer = image::RgbaImage::from_raw(width as u32, height as u32, rgba_bytes.to_vec())
+        .ok_or("Invalid image data")?;
+    let mut png_bytes = Vec::new();
+    buffer
+        .write_to(
+            &mut std::io::Cursor::new(&mut png_bytes),
+            image::ImageFormat::Png,
+        )
+        .map_err(|e| format!("Can't encode image: {}", e))?;
+
+    let mut child = Command::new("tesseract")
+        .args(["stdin", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => {
+                "Text recognition needs tesseract, which isn't installed".to_string()
+            }
+            _ => format!("Can't run tesseract: {}", e),
+        })?;
+
+    // Dropping stdin after the write closes it, so tesseract starts reading
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(&png_bytes)
+            .map_err(|e| format!("Can't pass the image to tesseract: {}", e))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Tesseract failed: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Tesseract failed: {}", stderr.trim()));
+    }
+
+    clean_text(&String::from_utf8_lossy(&output.stdout)).ok_or("No text found".to_string())
+}
+
+/// Tidy up tesseract's output, None if there's no text.
+///
+/// Drops the trailing form feed and whitespace at the end of lines, and
+/// collapses runs of blank lines into one.
+fn clean_text(output: &str) -> Option<String> {
+    let mut lines: Vec<&str> = Vec::new();
+    for line in output
+        .lines()
+        .map(|line| line.trim_end_matches(['\x0c', ' ', '\t']))
+    {
+        if line.is_empty() && lines.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_text() {
+        assert_eq!(
+            clean_text("\nHello  \nworld\n\n\n\nSecond paragraph\n\n\x0c").as_deref(),
+            Some("Hello\nworld\n\nSecond paragraph")
+        );
+        assert_eq!(clean_text(" \n\x0c\n"), None);
+    }
+}
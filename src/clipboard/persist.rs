@@ -0,0 +1,441 @@
+//! Clipboard history persistence.
+//!
+//! The history is stored as JSON in `~/.local/share/zlaunch/clipboard.json`.
+//! Image entries are written as separate PNG files in the `clipboard-images`
+//! directory next to it and referenced by path, so the JSON stays small.
+//! Both are only readable by the user, since the history may hold secrets.
+
+use super::item::{ClipboardContent, ClipboardItem, ClipboardSelection};
+use crate::util::state_file::{self, create_private_dir, write_private_file};
+use flume::Sender;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::{Duration, UNIX_EPOCH};
+
+/// File name of the persisted history.
+const HISTORY_FILE: &str = "clipboard.json";
+
+/// Directory (inside the data dir) holding persisted images.
+const IMAGES_DIR: &str = "clipboard-images";
+
+/// Limits applied when writing the history to disk.
+#[derive(Clone, Copy, Debug)]
+pub struct PersistLimits {
+    /// Maximum number of entries written (pinned entries always are)
+    pub max_items: usize,
+    /// Maximum total size of persisted images in bytes
+    pub max_image_bytes: u64,
+}
+
+impl PersistLimits {
+    /// Read the limits from the app config.
+    pub fn from_config() -> Self {
+        let config = crate::config::config();
+        Self {
+            max_items: config.clipboard_persist_max_items,
+            max_image_bytes: config.clipboard_persist_max_image_mb * 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PersistedHistory {
+    items: Vec<PersistedItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedItem {
+    content: PersistedContent,
+    /// Seconds since the Unix epoch
+    timestamp: u64,
+    #[serde(default)]
+    source: ClipboardSelection,
+    #[serde(default)]
+    pinned: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum PersistedContent {
+    Text {
+        text: String,
+    },
+    Image {
+        width: usize,
+        height: usize,
+        path: PathBuf,
+    },
+    FilePaths {
+        paths: Vec<PathBuf>,
+    },
+    RichText {
+        plain: String,
+        html: String,
+    },
+}
+
+/// Load the persisted history.
+///
+/// A missing or corrupt file results in an empty history.
+pub fn load() -> VecDeque<ClipboardItem> {
+    match state_file::data_dir() {
+        Some(dir) => load_from(&dir),
+        None => VecDeque::new(),
+    }
+}
+
+/// Save the history in the background.
+///
+/// Saves are handled by a single writer thread which only writes the most
+/// recent snapshot, so rapid changes don't pile up disk writes. Snapshots
+/// share the image data of the history, so taking one is cheap.
+pub fn save(items: Vec<ClipboardItem>) {
+    static WRITER: OnceLock<Sender<Vec<ClipboardItem>>> = OnceLock::new();
+
+    let writer = WRITER.get_or_init(|| {
+        let (tx, rx) = flume::unbounded::<Vec<ClipboardItem>>();
+        std::thread::spawn(move || {
+            let mut image_paths = HashMap::new();
+            while let Ok(mut items) = rx.recv() {
+                // Skip to the latest snapshot
+                while let Ok(newer) = rx.try_recv() {
+                    items = newer;
+                }
+
+                let Some(dir) = state_file::data_dir() else {
+                    continue;
+                };
+                let limits = PersistLimits::from_config();
+                if let Err(e) = save_to(&dir, &items, limits, &mut image_paths) {
+                    tracing::warn!(%e, "Failed to persist clipboard history");
+                }
+            }
+        });
+        tx
+    });
+
+    let _ = writer.send(items);
+}
+
+/// Load the history from the given directory.
+fn load_from(dir: &Path) -> VecDeque<ClipboardItem> {
+    let persisted: PersistedHistory = state_file::load_from(dir, HISTORY_FILE, "clipboard history");
+    persisted
+        .items
+        .into_iter()
+        .filter_map(|item| {
+            let content = match item.content {
+                PersistedContent::Text { text } => ClipboardContent::Text(text),
+                PersistedContent::FilePaths { paths } => ClipboardContent::FilePaths(paths),
+                PersistedContent::RichText { plain, html } => {
+                    ClipboardContent::RichText { plain, html }
+                }
+                PersistedContent::Image { path, .. } => match image::open(&path) {
+                    Ok(img) => {
+                        let rgba = img.to_rgba8();
+                        ClipboardContent::Image {
+                            width: rgba.width() as usize,
+                            height: rgba.height() as usize,
+                            rgba_bytes: rgba.into_raw().into(),
+                        }
+                    }
+                    Err(e) => {
+                        tracing::debug!(%e, "Dropping persisted image {:?}", path);
+                        return None;
+                    }
+                },
+            };
+
+            let mut restored = ClipboardItem::new(content).with_source(item.source);
+            restored.timestamp = UNIX_EPOCH + Duration::from_secs(item.timestamp);
+            restored.pinned = item.pinned;
+            restored.tags = item.tags;
+            Some(restored)
+        })
+        .collect()
+}
+
+/// Write the history to the given directory, applying the limits.
+///
+/// Only written items count towards `max_items`, so images over the size
+/// budget don't take the place of other items. `image_paths` keeps the file
+/// of each image item across saves, so its pixels are only hashed once.
+fn save_to(
+    dir: &Path,
+    items: &[ClipboardItem],
+    limits: PersistLimits,
+    image_paths: &mut HashMap<u64, PathBuf>,
+) -> anyhow::Result<()> {
+    let images_dir = dir.join(IMAGES_DIR);
+    create_private_dir(&images_dir)?;
+
+    let mut persisted = PersistedHistory::default();
+    let mut written_images = HashSet::new();
+    let mut image_bytes = 0u64;
+    let mut unpinned = 0usize;
+
+    for item in items {
+        if !item.pinned && unpinned >= limits.max_items {
+            continue;
+        }
+
+        let content = match &item.content {
+            ClipboardContent::Text(text) => PersistedContent::Text { text: text.clone() },
+            ClipboardContent::FilePaths(paths) => PersistedContent::FilePaths {
+                paths: paths.clone(),
+            },
+            ClipboardContent::RichText { plain, html } => PersistedContent::RichText {
+                plain: plain.clone(),
+                html: html.clone(),
+            },
+            ClipboardContent::Image {
+                width,
+                height,
+                rgba_bytes,
+            } => {
+                let path = image_paths
+                    .entry(item.id)
+                    .or_insert_with(|| image_path(&images_dir, *width, *height, rgba_bytes))
+                    .clone();
+                let size = match std::fs::metadata(&path) {
+                    // Written by an earlier save
+                    Ok(metadata) => metadata.len(),
+                    // Only encoded while there's budget left, only written if it fits
+                    Err(_) => {
+                        if image_bytes >= limits.max_image_bytes {
+                            continue;
+                        }
+                        let Some(png) = encode_png(*width, *height, rgba_bytes) else {
+                            continue;
+                        };
+                        let size = png.len() as u64;
+                        if image_bytes + size > limits.max_image_bytes {
+                            continue;
+                        }
+                        if let Err(e) = write_private_file(&path, &png) {
+                            tracing::warn!(%e, "Failed to write clipboard image {:?}", path);
+                            continue;
+                        }
+                        size
+                    }
+                };
+                if image_bytes + size > limits.max_image_bytes {
+                    continue;
+                }
+                image_bytes += size;
+                written_images.insert(path.clone());
+                PersistedContent::Image {
+                    width: *width,
+                    height: *height,
+                    path,
+                }
+            }
+        };
+
+        let timestamp = item
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        if !item.pinned {
+            unpinned += 1;
+        }
+        persisted.items.push(PersistedItem {
+            content,
+            timestamp,
+            source: item.source,
+            pinned: item.pinned,
+            tags: item.tags.clone(),
+        });
+    }
+
+    state_file::save_to(dir, HISTORY_FILE, &persisted)?;
+
+    // Forget the files of items that left the history
+    let ids: HashSet<u64> = items.iter().map(|item| item.id).collect();
+    image_paths.retain(|id, _| ids.contains(id));
+
+    // Remove images that are no longer referenced
+    if let Ok(entries) = std::fs::read_dir(&images_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !written_images.contains(&path) {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+    tracing::debug!(count = persisted.items.len(), "Persisted clipboard history");
+    Ok(())
+}
+
+/// Get the path an image is persisted at, named by a hash of its pixels.
+fn image_path(images_dir: &Path, width: usize, height: usize, rgba_bytes: &[u8]) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    (width, height).hash(&mut hasher);
+    rgba_bytes.hash(&mut hasher);
+    images_dir.join(format!("{:016x}.png", hasher.finish()))
+}
+
+/// Encode an RGBA image as PNG.
+fn encode_png(width: usize, height: usize, rgba_bytes: &[u8]) -> Option<Vec<u8>> {
+    let buffer = image::RgbaImage::from_raw(width as u32, height as u32, rgba_bytes.to_vec())?;
+    let mut png = Vec::new();
+    match buffer.write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png) {
+        Ok(()) => Some(png),
+        Err(e) => {
+            tracing::warn!(%e, "Failed to encode clipboard image");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("zlaunch-test-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let dir = temp_dir("persist-round-trip");
+        let limits = PersistLimits {
+            max_items: 10,
+            max_image_bytes: 1024 * 1024,
+        };
+
+        let mut pinned = ClipboardItem::new(ClipboardContent::Text("pinned".to_string()));
+        pinned.pinned = true;
+        pinned.tags = vec!["work".to_string()];
+        let items = vec![
+            ClipboardItem::new(ClipboardContent::Text("hello".to_string())),
+            pinned,
+            ClipboardItem::new(ClipboardContent::Image {
+                width: 1,
+                height: 1,
+                rgba_bytes: vec![255, 0, 0, 255].into(),
+            }),
+        ];
+
+        save_to(&dir, &items, limits, &mut HashMap::new()).unwrap();
+        let loaded = load_from(&dir);
+
+        assert_eq!(loaded.len(), 3);
+        assert!(matches!(&loaded[0].content, ClipboardContent::Text(t) if t == "hello"));
+        assert!(loaded[1].pinned);
+        assert_eq!(loaded[1].tags, ["work"]);
+        assert!(loaded[0].tags.is_empty());
+        assert!(matches!(
+            &loaded[2].content,
+            ClipboardContent::Image { width: 1, height: 1, rgba_bytes } if **rgba_bytes == [255, 0, 0, 255]
+        ));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_item_limit_keeps_pinned() {
+        let dir = temp_dir("persist-limit");
+        let limits = PersistLimits {
+            max_items: 1,
+            max_image_bytes: 0,
+        };
+
+        let mut pinned = ClipboardItem::new(ClipboardContent::Text("old pinned".to_string()));
+        pinned.pinned = true;
+        let items = vec![
+            ClipboardItem::new(ClipboardContent::Text("new".to_string())),
+            ClipboardItem::new(ClipboardContent::Text("old".to_string())),
+            pinned,
+        ];
+
+        save_to(&dir, &items, limits, &mut HashMap::new()).unwrap();
+        let loaded = load_from(&dir);
+
+        assert_eq!(loaded.len(), 2);
+        assert!(matches!(&loaded[0].content, ClipboardContent::Text(t) if t == "new"));
+        assert!(loaded[1].pinned);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_skipped_images_dont_count() {
+        let dir = temp_dir("persist-skipped-images");
+        let limits = PersistLimits {
+            max_items: 2,
+            max_image_bytes: 0,
+        };
+
+        let items = vec![
+            ClipboardItem::new(ClipboardContent::Image {
+                width: 1,
+                height: 1,
+                rgba_bytes: vec![255, 0, 0, 255].into(),
+            }),
+            ClipboardItem::new(ClipboardContent::Text("a".to_string())),
+            ClipboardItem::new(ClipboardContent::Text("b".to_string())),
+        ];
+
+        save_to(&dir, &items, limits, &mut HashMap::new()).unwrap();
+        assert_eq!(load_from(&dir).len(), 2);
+        // Images over the budget are never written
+        assert_eq!(std::fs::read_dir(dir.join(IMAGES_DIR)).unwrap().count(), 0);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_files_are_private() {
+        let dir = temp_dir("persist-private");
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let limits = PersistLimits {
+            max_items: 10,
+            max_image_bytes: 1024 * 1024,
+        };
+        let items = vec![
+            ClipboardItem::new(ClipboardContent::Text("secret".to_string())),
+            ClipboardItem::new(ClipboardContent::Image {
+                width: 1,
+                height: 1,
+                rgba_bytes: vec![255, 0, 0, 255].into(),
+            }),
+        ];
+
+        save_to(&dir, &items, limits, &mut HashMap::new()).unwrap();
+        let mode = |path: &Path| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&dir), 0o700);
+        assert_eq!(mode(&dir.join(IMAGES_DIR)), 0o700);
+        assert_eq!(mode(&dir.join(HISTORY_FILE)), 0o600);
+        for image in std::fs::read_dir(dir.join(IMAGES_DIR)).unwrap() {
+            assert_eq!(mode(&image.unwrap().path()), 0o600);
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_corrupt_file_starts_empty() {
+        let dir = temp_dir("persist-corrupt");
+        std::fs::write(dir.join(HISTORY_FILE), "{ not json").unwrap();
+
+        assert!(load_from(&dir).is_empty());
+        assert!(load_from(&dir.join("missing")).is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
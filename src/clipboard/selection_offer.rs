@@ -0,0 +1,166 @@
+//! Types offered for the X11 selections, for the polling fallback.
+//!
+//! Without data-control, the selections are read through X11 (XWayland),
+//! which also tells which MIME types the owner offers (the `TARGETS` of the
+//! selection) and when it was copied (its `TIMESTAMP`), without reading the
+//! content itself.
+
+use super::item::ClipboardSelection;
+use std::collections::HashMap;
+use std::thread;
+use std::time::{Duration, Instant};
+use x11rb::connection::Connection;
+use x11rb::protocol::Event;
+use x11rb::protocol::xproto::{
+    Atom, AtomEnum, ConnectionExt, CreateWindowAux, Window, WindowClass,
+};
+use x11rb::rust_connection::RustConnection;
+
+/// How long the selection owner has to answer.
+const REPLY_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// What a selection offers.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct Offer {
+    /// Offered MIME types and other targets
+    pub types: Vec<String>,
+    /// When the selection was copied, if the owner tells
+    pub timestamp: Option<u32>,
+}
+
+impl Offer {
+    /// Check whether an image is offered.
+    pub fn has_image(&self) -> bool {
+        self.types.iter().any(|t| t.starts_with("image/"))
+    }
+}
+
+/// Connection for querying the offers of the selections.
+pub struct SelectionOffers {
+    conn: RustConnection,
+    /// Unmapped window receiving the answers
+    window: Window,
+    clipboard: Atom,
+    targets: Atom,
+    timestamp: Atom,
+    /// Property of `window` the answers are stored in
+    property: Atom,
+    /// Names of the atoms of offered types
+    names: HashMap<Atom, String>,
+}
+
+impl SelectionOffers {
+    /// Connect to the X server, None if there is none.
+    pub fn connect() -> Option<Self> {
+        let (conn, screen) = x11rb::connect(None).ok()?;
+        let root = conn.setup().roots.get(screen)?.root;
+        let window = conn.generate_id().ok()?;
+        conn.create_window(
+            x11rb::COPY_DEPTH_FROM_PARENT,
+            window,
+            root,
+            0,
+            0,
+            1,
+            1,
+            0,
+            WindowClass::INPUT_ONLY,
+            x11rb::COPY_FROM_PARENT,
+            &CreateWindowAux::new(),
+        )
+        .ok()?;
+
+        let intern = |name: &str| -> Option<Atom> {
+            Some(
+                conn.intern_atom(false, name.as_bytes())
+                    .ok()?
+                    .reply()
+                    .ok()?
+                    .atom,
+            )
+        };
+        let clipboard = intern("CLIPBOARD")?;
+        let targets = intern("TARGETS")?;
+        let timestamp = intern("TIMESTAMP")?;
+        let property = intern("ZLAUNCH_SELECTION")?;
+        Some(Self {
+            conn,
+            window,
+            clipboard,
+            targets,
+            timestamp,
+            property,
+            names: HashMap::new(),
+        })
+    }
+
+    /// Get what a selection offers, None if it's empty or the owner doesn't
+    /// answer.
+    pub fn offer(&mut self, source: ClipboardSelection) -> Option<Offer> {
+        let selection = match source {
+            ClipboardSelection::Clipboard => self.clipboard,
+            ClipboardSelection::Primary => AtomEnum::PRIMARY.into(),
+        };
+        let types = self
+            .convert(selection, self.targets)?
+            .into_iter()
+            .filter_map(|atom| self.atom_name(atom))
+            .collect();
+        let timestamp = self
+            .convert(selection, self.timestamp)
+            .and_then(|values| values.first().copied());
+        Some(Offer { types, timestamp })
+    }
+
+    /// Ask the owner of a selection to convert it to `target`, returning the
+    /// answer as 32-bit values.
+    fn convert(&mut self, selection: Atom, target: Atom) -> Option<Vec<u32>> {
+        self.conn
+            .convert_selection(
+                self.window,
+                selection,
+                target,
+                self.property,
+                x11rb::CURRENT_TIME,
+            )
+            .ok()?;
+        self.conn.flush().ok()?;
+
+        let deadline = Instant::now() + REPLY_TIMEOUT;
+        loop {
+            match self.conn.poll_for_event().ok()? {
+                Some(Event::SelectionNotify(event))
+                    if event.selection == selection && event.target == target =>
+                {
+                    // No owner, or it can't convert to the target
+                    if event.property == x11rb::NONE {
+                        return None;
+                    }
+                    break;
+                }
+                Some(_) => {}
+                None if Instant::now() < deadline => thread::sleep(Duration::from_millis(5)),
+                None => return None,
+            }
+        }
+
+        let reply = self
+            .conn
+            .get_property(true, self.window, self.property, AtomEnum::ANY, 0, 1024)
+            .ok()?
+            .reply()
+            .ok()?;
+        Some(reply.value32()?.collect())
+    }
+
+    /// Get the name of an atom, looked up once.
+    fn atom_name(&mut self, atom: Atom) -> Option<String> {
+        if let Some(name) = self.names.get(&atom) {
+            return Some(name.clone());
+        }
+        let reply = self.conn.get_atom_name(atom).ok()?.reply().ok()?;
+        let name = String::from_utf8_lossy(&reply.name).to_string();
+        self.names.insert(atom, name.clone());
+        Some(name)
+    }
+}
@@ -4,39 +4,75 @@ use super::Compositor;
 use super::hyprland::HyprlandCompositor;
 use super::kwin::KwinCompositor;
 use super::niri::NiriCompositor;
-use super::noop::NoopCompositor;
-use tracing::{info, warn};
+use super::null::NullCompositor;
+use super::sway::SwayCompositor;
+use tracing::info;
+
+/// A supported backend: its `XDG_CURRENT_DESKTOP` name and constructor.
+struct Backend {
+    desktop: &'static str,
+    name: &'static str,
+    connect: fn() -> Option<Box<dyn Compositor>>,
+}
+
+/// Supported backends in default detection order.
+const BACKENDS: &[Backend] = &[
+    Backend {
+        desktop: "hyprland",
+        name: "Hyprland",
+        connect: || HyprlandCompositor::new().map(|c| Box::new(c) as Box<dyn Compositor>),
+    },
+    Backend {
+        desktop: "kde",
+        name: "KWin",
+        connect: || KwinCompositor::new().map(|c| Box::new(c) as Box<dyn Compositor>),
+    },
+    Backend {
+        desktop: "niri",
+        name: "Niri",
+        connect: || NiriCompositor::new().map(|c| Box::new(c) as Box<dyn Compositor>),
+    },
+    Backend {
+        desktop: "sway",
+        name: "Sway",
+        connect: || SwayCompositor::new().map(|c| Box::new(c) as Box<dyn Compositor>),
+    },
+];
 
 /// Detect and create the appropriate compositor client.
 ///
-/// Detection order:
+/// Each backend checks for its own environment variable / IPC socket:
 /// 1. Hyprland (via HYPRLAND_INSTANCE_SIGNATURE env var)
 /// 2. KDE/KWin (via KDE_SESSION_VERSION env var)
 /// 3. Niri     (via NIRI_SOCKET env var)
-/// 3. Fallback to NoopCompositor
+/// 4. Sway     (via SWAYSOCK env var and socket presence)
+/// 5. Fallback to NullCompositor
 ///
-/// The NoopCompositor allows the launcher to function (with applications only)
+/// Backends named in `XDG_CURRENT_DESKTOP` are tried first, so a leftover
+/// variable from a nested or previous session doesn't pick the wrong one.
+///
+/// The NullCompositor allows the launcher to function (with applications only)
 /// even on unsupported compositors.
 pub fn detect_compositor() -> Box<dyn Compositor> {
-    // Try Hyprland first
-    if let Some(compositor) = HyprlandCompositor::new() {
-        info!("Detected Hyprland compositor");
-        return Box::new(compositor);
-    }
+    let desktops = std::env::var("XDG_CURRENT_DESKTOP")
+        .unwrap_or_default()
+        .to_lowercase();
+    let is_current = |backend: &Backend| {
+        desktops
+            .split(':')
+            .any(|desktop| desktop == backend.desktop)
+    };
 
-    // Try KWin
-    if let Some(compositor) = KwinCompositor::new() {
-        info!("Detected KWin compositor");
-        return Box::new(compositor);
-    }
+    let preferred = BACKENDS.iter().filter(|b| is_current(b));
+    let others = BACKENDS.iter().filter(|b| !is_current(b));
 
-    // Try Niri
-    if let Some(compositor) = NiriCompositor::new() {
-        info!("Detected Niri compositor");
-        return Box::new(compositor);
+    for backend in preferred.chain(others) {
+        if let Some(compositor) = (backend.connect)() {
+            info!("Detected {} compositor", backend.name);
+            return compositor;
+        }
     }
 
-    // Fallback to no-op
-    warn!("No supported compositor detected, window switching disabled");
-    Box::new(NoopCompositor)
+    // Fallback to the null backend (logs its own warning)
+    Box::new(NullCompositor::new())
 }
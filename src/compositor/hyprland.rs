@@ -3,6 +3,7 @@
 use super::{Compositor, WindowInfo};
 use anyhow::{Context, Result};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::io::{Read, Write};
 use std::os::unix::net::UnixStream;
 use std::path::PathBuf;
@@ -45,6 +46,12 @@ impl HyprlandCompositor {
 
         Ok(response)
     }
+
+    /// List the monitors.
+    fn monitors(&self) -> Result<Vec<HyprlandMonitor>> {
+        let json = self.send_command("j/monitors")?;
+        serde_json::from_str(&json).context("Failed to parse Hyprland monitors JSON")
+    }
 }
 
 impl Compositor for HyprlandCompositor {
@@ -53,6 +60,17 @@ impl Compositor for HyprlandCompositor {
         let json = self.send_command("j/clients")?;
         let clients: Vec<HyprlandClient> =
             serde_json::from_str(&json).context("Failed to parse Hyprland clients JSON")?;
+        // Clients only reference their monitor by id
+        let monitors = self.monitors().unwrap_or_else(|e| {
+            tracing::debug!(%e, "Failed to list Hyprland monitors");
+            Vec::new()
+        });
+        let active_workspace = monitors
+            .iter()
+            .find(|m| m.focused)
+            .map(|m| m.active_workspace.id);
+        let monitor_names: HashMap<i32, String> =
+            monitors.into_iter().map(|m| (m.id, m.name)).collect();
 
         let windows = clients
             .into_iter()
@@ -84,6 +102,8 @@ impl Compositor for HyprlandCompositor {
                     },
                     class: c.class,
                     workspace,
+                    monitor: monitor_names.get(&c.monitor).cloned(),
+                    on_active_workspace: active_workspace.is_none_or(|id| id == workspace),
                     focused,
                 }
             })
@@ -98,6 +118,12 @@ impl Compositor for HyprlandCompositor {
         Ok(())
     }
 
+    fn close_window(&self, window_id: &str) -> Result<()> {
+        let cmd = format!("dispatch closewindow address:{}", window_id);
+        self.send_command(&cmd)?;
+        Ok(())
+    }
+
     fn name(&self) -> &'static str {
         "Hyprland"
     }
@@ -110,6 +136,9 @@ struct HyprlandClient {
     title: String,
     class: String,
     workspace: HyprlandWorkspace,
+    /// Id of the monitor, -1 if the window isn't on one
+    #[serde(default = "no_monitor")]
+    monitor: i32,
     /// 0 means currently focused, higher numbers mean less recently focused
     #[serde(rename = "focusHistoryID")]
     focus_history_id: i32,
@@ -126,12 +155,28 @@ impl HyprlandClient {
     }
 }
 
+fn no_monitor() -> i32 {
+    -1
+}
+
 /// Hyprland workspace information.
 #[derive(Debug, Deserialize)]
 struct HyprlandWorkspace {
     id: i32,
 }
 
+/// Hyprland monitor information from IPC.
+#[derive(Debug, Deserialize)]
+struct HyprlandMonitor {
+    id: i32,
+    name: String,
+    /// Workspace currently shown on the monitor
+    #[serde(rename = "activeWorkspace")]
+    active_workspace: HyprlandWorkspace,
+    #[serde(default)]
+    focused: bool,
+}
+
 /// Apply blur layer rules for zlaunch on Hyprland.
 ///
 /// This sets up transparency and blur effects via Hyprland IPC.
@@ -1,7 +1,7 @@
 //! KDE KWin compositor implementation using D-Bus scripting API.
 //!
-//! Uses KWin's JavaScript scripting interface via D-Bus to enumerate and
-//! focus windows. This works for both native Wayland and XWayland windows.
+//! Uses KWin's JavaScript scripting interface via D-Bus to enumerate, focus
+//! and close windows. This works for both native Wayland and XWayland windows.
 
 use super::{Compositor, WindowInfo};
 use anyhow::{Context, Result, anyhow};
@@ -28,11 +28,17 @@ const LIST_WINDOWS_SCRIPT: &str = r#"(function() {
         if (w.desktops && w.desktops.length > 0 && w.desktops[0]) {
             desk = w.desktops[0].x11DesktopNumber || 1;
         }
+        var current = w.onAllDesktops;
+        for (var j = 0; w.desktops && j < w.desktops.length; j++) {
+            if (w.desktops[j] === workspace.currentDesktop) current = true;
+        }
         result.push({
             id: w.internalId.toString(),
             title: w.caption || w.resourceClass,
             class: w.resourceClass,
             workspace: desk,
+            monitor: w.output ? w.output.name : null,
+            current: current,
             focused: w.active
         });
     }
@@ -53,6 +59,19 @@ const FOCUS_WINDOW_SCRIPT_TEMPLATE: &str = r#"(function() {
 })();
 "#;
 
+/// JavaScript script template to close a window by its internal ID.
+const CLOSE_WINDOW_SCRIPT_TEMPLATE: &str = r#"(function() {
+    var targetId = '%WINDOW_ID%';
+    var windows = workspace.windowList();
+    for (var i = 0; i < windows.length; i++) {
+        if (windows[i].internalId.toString() === targetId) {
+            windows[i].closeWindow();
+            return;
+        }
+    }
+})();
+"#;
+
 /// Window information from KWin script JSON output.
 #[derive(Debug, Deserialize)]
 struct KwinWindow {
@@ -60,6 +79,10 @@ struct KwinWindow {
     title: String,
     class: String,
     workspace: i32,
+    #[serde(default)]
+    monitor: Option<String>,
+    /// Whether the window is on the current virtual desktop
+    current: bool,
     focused: bool,
 }
 
@@ -221,6 +244,8 @@ impl Compositor for KwinCompositor {
                 title: w.title,
                 class: w.class,
                 workspace: w.workspace,
+                monitor: w.monitor,
+                on_active_workspace: w.current,
                 focused: w.focused,
             })
             .collect())
@@ -232,6 +257,12 @@ impl Compositor for KwinCompositor {
         Ok(())
     }
 
+    fn close_window(&self, window_id: &str) -> Result<()> {
+        let script = CLOSE_WINDOW_SCRIPT_TEMPLATE.replace("%WINDOW_ID%", window_id);
+        self.run_script(&script)?;
+        Ok(())
+    }
+
     fn name(&self) -> &'static str {
         "KWin"
     }
@@ -2,13 +2,16 @@
 //!
 //! This module provides a trait-based abstraction for interacting with
 //! Wayland compositors to list windows and switch focus. Implementations
-//! are provided for Hyprland (IPC socket) and KDE/KWin (DBus).
+//! are provided for Hyprland (IPC socket), KDE/KWin (DBus), Niri (IPC socket)
+//! and Sway (IPC socket), with a null backend used when none of them is
+//! detected. The backend is selected at startup by [`detect_compositor`].
 
 mod detect;
 pub mod hyprland;
 mod kwin;
 mod niri;
-mod noop;
+mod null;
+mod sway;
 
 pub use detect::detect_compositor;
 
@@ -17,7 +20,11 @@ use std::fmt;
 /// Information about an open window from the compositor.
 #[derive(Debug, Clone)]
 pub struct WindowInfo {
-    /// Unique window identifier (compositor-specific, e.g., "0x5678abcd" for Hyprland)
+    /// Unique window identifier.
+    ///
+    /// Opaque to everything but the backend that produced it: Hyprland uses
+    /// the client address ("0x5678abcd"), KWin the window UUID, Niri and Sway
+    /// the numeric window / container id.
     pub address: String,
     /// Window title
     pub title: String,
@@ -25,6 +32,11 @@ pub struct WindowInfo {
     pub class: String,
     /// Workspace number
     pub workspace: i32,
+    /// Name of the monitor (output) showing the window's workspace, if known
+    pub monitor: Option<String>,
+    /// Whether the window is on the focused workspace (true when the backend
+    /// can't tell)
+    pub on_active_workspace: bool,
     /// Whether this window is currently focused
     pub focused: bool,
 }
@@ -42,9 +54,16 @@ pub trait Compositor: Send + Sync {
 
     /// Focus/activate a window by its address.
     ///
-    /// The address format is compositor-specific.
+    /// The address is the opaque [`WindowInfo::address`] reported by this
+    /// backend's [`Compositor::list_windows`].
     fn focus_window(&self, window_id: &str) -> anyhow::Result<()>;
 
+    /// Close a window by its address.
+    ///
+    /// Asks the application to close gracefully, like the compositor's own
+    /// close keybinding would.
+    fn close_window(&self, window_id: &str) -> anyhow::Result<()>;
+
     /// Get the compositor name for logging/debugging.
     fn name(&self) -> &'static str;
 }
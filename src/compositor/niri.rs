@@ -1,6 +1,7 @@
 use super::{Compositor, WindowInfo};
 use anyhow::{Context, Result, anyhow, bail};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::io::{BufRead, Write};
 use std::os::unix::net::UnixStream;
 use std::path::PathBuf;
@@ -47,6 +48,13 @@ impl Compositor for NiriCompositor {
         Ok(())
     }
 
+    fn close_window(&self, window_id: &str) -> Result<()> {
+        let newline = "\n";
+        let cmd = format!(r#"{{"Action":{{"CloseWindow":{{"id":{window_id}}}}}}}{newline}"#);
+        self.send_command(&cmd)?;
+        Ok(())
+    }
+
     fn list_windows(&self) -> Result<Vec<WindowInfo>> {
         let json_string = self.send_command("\"Windows\"\n")?;
 
@@ -57,11 +65,19 @@ impl Compositor for NiriCompositor {
             bail!("Niri returned an error to Windows request");
         };
 
+        // Windows only reference their workspace by its internal id
+        let workspaces = self.workspaces().unwrap_or_else(|e| {
+            tracing::debug!(%e, "Failed to list Niri workspaces");
+            HashMap::new()
+        });
+        let focused_workspace = workspaces.values().find(|ws| ws.is_focused).map(|ws| ws.id);
+
         let mut window_info = Vec::new();
         for window in niri_reply.windows {
             if window.app_id.to_lowercase() == "zlaunch" {
                 continue;
             }
+            let workspace = window.workspace_id.and_then(|id| workspaces.get(&id));
 
             window_info.push(WindowInfo {
                 address: format!("{}", window.id),
@@ -71,7 +87,11 @@ impl Compositor for NiriCompositor {
                     window.title
                 },
                 class: window.app_id,
-                workspace: window.workspace_id as i32,
+                // The index is the number shown by bars, unlike the id
+                workspace: workspace.map_or(-1, |ws| ws.idx as i32),
+                monitor: workspace.and_then(|ws| ws.output.clone()),
+                on_active_workspace: focused_workspace
+                    .is_none_or(|id| window.workspace_id == Some(id)),
                 focused: window.is_focused,
             });
         }
@@ -80,6 +100,26 @@ impl Compositor for NiriCompositor {
     }
 }
 
+impl NiriCompositor {
+    /// Get the workspaces by their id.
+    fn workspaces(&self) -> Result<HashMap<i64, NiriWorkspace>> {
+        let json_string = self.send_command("\"Workspaces\"\n")?;
+
+        let niri_result: std::result::Result<NiriWorkspaceReply, serde_json::Value> =
+            serde_json::from_str(&json_string).context("Failed to parse Niri workspaces JSON")?;
+
+        let Ok(niri_reply) = niri_result else {
+            bail!("Niri returned an error to Workspaces request");
+        };
+
+        Ok(niri_reply
+            .workspaces
+            .into_iter()
+            .map(|ws| (ws.id, ws))
+            .collect())
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct NiriWindowReply {
     #[serde(rename = "Windows")]
@@ -91,6 +131,22 @@ struct NiriWindow {
     id: i64,
     title: String,
     app_id: String,
-    workspace_id: i64,
+    /// None for windows not on any workspace
+    workspace_id: Option<i64>,
+    is_focused: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct NiriWorkspaceReply {
+    #[serde(rename = "Workspaces")]
+    workspaces: Vec<NiriWorkspace>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NiriWorkspace {
+    id: i64,
+    /// Position of the workspace on its monitor, starting at 1
+    idx: u8,
+    output: Option<String>,
     is_focused: bool,
 }
@@ -0,0 +1,44 @@
+//! Null compositor implementation for unsupported environments.
+
+use super::{Compositor, WindowInfo};
+
+/// A compositor backend that reports no windows.
+///
+/// Used as a fallback when no supported compositor is detected (plain X11,
+/// unsupported Wayland compositors, ...). This allows the launcher to function
+/// (applications, calculator, clipboard) even without window switching.
+pub struct NullCompositor;
+
+impl NullCompositor {
+    /// Create the null backend, logging once that window switching is unavailable.
+    pub fn new() -> Self {
+        tracing::warn!(
+            "No supported compositor detected (Hyprland, KWin, Niri, Sway), window switching disabled"
+        );
+        Self
+    }
+}
+
+impl Compositor for NullCompositor {
+    fn list_windows(&self) -> anyhow::Result<Vec<WindowInfo>> {
+        Ok(Vec::new())
+    }
+
+    fn focus_window(&self, window_id: &str) -> anyhow::Result<()> {
+        anyhow::bail!(
+            "Cannot focus window {}: no supported compositor backend detected",
+            window_id
+        )
+    }
+
+    fn close_window(&self, window_id: &str) -> anyhow::Result<()> {
+        anyhow::bail!(
+            "Cannot close window {}: no supported compositor backend detected",
+            window_id
+        )
+    }
+
+    fn name(&self) -> &'static str {
+        "Null"
+    }
+}
@@ -0,0 +1,274 @@
+//! Sway compositor implementation using the i3-compatible IPC socket.
+
+use super::{Compositor, WindowInfo};
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+
+/// Magic string starting every IPC message.
+const IPC_MAGIC: &[u8] = b"i3-ipc";
+
+/// IPC message type running a command.
+const RUN_COMMAND: u32 = 0;
+
+/// IPC message type returning the workspaces.
+const GET_WORKSPACES: u32 = 1;
+
+/// IPC message type returning the layout tree.
+const GET_TREE: u32 = 4;
+
+/// Sway compositor client using IPC socket communication.
+pub struct SwayCompositor {
+    socket_path: PathBuf,
+}
+
+impl SwayCompositor {
+    /// Create a new Sway compositor client.
+    ///
+    /// Returns None if `SWAYSOCK` is not set or the socket does not exist.
+    pub fn new() -> Option<Self> {
+        let socket_path = PathBuf::from(std::env::var("SWAYSOCK").ok()?);
+        if !socket_path.exists() {
+            return None;
+        }
+        Some(Self { socket_path })
+    }
+
+    /// Send a message to Sway and receive the response payload.
+    fn send_message(&self, message_type: u32, payload: &str) -> Result<String> {
+        let mut stream = UnixStream::connect(&self.socket_path)
+            .with_context(|| format!("Failed to connect to Sway socket: {:?}", self.socket_path))?;
+
+        let mut message = Vec::with_capacity(IPC_MAGIC.len() + 8 + payload.len());
+        message.extend_from_slice(IPC_MAGIC);
+        message.extend_from_slice(&(payload.len() as u32).to_ne_bytes());
+        message.extend_from_slice(&message_type.to_ne_bytes());
+        message.extend_from_slice(payload.as_bytes());
+        stream
+            .write_all(&message)
+            .context("Failed to write message to Sway socket")?;
+
+        let mut header = [0u8; 14];
+        stream
+            .read_exact(&mut header)
+            .context("Failed to read response header from Sway socket")?;
+        if &header[..6] != IPC_MAGIC {
+            bail!("Invalid response from Sway socket");
+        }
+        let length = u32::from_ne_bytes(header[6..10].try_into()?) as usize;
+
+        let mut response = vec![0u8; length];
+        stream
+            .read_exact(&mut response)
+            .context("Failed to read response from Sway socket")?;
+
+        String::from_utf8(response).context("Sway response is not valid UTF-8")
+    }
+
+    /// Run a Sway command, failing if Sway reports an error.
+    fn run_command(&self, cmd: &str) -> Result<()> {
+        let json = self.send_message(RUN_COMMAND, cmd)?;
+        let replies: Vec<SwayCommandReply> =
+            serde_json::from_str(&json).context("Failed to parse Sway command reply")?;
+
+        if let Some(reply) = replies.into_iter().find(|r| !r.success) {
+            bail!(
+                "Sway command '{}' failed: {}",
+                cmd,
+                reply.error.unwrap_or_default()
+            );
+        }
+        Ok(())
+    }
+
+    /// Get the number of the focused workspace.
+    fn focused_workspace(&self) -> Result<Option<i32>> {
+        let json = self.send_message(GET_WORKSPACES, "")?;
+        let workspaces: Vec<SwayWorkspace> =
+            serde_json::from_str(&json).context("Failed to parse Sway workspaces JSON")?;
+        Ok(workspaces
+            .into_iter()
+            .find(|ws| ws.focused)
+            .map(|ws| ws.num))
+    }
+}
+
+impl Compositor for SwayCompositor {
+    fn list_windows(&self) -> Result<Vec<WindowInfo>> {
+        let json = self.send_message(GET_TREE, "")?;
+        let tree: SwayNode =
+            serde_json::from_str(&json).context("Failed to parse Sway tree JSON")?;
+
+        let mut windows = Vec::new();
+        collect_windows(&tree, -1, None, &mut windows);
+
+        match self.focused_workspace() {
+            Ok(Some(focused)) => {
+                for window in &mut windows {
+                    window.on_active_workspace = window.workspace == focused;
+                }
+            }
+            Ok(None) => {}
+            Err(e) => tracing::debug!(%e, "Failed to list Sway workspaces"),
+        }
+        Ok(windows)
+    }
+
+    fn focus_window(&self, window_id: &str) -> Result<()> {
+        self.run_command(&format!("[con_id={}] focus", window_id))
+    }
+
+    fn close_window(&self, window_id: &str) -> Result<()> {
+        self.run_command(&format!("[con_id={}] kill", window_id))
+    }
+
+    fn name(&self) -> &'static str {
+        "Sway"
+    }
+}
+
+/// Walk the layout tree and collect all application windows.
+fn collect_windows(
+    node: &SwayNode,
+    workspace: i32,
+    output: Option<&str>,
+    windows: &mut Vec<WindowInfo>,
+) {
+    let workspace = if node.node_type == "workspace" {
+        node.num.unwrap_or(-1)
+    } else {
+        workspace
+    };
+    let output = if node.node_type == "output" {
+        node.name.as_deref()
+    } else {
+        output
+    };
+
+    // Only leaf containers backed by a client are windows
+    if node.pid.is_some() && node.nodes.is_empty() && node.floating_nodes.is_empty() {
+        let class = node
+            .app_id
+            .clone()
+            .or_else(|| {
+                node.window_properties
+                    .as_ref()
+                    .and_then(|p| p.class.clone())
+            })
+            .unwrap_or_default();
+
+        if !class.is_empty() && class.to_lowercase() != "zlaunch" {
+            let title = node.name.clone().unwrap_or_default();
+            windows.push(WindowInfo {
+                address: node.id.to_string(),
+                title: if title.is_empty() {
+                    class.clone()
+                } else {
+                    title
+                },
+                class,
+                workspace,
+                monitor: output.map(str::to_string),
+                on_active_workspace: true,
+                focused: node.focused,
+            });
+        }
+        return;
+    }
+
+    for child in node.nodes.iter().chain(&node.floating_nodes) {
+        collect_windows(child, workspace, output, windows);
+    }
+}
+
+/// Node of the Sway layout tree.
+#[derive(Debug, Deserialize)]
+struct SwayNode {
+    id: i64,
+    #[serde(rename = "type")]
+    node_type: String,
+    #[serde(default)]
+    name: Option<String>,
+    /// Workspace number (workspaces only)
+    #[serde(default)]
+    num: Option<i32>,
+    #[serde(default)]
+    focused: bool,
+    /// Client process id (windows only)
+    #[serde(default)]
+    pid: Option<i32>,
+    /// Wayland app id (native Wayland windows)
+    #[serde(default)]
+    app_id: Option<String>,
+    /// X11 properties (Xwayland windows)
+    #[serde(default)]
+    window_properties: Option<SwayWindowProperties>,
+    #[serde(default)]
+    nodes: Vec<SwayNode>,
+    #[serde(default)]
+    floating_nodes: Vec<SwayNode>,
+}
+
+/// X11 window properties of an Xwayland window.
+#[derive(Debug, Deserialize)]
+struct SwayWindowProperties {
+    #[serde(default)]
+    class: Option<String>,
+}
+
+/// Workspace in a GET_WORKSPACES reply.
+#[derive(Debug, Deserialize)]
+struct SwayWorkspace {
+    num: i32,
+    focused: bool,
+}
+
+/// Result of a single command in a RUN_COMMAND reply.
+#[derive(Debug, Deserialize)]
+struct SwayCommandReply {
+    success: bool,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_windows() {
+        let json = r#"{
+            "id": 1, "type": "root", "nodes": [
+                {"id": 2, "type": "output", "name": "DP-1", "nodes": [
+                    {"id": 3, "type": "workspace", "num": 2, "nodes": [
+                        {"id": 4, "type": "con", "nodes": [
+                            {"id": 5, "type": "con", "name": "Mozilla Firefox", "pid": 10,
+                             "app_id": "firefox", "focused": true},
+                            {"id": 6, "type": "con", "name": "zlaunch", "pid": 11,
+                             "app_id": "zlaunch"}
+                        ]}
+                    ], "floating_nodes": [
+                        {"id": 7, "type": "floating_con", "name": "", "pid": 12,
+                         "app_id": null, "window_properties": {"class": "Steam"}}
+                    ]}
+                ]}
+            ]
+        }"#;
+        let tree: SwayNode = serde_json::from_str(json).unwrap();
+
+        let mut windows = Vec::new();
+        collect_windows(&tree, -1, None, &mut windows);
+
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].address, "5");
+        assert_eq!(windows[0].class, "firefox");
+        assert_eq!(windows[0].workspace, 2);
+        assert_eq!(windows[0].monitor.as_deref(), Some("DP-1"));
+        assert!(windows[0].focused);
+        assert_eq!(windows[1].address, "7");
+        assert_eq!(windows[1].title, "Steam");
+        assert!(!windows[1].focused);
+    }
+}
@@ -1,11 +1,13 @@
 use rust_embed::RustEmbed;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::RwLock;
+use std::time::Duration;
 
+use crate::emoji::SkinTone;
 use crate::items::ThemeSource;
-use crate::ui::theme::LauncherTheme;
+use crate::ui::theme::{LauncherTheme, ThemeVariant};
 
 /// Embedded bundled themes
 #[derive(RustEmbed)]
@@ -22,16 +24,168 @@ static CONFIG: RwLock<AppConfig> = RwLock::new(AppConfig::default_const());
 pub struct AppConfig {
     /// Name of the theme to use
     pub theme: String,
+    /// Name of the theme to use when the light variant is active
+    pub light_theme: String,
+    /// Whether to use the light or dark theme
+    pub appearance: Appearance,
     /// Window width in pixels
     pub window_width: f32,
     /// Window height in pixels
     pub window_height: f32,
+    /// Where the window sits on the screen
+    pub window_anchor: WindowAnchor,
+    /// Distance from the top of the screen in pixels with the `top-center` anchor
+    pub window_margin_top: f32,
+    /// Font family for the launcher text (the system UI font when unset)
+    pub font_family: Option<String>,
+    /// Factor applied to item title, description and preview text sizes
+    pub font_scale: f32,
     /// Automatically apply blur layer rules on Hyprland
     pub hyprland_auto_blur: bool,
     /// Modules that are disabled
     pub disabled_modules: Option<HashSet<ConfigModule>>,
     /// Enable transparency of the window
     pub enable_transparency: bool,
+    /// Also record the primary selection (select to copy) in clipboard history
+    pub capture_primary_selection: bool,
+    /// Poll the clipboard every this many milliseconds on compositors
+    /// without the wlr data-control protocol (0 disables polling)
+    pub clipboard_poll_interval_ms: u64,
+    /// Maximum number of clipboard entries kept in history, the oldest are
+    /// dropped first (pinned entries are always kept)
+    pub clipboard_max_items: usize,
+    /// Maximum number of clipboard entries persisted to disk (pinned entries are always kept)
+    pub clipboard_persist_max_items: usize,
+    /// Maximum total size of persisted clipboard images in megabytes
+    pub clipboard_persist_max_image_mb: u64,
+    /// Regexes for text that should never be recorded in clipboard history
+    pub clipboard_exclude_patterns: Vec<String>,
+    /// Directory clipboard images are saved to with Ctrl+Shift+S (the
+    /// pictures directory when unset)
+    pub screenshots_dir: Option<String>,
+    /// Extra search keywords for applications, keyed by desktop entry id
+    pub aliases: Option<HashMap<String, Vec<String>>>,
+    /// Desktop entry ids of applications listed before the other matching
+    /// applications
+    pub pinned_apps: Vec<String>,
+    /// Key chords for launcher actions, keyed by action name (e.g.
+    /// "SelectNext"), replacing the default keys of those actions
+    pub keybindings: Option<HashMap<String, Vec<String>>>,
+    /// Navigate with Ctrl+J / Ctrl+K in addition to the arrow keys
+    pub vim_navigation: bool,
+    /// Restore the last main search query when the launcher is reopened
+    pub remember_last_query: bool,
+    /// Show the command line of applications instead of their description
+    /// (applications without a description always show it)
+    pub show_exec_command: bool,
+    /// Show hints for the main keys of the current view in the footer
+    pub show_key_hints: bool,
+    /// Keep the launcher open after copying a calculator result, emoji or
+    /// clipboard item instead of hiding it after the "Copied!" toast
+    pub stay_open_after_copy: bool,
+    /// Allow turning on sticky mode with Ctrl+S, which keeps the emoji picker
+    /// and clipboard history open after a copy and clears the query
+    pub sticky_mode: bool,
+    /// Only list the windows of the focused workspace (toggled with Alt+W)
+    pub current_workspace_windows: bool,
+    /// Sections of the main list shown collapsed to their header, by name
+    /// (e.g. "Windows", "Commands", "Applications", "Search and AI")
+    pub collapsed_sections: Vec<String>,
+    /// Maximum number of results shown per section while searching, the
+    /// rest behind a "+N more" row (0 shows all results)
+    pub section_result_limit: usize,
+    /// Lay out the windows, commands and applications of the main list in a
+    /// grid with this many columns (1 for a plain list)
+    pub main_grid_columns: usize,
+    /// Order of the search results in the main list
+    pub result_order: ResultOrder,
+    /// Show pinned and recently launched applications and the latest
+    /// clipboard entries while the query is empty, instead of every item
+    pub home_view: bool,
+    /// Terminal command for `Terminal=true` applications, `{cmd}` marks where
+    /// the program goes (auto-detected when unset)
+    pub terminal: Option<String>,
+    /// Copy unit conversion results with their unit ("6.2 mi" instead of "6.2")
+    pub calculator_copy_unit: bool,
+    /// Show calculator results as a fraction, rounded and in scientific
+    /// notation too (Alt+F picks the one copied)
+    pub calculator_formats: bool,
+    /// Command opening web searches and links, `{url}` marks where the URL
+    /// goes (`xdg-open` when unset)
+    pub browser: Option<String>,
+    /// Additional web search providers
+    pub search_providers: Vec<SearchProviderConfig>,
+    /// Name of the provider offered first for plain queries (Google when unset)
+    pub default_search_provider: Option<String>,
+    /// Provider names in the order they are offered after the default one
+    pub search_provider_order: Vec<String>,
+    /// Maximum number of web search suggestions for plain queries
+    pub max_search_suggestions: usize,
+    /// Skin tone of emojis that support one (cycle it with Ctrl+T in the picker)
+    pub emoji_skin_tone: SkinTone,
+    /// Text snippets listed with the commands, copied when confirmed
+    pub snippets: Vec<SnippetConfig>,
+}
+
+/// A user-defined web search provider
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchProviderConfig {
+    /// Provider name (e.g. "Company Wiki")
+    pub name: String,
+    /// URL template with a `{query}` placeholder
+    pub url: String,
+    /// Phosphor icon name (e.g. "book-open")
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Bang shortcut, used as `!<shortcut> query`
+    #[serde(default)]
+    pub shortcut: Option<String>,
+}
+
+/// A user-defined text snippet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnippetConfig {
+    /// Name the snippet is searched by (e.g. "Email signature")
+    pub name: String,
+    /// Copied text, may contain `{date}`, `{time}` and `{clipboard}`
+    /// placeholders
+    pub text: String,
+}
+
+/// Which theme variant to use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Appearance {
+    /// Follow the system color scheme preference
+    Auto,
+    /// Always use `light_theme`
+    Light,
+    /// Always use `theme`
+    #[default]
+    Dark,
+}
+
+/// Position of the window on the screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WindowAnchor {
+    /// Centered horizontally and vertically
+    #[default]
+    Center,
+    /// Centered horizontally, `window_margin_top` below the top edge
+    TopCenter,
+}
+
+/// Order of the search results in the main list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResultOrder {
+    /// Grouped by type (windows, commands, applications), each group sorted
+    /// by match score
+    #[default]
+    Types,
+    /// Sorted by match score only, in a single section of all types
+    Relevance,
 }
 
 /// Modules enum
@@ -44,6 +198,7 @@ pub enum ConfigModule {
     Clipboard,
     Search,
     Themes,
+    Files,
 }
 
 impl AppConfig {
@@ -51,11 +206,49 @@ impl AppConfig {
     const fn default_const() -> Self {
         Self {
             theme: String::new(),
+            light_theme: String::new(),
+            appearance: Appearance::Dark,
             window_width: 600.0,
             window_height: 400.0,
+            window_anchor: WindowAnchor::Center,
+            window_margin_top: 200.0,
+            font_family: None,
+            font_scale: 1.0,
             hyprland_auto_blur: true,
             disabled_modules: None,
             enable_transparency: true,
+            capture_primary_selection: false,
+            clipboard_poll_interval_ms: 0,
+            clipboard_max_items: 200,
+            clipboard_persist_max_items: 100,
+            clipboard_persist_max_image_mb: 50,
+            clipboard_exclude_patterns: Vec::new(),
+            screenshots_dir: None,
+            aliases: None,
+            pinned_apps: Vec::new(),
+            keybindings: None,
+            vim_navigation: false,
+            remember_last_query: false,
+            show_exec_command: false,
+            show_key_hints: true,
+            stay_open_after_copy: false,
+            sticky_mode: false,
+            current_workspace_windows: true,
+            collapsed_sections: Vec::new(),
+            section_result_limit: 10,
+            main_grid_columns: 1,
+            result_order: ResultOrder::Types,
+            home_view: false,
+            terminal: None,
+            browser: None,
+            calculator_copy_unit: false,
+            calculator_formats: false,
+            search_providers: Vec::new(),
+            default_search_provider: None,
+            search_provider_order: Vec::new(),
+            max_search_suggestions: 3,
+            emoji_skin_tone: SkinTone::Default,
+            snippets: Vec::new(),
         }
     }
 }
@@ -64,11 +257,49 @@ impl Default for AppConfig {
     fn default() -> Self {
         Self {
             theme: "default".to_string(),
+            light_theme: "default-light".to_string(),
+            appearance: Appearance::Dark,
             window_width: 600.0,
             window_height: 400.0,
+            window_anchor: WindowAnchor::Center,
+            window_margin_top: 200.0,
+            font_family: None,
+            font_scale: 1.0,
             hyprland_auto_blur: true,
             disabled_modules: None,
             enable_transparency: true,
+            capture_primary_selection: false,
+            clipboard_poll_interval_ms: 0,
+            clipboard_max_items: 200,
+            clipboard_persist_max_items: 100,
+            clipboard_persist_max_image_mb: 50,
+            clipboard_exclude_patterns: Vec::new(),
+            screenshots_dir: None,
+            aliases: None,
+            pinned_apps: Vec::new(),
+            keybindings: None,
+            vim_navigation: false,
+            remember_last_query: false,
+            show_exec_command: false,
+            show_key_hints: true,
+            stay_open_after_copy: false,
+            sticky_mode: false,
+            current_workspace_windows: true,
+            collapsed_sections: Vec::new(),
+            section_result_limit: 10,
+            main_grid_columns: 1,
+            result_order: ResultOrder::Types,
+            home_view: false,
+            terminal: None,
+            browser: None,
+            calculator_copy_unit: false,
+            calculator_formats: false,
+            search_providers: Vec::new(),
+            default_search_provider: None,
+            search_provider_order: Vec::new(),
+            max_search_suggestions: 3,
+            emoji_skin_tone: SkinTone::Default,
+            snippets: Vec::new(),
         }
     }
 }
@@ -122,15 +353,39 @@ pub fn load_app_config() -> Option<AppConfig> {
     }
 }
 
+/// File extensions of user theme files, in lookup order
+const THEME_EXTENSIONS: &[&str] = &["toml", "json"];
+
+/// Get the theme name of a user theme file name (e.g. "mine.json" -> "mine")
+fn user_theme_name(filename: &str) -> Option<&str> {
+    THEME_EXTENSIONS
+        .iter()
+        .find_map(|ext| filename.strip_suffix(ext)?.strip_suffix('.'))
+}
+
+/// Parse theme file content, as JSON for `.json` files and TOML otherwise
+/// Fields missing from the file keep their default values
+fn parse_theme(content: &str, path: &std::path::Path) -> anyhow::Result<LauncherTheme> {
+    if path.extension().is_some_and(|ext| ext == "json") {
+        Ok(serde_json::from_str(content)?)
+    } else {
+        Ok(toml::from_str(content)?)
+    }
+}
+
 /// Load a theme by name
-/// First checks bundled themes, then user themes in ~/.config/zlaunch/themes/{name}.toml
+/// First checks bundled themes, then user themes in
+/// ~/.config/zlaunch/themes/{name}.toml or {name}.json
 /// Returns None if the theme is not found
-/// Logs warning and returns None if parsing fails
+/// Logs an error and returns None if parsing fails
 pub fn load_theme(name: &str) -> Option<LauncherTheme> {
-    // Special case: "default" theme is defined in code, not a file
+    // Special case: the default themes are defined in code, not files
     if name == "default" {
         return Some(LauncherTheme::default());
     }
+    if name == "default-light" {
+        return Some(LauncherTheme::light());
+    }
 
     // First, try to load from bundled themes
     let bundled_filename = format!("{}.toml", name);
@@ -157,19 +412,21 @@ pub fn load_theme(name: &str) -> Option<LauncherTheme> {
 
     // If not found in bundled themes, try user config directory
     let themes_dir = config_dir()?.join("themes");
-    let theme_path = themes_dir.join(format!("{}.toml", name));
-
-    if !theme_path.exists() {
+    let Some(theme_path) = THEME_EXTENSIONS
+        .iter()
+        .map(|ext| themes_dir.join(format!("{}.{}", name, ext)))
+        .find(|path| path.exists())
+    else {
         tracing::debug!(
-            "Theme '{}' not found in bundled themes or at {:?}",
+            "Theme '{}' not found in bundled themes or in {:?}",
             name,
-            theme_path
+            themes_dir
         );
         return None;
-    }
+    };
 
     match std::fs::read_to_string(&theme_path) {
-        Ok(content) => match toml::from_str::<LauncherTheme>(&content) {
+        Ok(content) => match parse_theme(&content, &theme_path) {
             Ok(mut theme) => {
                 // Ensure the theme name matches the file name
                 theme.name = name.to_string();
@@ -177,12 +434,12 @@ pub fn load_theme(name: &str) -> Option<LauncherTheme> {
                 Some(theme)
             }
             Err(e) => {
-                tracing::warn!("Failed to parse theme file at {:?}: {}", theme_path, e);
+                tracing::error!("Failed to parse theme file at {:?}: {}", theme_path, e);
                 None
             }
         },
         Err(e) => {
-            tracing::warn!("Failed to read theme file at {:?}: {}", theme_path, e);
+            tracing::error!("Failed to read theme file at {:?}: {}", theme_path, e);
             None
         }
     }
@@ -206,7 +463,7 @@ pub fn list_themes() -> Vec<String> {
     {
         for entry in entries.flatten() {
             if let Some(filename) = entry.file_name().to_str()
-                && let Some(name) = filename.strip_suffix(".toml")
+                && let Some(name) = user_theme_name(filename)
                 && !themes.contains(&name.to_string())
             {
                 themes.push(name.to_string());
@@ -218,24 +475,25 @@ pub fn list_themes() -> Vec<String> {
     themes
 }
 
-/// Load the configured theme, falling back to default if anything fails
+/// Load the configured theme for the active variant, falling back to the
+/// default theme of that variant if anything fails
 pub fn load_configured_theme() -> LauncherTheme {
     // Get theme name from cached config
-    let theme_name = config().theme;
-
-    // If a non-default theme is requested, try to load it
-    if theme_name != "default" {
-        if let Some(theme) = load_theme(&theme_name) {
-            return theme;
-        }
+    let config = config();
+    let (theme_name, fallback): (String, fn() -> LauncherTheme) =
+        match crate::ui::theme::current_variant() {
+            ThemeVariant::Light => (config.light_theme, LauncherTheme::light),
+            ThemeVariant::Dark => (config.theme, LauncherTheme::default),
+        };
+
+    let theme = load_theme(&theme_name).unwrap_or_else(|| {
         tracing::warn!(
             "Failed to load theme '{}', falling back to default",
             theme_name
         );
-    }
-
-    // Use default theme
-    LauncherTheme::default()
+        fallback()
+    });
+    theme.scaled(font_scale())
 }
 
 /// Initialize config from file (call once at daemon startup)
@@ -274,6 +532,44 @@ fn save_config_to_file(config: &AppConfig) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Smallest and largest accepted `font_scale`
+const FONT_SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.5..=3.0;
+
+/// Get the configured font scale, or 1.0 if it is out of range
+pub fn font_scale() -> f32 {
+    let scale = config().font_scale;
+    if FONT_SCALE_RANGE.contains(&scale) {
+        scale
+    } else {
+        tracing::warn!(
+            "font_scale {} is outside {:?}, using 1.0",
+            scale,
+            FONT_SCALE_RANGE
+        );
+        1.0
+    }
+}
+
+/// Shortest accepted clipboard polling interval
+const MIN_CLIPBOARD_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Get the clipboard polling interval, None if polling is disabled
+pub fn clipboard_poll_interval() -> Option<Duration> {
+    let interval = match config().clipboard_poll_interval_ms {
+        0 => return None,
+        ms => Duration::from_millis(ms),
+    };
+    if interval < MIN_CLIPBOARD_POLL_INTERVAL {
+        tracing::warn!(
+            "clipboard_poll_interval_ms {} is below {:?}, using that",
+            interval.as_millis(),
+            MIN_CLIPBOARD_POLL_INTERVAL
+        );
+        return Some(MIN_CLIPBOARD_POLL_INTERVAL);
+    }
+    Some(interval)
+}
+
 /// Get the configured window width
 pub fn window_width() -> f32 {
     config().window_width
@@ -288,8 +584,9 @@ pub fn window_height() -> f32 {
 pub fn list_all_themes_with_source() -> Vec<(String, ThemeSource)> {
     let mut themes = Vec::new();
 
-    // Add the implicit default theme (defined in code, not a file)
+    // Add the implicit default themes (defined in code, not files)
     themes.push(("default".to_string(), ThemeSource::Bundled));
+    themes.push(("default-light".to_string(), ThemeSource::Bundled));
 
     // Add bundled themes
     for filename in BundledThemes::iter() {
@@ -305,7 +602,7 @@ pub fn list_all_themes_with_source() -> Vec<(String, ThemeSource)> {
     {
         for entry in entries.flatten() {
             if let Some(filename) = entry.file_name().to_str()
-                && let Some(name) = filename.strip_suffix(".toml")
+                && let Some(name) = user_theme_name(filename)
             {
                 // Check if this theme name already exists in bundled themes
                 let name_string = name.to_string();
@@ -1,6 +1,5 @@
 use anyhow::Result;
-use gpui::{Application, QuitMode, hsla};
-use gpui_component::theme::{Theme, ThemeMode};
+use gpui::{Application, QuitMode};
 use std::sync::Arc;
 use tracing::{error, info};
 
@@ -11,8 +10,8 @@ use crate::compositor::{Compositor, detect_compositor};
 use crate::config::{ConfigModule, config};
 use crate::desktop::cache::load_applications;
 use crate::desktop::capture_session_environment;
-use crate::ipc::client;
 use crate::ipc::{IpcServerHandle, start_server};
+use crate::ipc::{LaunchMode, client};
 use crate::items::ApplicationItem;
 use crate::ui::init_launcher;
 
@@ -33,7 +32,11 @@ pub fn init_logging() {
 
 /// Run the launcher daemon.
 /// This is the main entry point when no subcommand is provided.
-pub fn run() -> Result<()> {
+///
+/// If a daemon is already running, it is toggled instead (into `mode` when
+/// given), so a key bind can simply run `zlaunch`. Otherwise the new daemon
+/// shows `mode` once started.
+pub fn run(mode: Option<LaunchMode>) -> Result<()> {
     init_logging();
     info!(
         version = env!("CARGO_PKG_VERSION"),
@@ -48,8 +51,8 @@ pub fn run() -> Result<()> {
         Ok(handle) => handle,
         Err(e) => {
             if client::is_daemon_running() {
-                error!("Daemon already running, exiting");
-                return Ok(());
+                info!("Daemon already running, toggling it");
+                return client::toggle(mode.unwrap_or_default());
             }
             return Err(e);
         }
@@ -88,19 +91,34 @@ pub fn run() -> Result<()> {
 
     // Load applications and convert to ApplicationItems
     let entries = load_applications();
-    let applications: Vec<ApplicationItem> = entries.into_iter().map(Into::into).collect();
+    let mut aliases = crate::config::config().aliases.unwrap_or_default();
+    let pinned_apps = crate::config::config().pinned_apps;
+    let applications: Vec<ApplicationItem> = entries
+        .into_iter()
+        .map(|entry| {
+            let app = ApplicationItem::from(entry);
+            let app = app.with_pinned(pinned_apps.contains(&app.id));
+            match aliases.remove(&app.id) {
+                Some(app_aliases) => app.with_aliases(app_aliases),
+                None => app,
+            }
+        })
+        .collect();
     info!(count = applications.len(), "Loaded applications");
 
+    // Show the requested view once the event loop runs (nobody awaits the response)
+    if let Some(mode) = mode {
+        let (response_tx, _) = tokio::sync::oneshot::channel();
+        let _ = event_tx.send(DaemonEvent::Show { mode, response_tx });
+    }
+
     Application::new()
         .with_assets(CombinedAssets)
         .with_quit_mode(QuitMode::Explicit)
         .run(move |cx| {
             gpui_component::init(cx);
             init_launcher(cx);
-            Theme::change(ThemeMode::Dark, None, cx);
-
-            // Customize theme for transparent background and no borders
-            configure_theme(cx);
+            crate::ui::theme::apply_component_theme(cx);
 
             let applications_clone = applications.clone();
             let compositor_clone = compositor.clone();
@@ -121,13 +139,14 @@ pub fn run() -> Result<()> {
                             visible = false;
                         }
 
-                        DaemonEvent::Show { response_tx } => {
+                        DaemonEvent::Show { mode, response_tx } => {
                             let result = if !visible {
                                 cx.update(|cx| {
                                     match window::create_and_show_window(
                                         applications_clone.clone(),
                                         compositor_clone.clone(),
                                         event_tx.clone(),
+                                        mode,
                                         cx,
                                     ) {
                                         Ok(lw) => {
@@ -143,7 +162,11 @@ pub fn run() -> Result<()> {
                                 })
                                 .unwrap_or(Err("Failed to update app".to_string()))
                             } else {
-                                Ok(()) // Already visible
+                                // Already visible, switch to the requested view
+                                if let Some(ref lw) = launcher_window {
+                                    let _ = cx.update(|cx| window::open_mode(lw, mode, cx));
+                                }
+                                Ok(())
                             };
                             let _ = response_tx.send(result);
                         }
@@ -161,7 +184,7 @@ pub fn run() -> Result<()> {
                             let _ = response_tx.send(Ok(()));
                         }
 
-                        DaemonEvent::Toggle { response_tx } => {
+                        DaemonEvent::Toggle { mode, response_tx } => {
                             let result = if visible {
                                 let _ = cx.update(|cx| {
                                     if let Some(ref lw) = launcher_window {
@@ -177,6 +200,7 @@ pub fn run() -> Result<()> {
                                         applications_clone.clone(),
                                         compositor_clone.clone(),
                                         event_tx.clone(),
+                                        mode,
                                         cx,
                                     ) {
                                         Ok(lw) => {
@@ -232,24 +256,10 @@ fn handle_set_theme(name: &str) -> Result<(), String> {
     crate::config::load_theme(name).ok_or_else(|| format!("Theme '{}' not found", name))?;
 
     // Update config (persists to disk if config file exists)
-    crate::config::update_config(|config| {
-        config.theme = name.to_string();
-    });
+    crate::ui::theme::save_theme_name(name.to_string());
 
     // Sync the theme cache from the updated config
     crate::ui::theme::sync_theme_from_config();
 
     Ok(())
 }
-
-/// Configure the global theme for transparent launcher appearance.
-fn configure_theme(cx: &mut gpui::App) {
-    let theme = Theme::global_mut(cx);
-    theme.background = hsla(0.0, 0.0, 0.0, 0.0); // Fully transparent
-    theme.window_border = hsla(0.0, 0.0, 0.0, 0.0); // No window border
-    theme.border = hsla(0.0, 0.0, 1.0, 0.1); // Subtle separator between search and list
-    theme.list_active_border = hsla(0.0, 0.0, 0.0, 0.0); // No selection border
-    theme.list_active = hsla(0.0, 0.0, 0.0, 0.0); // Fully transparent - we handle selection ourselves
-    theme.list_hover = hsla(0.0, 0.0, 0.0, 0.0); // Fully transparent - we handle hover ourselves
-    theme.mono_font_family = "Mononoki Nerd Font Mono".into(); // Monospace font for code blocks
-}
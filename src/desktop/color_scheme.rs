@@ -0,0 +1,45 @@
+//! System color scheme preference from the XDG desktop portal.
+//!
+//! Reads `org.freedesktop.appearance color-scheme` through the portal's
+//! Settings interface, which GNOME, KDE and most wlroots setups provide.
+
+use crate::ui::theme::ThemeVariant;
+use zbus::blocking::{Connection, Proxy};
+use zbus::zvariant::{OwnedValue, Value};
+
+/// Read the system color scheme preference.
+///
+/// Returns None if the portal isn't available or there is no preference.
+pub fn system_color_scheme() -> Option<ThemeVariant> {
+    let connection = Connection::session().ok()?;
+    let proxy = Proxy::new(
+        &connection,
+        "org.freedesktop.portal.Desktop",
+        "/org/freedesktop/portal/desktop",
+        "org.freedesktop.portal.Settings",
+    )
+    .ok()?;
+
+    let args = ("org.freedesktop.appearance", "color-scheme");
+    // ReadOne is only available in newer portals, Read wraps the value twice
+    let value: OwnedValue = proxy
+        .call("ReadOne", &args)
+        .or_else(|_| proxy.call("Read", &args))
+        .map_err(|e| tracing::debug!(%e, "Failed to read the color scheme from the portal"))
+        .ok()?;
+
+    match unwrap_u32(&value)? {
+        1 => Some(ThemeVariant::Dark),
+        2 => Some(ThemeVariant::Light),
+        _ => None,
+    }
+}
+
+/// Get a u32 out of a value, looking through nested variants.
+fn unwrap_u32(value: &Value<'_>) -> Option<u32> {
+    match value {
+        Value::U32(n) => Some(*n),
+        Value::Value(inner) => unwrap_u32(inner),
+        _ => None,
+    }
+}
@@ -1,5 +1,14 @@
 use std::path::PathBuf;
 
+/// An additional action from a `[Desktop Action <id>]` group
+/// (e.g. "New Private Window").
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DesktopAction {
+    pub id: String,
+    pub name: String,
+    pub exec: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct DesktopEntry {
     pub id: String,
@@ -12,6 +21,7 @@ pub struct DesktopEntry {
     pub categories: Vec<String>,
     pub terminal: bool,
     pub path: PathBuf,
+    pub actions: Vec<DesktopAction>,
 }
 
 impl DesktopEntry {
@@ -37,6 +47,13 @@ impl DesktopEntry {
             categories,
             terminal,
             path,
+            actions: Vec::new(),
         }
     }
+
+    /// Set the additional desktop actions.
+    pub fn with_actions(mut self, actions: Vec<DesktopAction>) -> Self {
+        self.actions = actions;
+        self
+    }
 }
@@ -1,8 +1,24 @@
 use crate::desktop::entry::DesktopEntry;
 use crate::desktop::env::get_session_environment;
 use std::os::unix::process::CommandExt;
+use std::path::Path;
 use std::process::Command;
 
+/// Placeholder for the program in terminal commands.
+const CMD_PLACEHOLDER: &str = "{cmd}";
+
+/// Terminal emulators tried when none is configured, in order.
+const TERMINAL_CANDIDATES: &[&str] = &[
+    "foot {cmd}",
+    "alacritty -e {cmd}",
+    "kitty {cmd}",
+    "wezterm start -- {cmd}",
+    "ghostty -e {cmd}",
+    "konsole -e {cmd}",
+    "gnome-terminal -- {cmd}",
+    "xterm -e {cmd}",
+];
+
 pub fn launch_application(entry: &DesktopEntry) -> anyhow::Result<()> {
     let exec = clean_exec_string(&entry.exec);
 
@@ -29,19 +45,21 @@ fn clean_exec_string(exec: &str) -> String {
 
 fn launch_detached(exec: &str) -> anyhow::Result<()> {
     let parts: Vec<&str> = exec.split_whitespace().collect();
-    if parts.is_empty() {
-        anyhow::bail!("Empty exec command");
-    }
+    spawn_detached(&parts)
+}
 
-    let program = parts[0];
-    let args = &parts[1..];
+/// Spawn a program with arguments, detached from the daemon.
+fn spawn_detached<S: AsRef<str>>(parts: &[S]) -> anyhow::Result<()> {
+    let Some((program, args)) = parts.split_first() else {
+        anyhow::bail!("Empty exec command");
+    };
 
     // SAFETY: setsid() is async-signal-safe and creates a new session,
     // detaching the child from the parent's process group so it survives
     // when the daemon exits.
     unsafe {
-        Command::new(program)
-            .args(args)
+        Command::new(program.as_ref())
+            .args(args.iter().map(AsRef::as_ref))
             .env_clear()
             .envs(get_session_environment().iter())
             .stdin(std::process::Stdio::null())
@@ -57,43 +75,122 @@ fn launch_detached(exec: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Launch a program inside the first available terminal.
 fn launch_in_terminal(exec: &str) -> anyhow::Result<()> {
-    let terminal = get_terminal()?;
+    let program: Vec<String> = exec.split_whitespace().map(str::to_string).collect();
+    spawn_in_terminal(&program)
+}
 
-    // SAFETY: setsid() is async-signal-safe and creates a new session,
-    // detaching the child from the parent's process group so it survives
-    // when the daemon exits.
-    unsafe {
-        Command::new(&terminal)
-            .arg("-e")
-            .arg(exec)
-            .env_clear()
-            .envs(get_session_environment().iter())
-            .stdin(std::process::Stdio::null())
-            .stdout(std::process::Stdio::null())
-            .stderr(std::process::Stdio::null())
-            .pre_exec(|| {
-                libc::setsid();
-                Ok(())
-            })
-            .spawn()?;
+/// Run a shell command line (e.g. `ls -la | less`) in a terminal.
+///
+/// The command is passed to `sh -c` as a single argument, so its quoting,
+/// pipes and redirections are left to the shell.
+pub fn run_in_terminal(command: &str) -> anyhow::Result<()> {
+    let command = command.trim();
+    if command.is_empty() {
+        anyhow::bail!("Empty command");
     }
+    spawn_in_terminal(&["sh".to_string(), "-c".to_string(), command.to_string()])
+}
 
-    Ok(())
+/// Spawn a program and its arguments inside the first available terminal.
+///
+/// Candidates are the configured `terminal`, then `$TERMINAL`, then common
+/// terminal emulators. Candidates whose binary isn't installed are skipped.
+fn spawn_in_terminal(program: &[String]) -> anyhow::Result<()> {
+    let env = get_session_environment();
+    let configured = crate::config::config().terminal;
+    let from_env = env
+        .get("TERMINAL")
+        .map(|terminal| format!("{} -e {}", terminal, CMD_PLACEHOLDER));
+
+    let candidates = configured
+        .iter()
+        .chain(from_env.iter())
+        .map(String::as_str)
+        .chain(TERMINAL_CANDIDATES.iter().copied());
+
+    let path = env.get("PATH").map_or("", String::as_str);
+    for template in candidates {
+        let command = terminal_command(template, program);
+        match command.first() {
+            Some(binary) if binary_in_path(binary, path) => return spawn_detached(&command),
+            Some(binary) => {
+                if configured.as_deref() == Some(template) {
+                    tracing::error!(%binary, "Configured terminal not found, trying the next one");
+                } else {
+                    tracing::debug!(%binary, "Terminal not found");
+                }
+            }
+            None => tracing::error!(%template, "Empty terminal command"),
+        }
+    }
+
+    anyhow::bail!("No terminal emulator found. Set `terminal` in the config or $TERMINAL.")
 }
 
-fn get_terminal() -> anyhow::Result<String> {
-    if let Ok(terminal) = std::env::var("TERMINAL") {
-        return Ok(terminal);
+/// Build a terminal command line running `program`.
+///
+/// The `{cmd}` placeholder is replaced by the program and its arguments; a
+/// template without the placeholder gets them appended.
+fn terminal_command(template: &str, program: &[String]) -> Vec<String> {
+    let program = program.iter().cloned();
+    let mut command: Vec<String> = template.split_whitespace().map(str::to_string).collect();
+
+    match command.iter().position(|part| part == CMD_PLACEHOLDER) {
+        Some(idx) => {
+            command.splice(idx..=idx, program);
+        }
+        None => command.extend(program),
     }
+    command
+}
 
-    if Command::new("which")
-        .arg("xterm")
-        .output()
-        .is_ok_and(|o| o.status.success())
-    {
-        return Ok("xterm".to_string());
+/// Check whether a binary exists, either as a path or on `PATH`.
+fn binary_in_path(binary: &str, path: &str) -> bool {
+    let binary = Path::new(binary);
+    if binary.components().count() > 1 {
+        return binary.is_file();
     }
+    std::env::split_paths(path).any(|dir| dir.join(binary).is_file())
+}
 
-    anyhow::bail!("No terminal emulator found. Set $TERMINAL environment variable.")
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(exec: &str) -> Vec<String> {
+        exec.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_terminal_command() {
+        assert_eq!(
+            terminal_command("alacritty -e {cmd}", &args("htop -d 10")),
+            ["alacritty", "-e", "htop", "-d", "10"]
+        );
+        assert_eq!(
+            terminal_command("wezterm start -- {cmd} ", &args("htop")),
+            ["wezterm", "start", "--", "htop"]
+        );
+        // Without a placeholder the program is appended
+        assert_eq!(terminal_command("foot", &args("htop")), ["foot", "htop"]);
+    }
+
+    #[test]
+    fn test_shell_command_stays_one_argument() {
+        let program = ["sh", "-c", "ls -la | less"].map(str::to_string);
+        assert_eq!(
+            terminal_command("foot {cmd}", &program),
+            ["foot", "sh", "-c", "ls -la | less"]
+        );
+    }
+
+    #[test]
+    fn test_binary_in_path() {
+        assert!(binary_in_path("sh", "/nonexistent:/bin"));
+        assert!(binary_in_path("/bin/sh", ""));
+        assert!(!binary_in_path("sh", "/nonexistent"));
+        assert!(!binary_in_path("zlaunch-missing-terminal", "/usr/bin:/bin"));
+    }
 }
@@ -0,0 +1,119 @@
+//! Launch frecency tracking for applications.
+//!
+//! Every launch bumps a per-application score that decays exponentially over
+//! time, so apps launched often *and* recently rank highest. Scores are keyed
+//! by desktop entry id and persisted as JSON in
+//! `~/.local/share/zlaunch/frecency.json`.
+
+use crate::util::state_file;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// File name of the persisted launch stats.
+const FRECENCY_FILE: &str = "frecency.json";
+
+/// Time after which a launch counts half as much.
+const HALF_LIFE_SECS: f64 = 7.0 * 24.0 * 60.0 * 60.0;
+
+/// Launch stats for a single application.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+struct LaunchStats {
+    /// Total number of launches
+    count: u32,
+    /// Seconds since the Unix epoch of the last launch
+    last_launch: u64,
+    /// Decayed launch score as of `last_launch`
+    score: f64,
+}
+
+impl LaunchStats {
+    /// Score decayed to the given time.
+    fn score_at(&self, now: u64) -> f64 {
+        let age = now.saturating_sub(self.last_launch) as f64;
+        self.score * 0.5f64.powf(age / HALF_LIFE_SECS)
+    }
+
+    /// Record a launch at the given time.
+    fn record(&mut self, now: u64) {
+        self.score = self.score_at(now) + 1.0;
+        self.count += 1;
+        self.last_launch = now;
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct FrecencyStore {
+    apps: HashMap<String, LaunchStats>,
+}
+
+static STORE: LazyLock<Mutex<FrecencyStore>> =
+    LazyLock::new(|| Mutex::new(state_file::load(FRECENCY_FILE, "launch frecency")));
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Record a launch of the application with the given id and persist it.
+pub fn record_launch(id: &str) {
+    let mut store = STORE.lock().unwrap();
+    store
+        .apps
+        .entry(id.to_string())
+        .or_default()
+        .record(now_secs());
+
+    if let Err(e) = state_file::save(FRECENCY_FILE, &*store) {
+        tracing::warn!(%e, "Failed to persist launch frecency");
+    }
+}
+
+/// Get the current frecency score of every launched application.
+///
+/// Applications that were never launched are missing (score 0).
+pub fn scores() -> HashMap<String, f64> {
+    let now = now_secs();
+    STORE
+        .lock()
+        .unwrap()
+        .apps
+        .iter()
+        .map(|(id, stats)| (id.clone(), stats.score_at(now)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: u64 = 24 * 60 * 60;
+
+    #[test]
+    fn test_score_decays() {
+        let mut stats = LaunchStats::default();
+        stats.record(0);
+        assert_eq!(stats.score_at(0), 1.0);
+        assert!((stats.score_at(7 * DAY) - 0.5).abs() < 1e-9);
+
+        // Frequent launches outrank a single recent one, but not once they are stale
+        let mut frequent = LaunchStats::default();
+        for _ in 0..5 {
+            frequent.record(0);
+        }
+        let mut recent = LaunchStats::default();
+        recent.record(14 * DAY);
+        assert!(frequent.score_at(14 * DAY) > recent.score_at(14 * DAY));
+        assert_eq!(frequent.count, 5);
+
+        let mut stale = LaunchStats::default();
+        for _ in 0..5 {
+            stale.record(0);
+        }
+        recent.record(60 * DAY);
+        assert!(stale.score_at(60 * DAY) < recent.score_at(60 * DAY));
+    }
+}
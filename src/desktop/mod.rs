@@ -1,11 +1,13 @@
 pub mod cache;
+pub mod color_scheme;
 pub mod entry;
 pub mod env;
 pub mod exec;
+pub mod frecency;
 pub mod parser;
 pub mod scanner;
 
-pub use entry::DesktopEntry;
+pub use entry::{DesktopAction, DesktopEntry};
 pub use env::{capture_session_environment, get_session_environment};
-pub use exec::launch_application;
+pub use exec::{launch_application, run_in_terminal};
 pub use scanner::scan_applications;
@@ -1,24 +1,112 @@
-use crate::desktop::entry::DesktopEntry;
+use crate::desktop::entry::{DesktopAction, DesktopEntry};
+use crate::desktop::env::get_session_environment;
 use freedesktop_desktop_entry::DesktopEntry as FdEntry;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-pub fn parse_desktop_file(path: &Path) -> Option<DesktopEntry> {
+/// Session details deciding which entries are shown.
+pub struct ShowEnvironment {
+    /// Lowercased names from `XDG_CURRENT_DESKTOP`
+    desktops: Vec<String>,
+    /// Directories searched for `TryExec` binaries
+    path: Vec<PathBuf>,
+}
+
+impl ShowEnvironment {
+    /// Read the environment from the captured user session.
+    pub fn from_session() -> Self {
+        let env = get_session_environment();
+        Self::new(
+            env.get("XDG_CURRENT_DESKTOP").map_or("", String::as_str),
+            env.get("PATH").map_or("", String::as_str),
+        )
+    }
+
+    /// Create an environment from `XDG_CURRENT_DESKTOP` and `PATH` values.
+    pub fn new(current_desktop: &str, path: &str) -> Self {
+        Self {
+            desktops: split_list(current_desktop, ':')
+                .map(str::to_lowercase)
+                .collect(),
+            path: std::env::split_paths(path).collect(),
+        }
+    }
+
+    /// Check the `OnlyShowIn` / `NotShowIn` lists against the current desktop.
+    fn shows_in(&self, only_show_in: Option<&str>, not_show_in: Option<&str>) -> bool {
+        let is_current = |desktop: &str| self.desktops.contains(&desktop.to_lowercase());
+
+        if let Some(only) = only_show_in
+            && !split_list(only, ';').any(is_current)
+        {
+            return false;
+        }
+        not_show_in.is_none_or(|not| !split_list(not, ';').any(is_current))
+    }
+
+    /// Check whether a `TryExec` binary exists (absolute or on `PATH`).
+    fn binary_exists(&self, try_exec: &str) -> bool {
+        let binary = Path::new(try_exec);
+        if binary.is_absolute() {
+            return binary.is_file();
+        }
+        self.path.iter().any(|dir| dir.join(binary).is_file())
+    }
+}
+
+/// Get the desktop id of a desktop file: its name without the extension.
+pub fn desktop_id(path: &Path) -> String {
+    path.file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown")
+        .to_string()
+}
+
+/// Split a separated desktop entry list, skipping empty elements.
+fn split_list(list: &str, separator: char) -> impl Iterator<Item = &str> {
+    list.split(separator)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+pub fn parse_desktop_file(path: &Path, show_env: &ShowEnvironment) -> Option<DesktopEntry> {
     let content = std::fs::read_to_string(path).ok()?;
-    let fd_entry = FdEntry::from_str(path, &content, None::<&[&str]>).ok()?;
+    parse_desktop_content(path, &content, show_env)
+}
+
+/// Parse desktop entry content.
+///
+/// Returns None for entries that should not be listed: `NoDisplay` or
+/// `Hidden` entries, entries excluded from the current desktop by
+/// `OnlyShowIn` / `NotShowIn`, and entries whose `TryExec` binary is missing.
+pub fn parse_desktop_content(
+    path: &Path,
+    content: &str,
+    show_env: &ShowEnvironment,
+) -> Option<DesktopEntry> {
+    let fd_entry = FdEntry::from_str(path, content, None::<&[&str]>).ok()?;
 
     let locales: &[&str] = &[];
     let name = fd_entry.name(locales)?.to_string();
     let exec = fd_entry.exec()?.to_string();
 
-    if fd_entry.no_display() {
+    if fd_entry.no_display() || fd_entry.desktop_entry("Hidden") == Some("true") {
         return None;
     }
 
-    let id = path
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("unknown")
-        .to_string();
+    if !show_env.shows_in(
+        fd_entry.desktop_entry("OnlyShowIn"),
+        fd_entry.desktop_entry("NotShowIn"),
+    ) {
+        return None;
+    }
+
+    if let Some(try_exec) = fd_entry.desktop_entry("TryExec")
+        && !show_env.binary_exists(try_exec)
+    {
+        return None;
+    }
+
+    let id = desktop_id(path);
 
     let icon = fd_entry.icon().map(|s| s.to_string());
     let comment = fd_entry.comment(locales).map(|s| s.to_string());
@@ -30,16 +118,88 @@ pub fn parse_desktop_file(path: &Path) -> Option<DesktopEntry> {
 
     let terminal = fd_entry.terminal();
 
+    // Actions without a name or exec line can't be shown or launched
+    let actions: Vec<DesktopAction> = fd_entry
+        .actions()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|action| {
+            Some(DesktopAction {
+                id: action.to_string(),
+                name: fd_entry.action_name(action, locales)?.to_string(),
+                exec: fd_entry.action_exec(action)?.to_string(),
+            })
+        })
+        .collect();
+
     // icon_path is resolved later in cache.rs after all entries are loaded
-    Some(DesktopEntry::new(
-        id,
-        name,
-        exec,
-        icon,
-        None,
-        comment,
-        categories,
-        terminal,
-        path.to_path_buf(),
-    ))
+    Some(
+        DesktopEntry::new(
+            id,
+            name,
+            exec,
+            icon,
+            None,
+            comment,
+            categories,
+            terminal,
+            path.to_path_buf(),
+        )
+        .with_actions(actions),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(extra: &str, show_env: &ShowEnvironment) -> Option<DesktopEntry> {
+        let content = format!(
+            "[Desktop Entry]\nType=Application\nName=Test\nExec=test-app %U\n{}",
+            extra
+        );
+        parse_desktop_content(Path::new("/tmp/test-app.desktop"), &content, show_env)
+    }
+
+    fn sway() -> ShowEnvironment {
+        ShowEnvironment::new("sway", "/usr/bin:/bin")
+    }
+
+    #[test]
+    fn test_plain_entry_is_shown() {
+        let entry = parse("", &sway()).unwrap();
+        assert_eq!(entry.id, "test-app");
+        assert_eq!(entry.name, "Test");
+    }
+
+    #[test]
+    fn test_no_display_and_hidden() {
+        assert!(parse("NoDisplay=true\n", &sway()).is_none());
+        assert!(parse("Hidden=true\n", &sway()).is_none());
+        assert!(parse("NoDisplay=false\nHidden=false\n", &sway()).is_some());
+    }
+
+    #[test]
+    fn test_only_show_in() {
+        assert!(parse("OnlyShowIn=GNOME;KDE;\n", &sway()).is_none());
+        assert!(parse("OnlyShowIn=KDE;sway;\n", &sway()).is_some());
+        // XDG_CURRENT_DESKTOP may name several desktops
+        let env = ShowEnvironment::new("Hyprland:KDE", "/usr/bin:/bin");
+        assert!(parse("OnlyShowIn=KDE;\n", &env).is_some());
+        assert!(parse("OnlyShowIn=KDE;\n", &ShowEnvironment::new("", "")).is_none());
+    }
+
+    #[test]
+    fn test_not_show_in() {
+        assert!(parse("NotShowIn=Sway;\n", &sway()).is_none());
+        assert!(parse("NotShowIn=GNOME;\n", &sway()).is_some());
+    }
+
+    #[test]
+    fn test_try_exec() {
+        assert!(parse("TryExec=sh\n", &sway()).is_some());
+        assert!(parse("TryExec=/bin/sh\n", &sway()).is_some());
+        assert!(parse("TryExec=zlaunch-missing-binary\n", &sway()).is_none());
+        assert!(parse("TryExec=/nonexistent/zlaunch-missing-binary\n", &sway()).is_none());
+    }
 }
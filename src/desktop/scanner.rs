@@ -1,14 +1,23 @@
 use crate::desktop::entry::DesktopEntry;
-use crate::desktop::parser::parse_desktop_file;
-use std::collections::HashMap;
+use crate::desktop::parser::{ShowEnvironment, desktop_id, parse_desktop_file};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 pub fn scan_applications() -> Vec<DesktopEntry> {
-    let dirs = get_xdg_application_dirs();
+    scan_directories(
+        &get_xdg_application_dirs(),
+        &ShowEnvironment::from_session(),
+    )
+}
+
+/// Scan directories for desktop entries, earlier directories taking
+/// precedence.
+fn scan_directories(dirs: &[PathBuf], show_env: &ShowEnvironment) -> Vec<DesktopEntry> {
     let mut entries: HashMap<String, DesktopEntry> = HashMap::new();
+    let mut seen: HashSet<String> = HashSet::new();
 
     for dir in dirs {
-        scan_directory(&dir, &mut entries);
+        scan_directory(dir, show_env, &mut entries, &mut seen);
     }
 
     let mut result: Vec<DesktopEntry> = entries.into_values().collect();
@@ -35,7 +44,18 @@ fn get_xdg_application_dirs() -> Vec<PathBuf> {
     dirs
 }
 
-fn scan_directory(dir: &PathBuf, entries: &mut HashMap<String, DesktopEntry>) {
+/// Add the entries of a directory that weren't seen in a directory with
+/// higher precedence.
+///
+/// Ids are recorded as seen before hidden entries are dropped, so that a
+/// `Hidden` or `NoDisplay` entry (e.g. a user override) also hides the
+/// entries with the same id in later directories.
+fn scan_directory(
+    dir: &PathBuf,
+    show_env: &ShowEnvironment,
+    entries: &mut HashMap<String, DesktopEntry>,
+    seen: &mut HashSet<String>,
+) {
     let Ok(read_dir) = std::fs::read_dir(dir) else {
         return;
     };
@@ -44,15 +64,55 @@ fn scan_directory(dir: &PathBuf, entries: &mut HashMap<String, DesktopEntry>) {
         let path = entry.path();
 
         if path.is_dir() {
-            scan_directory(&path, entries);
+            scan_directory(&path, show_env, entries, seen);
             continue;
         }
 
         if path.extension().is_some_and(|ext| ext == "desktop")
-            && let Some(desktop_entry) = parse_desktop_file(&path)
-            && !entries.contains_key(&desktop_entry.id)
+            && seen.insert(desktop_id(&path))
+            && let Some(desktop_entry) = parse_desktop_file(&path, show_env)
         {
             entries.insert(desktop_entry.id.clone(), desktop_entry);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hidden_override_hides_system_entry() {
+        let root =
+            std::env::temp_dir().join(format!("zlaunch-test-scanner-{}", std::process::id()));
+        let user = root.join("user");
+        let system = root.join("system");
+        std::fs::create_dir_all(&user).unwrap();
+        std::fs::create_dir_all(&system).unwrap();
+
+        let entry = |name: &str, extra: &str| {
+            format!(
+                "[Desktop Entry]\nType=Application\nName={}\nExec=app\n{}",
+                name, extra
+            )
+        };
+        std::fs::write(
+            user.join("editor.desktop"),
+            entry("Editor", "Hidden=true\n"),
+        )
+        .unwrap();
+        std::fs::write(user.join("player.desktop"), entry("My Player", "")).unwrap();
+        std::fs::write(system.join("editor.desktop"), entry("Editor", "")).unwrap();
+        std::fs::write(system.join("player.desktop"), entry("Player", "")).unwrap();
+        std::fs::write(system.join("viewer.desktop"), entry("Viewer", "")).unwrap();
+
+        let show_env = ShowEnvironment::new("sway", "/usr/bin:/bin");
+        let names: Vec<String> = scan_directories(&[user, system], &show_env)
+            .into_iter()
+            .map(|entry| entry.name)
+            .collect();
+        assert_eq!(names, vec!["My Player", "Viewer"]);
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -8,10 +8,44 @@ lazy_static! {
     static ref ALL_EMOJIS: Vec<EmojiItem> = load_all_emojis();
 }
 
+/// Search keywords for common emojis, beyond their names and shortcodes.
+const KEYWORDS: &[(&str, &[&str])] = &[
+    ("👍", &["like", "yes", "agree", "approve"]),
+    ("👎", &["dislike", "no", "disagree"]),
+    ("🔥", &["lit", "hot", "flame"]),
+    ("😂", &["lol", "haha", "laugh"]),
+    ("🤣", &["lol", "rofl", "laugh"]),
+    ("😭", &["cry", "sad"]),
+    ("🎉", &["party", "congrats", "celebrate"]),
+    ("✅", &["done", "yes", "check"]),
+    ("❌", &["no", "wrong", "cancel"]),
+    ("👀", &["look", "see", "watching"]),
+    ("🙏", &["please", "thanks", "pray"]),
+    ("💯", &["hundred", "perfect", "score"]),
+    ("🚀", &["ship", "launch", "fast"]),
+    ("🤔", &["hmm", "think"]),
+    ("👋", &["hi", "hello", "bye"]),
+    ("💀", &["dead", "lmao"]),
+    ("😅", &["phew", "nervous"]),
+    ("🤷", &["idk", "dunno"]),
+];
+
+/// Get the search keywords of an emoji.
+fn keywords_for(emoji: &str) -> &'static [&'static str] {
+    KEYWORDS
+        .iter()
+        .find(|(e, _)| *e == emoji)
+        .map_or(&[], |(_, keywords)| keywords)
+}
+
 /// Load all emojis from the emojis crate.
 fn load_all_emojis() -> Vec<EmojiItem> {
     emojis::iter()
-        .map(|emoji| EmojiItem::new(emoji.as_str(), emoji.name()))
+        .map(|emoji| {
+            EmojiItem::new(emoji.as_str(), emoji.name())
+                .with_shortcodes(emoji.shortcodes())
+                .with_keywords(keywords_for(emoji.as_str()).iter().copied())
+        })
         .collect()
 }
 
@@ -20,6 +54,22 @@ pub fn all_emojis() -> &'static [EmojiItem] {
     &ALL_EMOJIS
 }
 
+/// Filter emojis by name, shortcode or keyword.
+/// Returns indices into `items`; emojis with a shortcode equal to the query
+/// come first, the rest keep their order.
+pub fn filter_emojis(items: &[EmojiItem], query: &str) -> Vec<usize> {
+    let query = query.trim().to_lowercase();
+    let mut ranked: Vec<(usize, u8)> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, item)| item.match_rank(&query).map(|rank| (idx, rank)))
+        .collect();
+
+    // Stable, so equally ranked emojis stay in their usual order
+    ranked.sort_by_key(|&(_, rank)| rank);
+    ranked.into_iter().map(|(idx, _)| idx).collect()
+}
+
 /// Search emojis by name using fuzzy matching.
 /// Returns indices into the all_emojis() slice, sorted by match score.
 pub fn search_emojis(query: &str) -> Vec<usize> {
@@ -42,3 +92,37 @@ pub fn search_emojis(query: &str) -> Vec<usize> {
     scored.sort_by(|a, b| b.1.cmp(&a.1));
     scored.into_iter().map(|(idx, _)| idx).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search(query: &str) -> Vec<&'static str> {
+        filter_emojis(all_emojis(), query)
+            .into_iter()
+            .map(|idx| all_emojis()[idx].emoji.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_search_by_name_and_keyword() {
+        assert!(search("thumbs").contains(&"👍"));
+        assert!(search("lit").contains(&"🔥"));
+        // "flag: São Tomé & Príncipe", whose shortcode has underscores
+        assert!(search("sao tome").contains(&"🇸🇹"));
+        assert!(search("são tomé").contains(&"🇸🇹"));
+        assert!(search("LOL").contains(&"😂"));
+    }
+
+    #[test]
+    fn test_exact_shortcode_ranks_first() {
+        assert_eq!(search(":fire:").first(), Some(&"🔥"));
+        assert_eq!(search("fire").first(), Some(&"🔥"));
+        assert_eq!(search(":+1:").first(), Some(&"👍"));
+    }
+
+    #[test]
+    fn test_no_match() {
+        assert!(search("zzzznotanemoji").is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::ui::utils::fold_diacritics;
+
 /// An emoji item for display in the emoji picker grid.
 #[derive(Clone, Debug)]
 pub struct EmojiItem {
@@ -5,6 +7,10 @@ pub struct EmojiItem {
     pub emoji: String,
     /// The display name of the emoji.
     pub name: String,
+    /// Shortcodes without colons (e.g. "fire" for `:fire:`).
+    pub shortcodes: Vec<String>,
+    /// Extra search keywords (e.g. "lit" for 🔥).
+    pub keywords: Vec<String>,
 }
 
 impl EmojiItem {
@@ -13,6 +19,42 @@ impl EmojiItem {
         Self {
             emoji: emoji.into(),
             name: name.into(),
+            shortcodes: Vec::new(),
+            keywords: Vec::new(),
         }
     }
+
+    /// Set the shortcodes.
+    pub fn with_shortcodes<S: Into<String>>(
+        mut self,
+        shortcodes: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.shortcodes = shortcodes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the search keywords.
+    pub fn with_keywords<S: Into<String>>(mut self, keywords: impl IntoIterator<Item = S>) -> Self {
+        self.keywords = keywords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Match a lowercase query against the emoji, its name, shortcodes and
+    /// keywords. Colons around the query are ignored, so `:fire:` works, and
+    /// so are diacritics in the name, so `sao tome` finds the flag of São Tomé.
+    ///
+    /// Returns None if nothing matches, otherwise a rank where lower is
+    /// better: 0 when the query is exactly one of the shortcodes.
+    pub fn match_rank(&self, query: &str) -> Option<u8> {
+        let term = query.trim_matches(':');
+        if self.shortcodes.iter().any(|code| code == term) {
+            return Some(0);
+        }
+
+        let matches = self.emoji.contains(query)
+            || fold_diacritics(&self.name.to_lowercase()).contains(&*fold_diacritics(term))
+            || self.shortcodes.iter().any(|code| code.contains(term))
+            || self.keywords.iter().any(|keyword| keyword.contains(term));
+        matches.then_some(1)
+    }
 }
@@ -1,5 +1,9 @@
 mod data;
 mod item;
+mod recent;
+mod skin_tone;
 
-pub use data::{all_emojis, search_emojis};
+pub use data::{all_emojis, filter_emojis, search_emojis};
 pub use item::EmojiItem;
+pub use recent::{recent_emojis, record_recent_emoji};
+pub use skin_tone::{SkinTone, apply_skin_tone};
@@ -0,0 +1,65 @@
+//! Recently used emojis.
+//!
+//! Picked emojis are kept most recent first, without duplicates, and
+//! persisted as JSON in `~/.local/share/zlaunch/emoji_recent.json`.
+
+use crate::emoji::{EmojiItem, all_emojis};
+use crate::util::state_file;
+use std::sync::{LazyLock, Mutex};
+
+/// File name of the persisted recent emojis.
+const RECENT_FILE: &str = "emoji_recent.json";
+
+/// Maximum number of recent emojis kept.
+const MAX_RECENT: usize = 24;
+
+static RECENT: LazyLock<Mutex<Vec<String>>> =
+    LazyLock::new(|| Mutex::new(state_file::load(RECENT_FILE, "recent emojis")));
+
+/// Move an emoji to the front of the recent emojis and persist them.
+pub fn record_recent_emoji(emoji: &str) {
+    let mut recent = RECENT.lock().unwrap();
+    bump(&mut recent, emoji);
+
+    if let Err(e) = state_file::save(RECENT_FILE, &*recent) {
+        tracing::warn!(%e, "Failed to persist recent emojis");
+    }
+}
+
+/// Get the recently used emojis, most recent first.
+pub fn recent_emojis() -> Vec<EmojiItem> {
+    let recent = RECENT.lock().unwrap();
+    recent
+        .iter()
+        .filter_map(|emoji| all_emojis().iter().find(|item| item.emoji == *emoji))
+        .cloned()
+        .collect()
+}
+
+/// Move an emoji to the front, dropping the oldest beyond `MAX_RECENT`.
+fn bump(recent: &mut Vec<String>, emoji: &str) {
+    recent.retain(|e| e != emoji);
+    recent.insert(0, emoji.to_string());
+    recent.truncate(MAX_RECENT);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bump_dedupes_and_caps() {
+        let mut recent = Vec::new();
+        bump(&mut recent, "🔥");
+        bump(&mut recent, "👍");
+        bump(&mut recent, "🔥");
+        assert_eq!(recent, ["🔥", "👍"]);
+
+        for i in 0..MAX_RECENT {
+            bump(&mut recent, &i.to_string());
+        }
+        assert_eq!(recent.len(), MAX_RECENT);
+        assert_eq!(recent[0], (MAX_RECENT - 1).to_string());
+        assert!(!recent.contains(&"👍".to_string()));
+    }
+}
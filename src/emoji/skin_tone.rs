@@ -0,0 +1,85 @@
+//! Skin tone variants of emojis.
+
+use serde::{Deserialize, Serialize};
+
+/// A skin tone applied to emojis that support Fitzpatrick modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SkinTone {
+    /// The yellow, unmodified emoji
+    #[default]
+    Default,
+    Light,
+    MediumLight,
+    Medium,
+    MediumDark,
+    Dark,
+}
+
+impl SkinTone {
+    /// All tones in cycling order.
+    const ALL: [Self; 6] = [
+        Self::Default,
+        Self::Light,
+        Self::MediumLight,
+        Self::Medium,
+        Self::MediumDark,
+        Self::Dark,
+    ];
+
+    /// The next tone, wrapping around to the default one.
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|&tone| tone == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    fn modifier(self) -> emojis::SkinTone {
+        match self {
+            Self::Default => emojis::SkinTone::Default,
+            Self::Light => emojis::SkinTone::Light,
+            Self::MediumLight => emojis::SkinTone::MediumLight,
+            Self::Medium => emojis::SkinTone::Medium,
+            Self::MediumDark => emojis::SkinTone::MediumDark,
+            Self::Dark => emojis::SkinTone::Dark,
+        }
+    }
+}
+
+/// Get the variant of an emoji with the given skin tone.
+///
+/// Emojis without skin tone support are returned unchanged.
+pub fn apply_skin_tone(emoji: &str, tone: SkinTone) -> String {
+    if tone == SkinTone::Default {
+        return emoji.to_string();
+    }
+    emojis::get(emoji)
+        .and_then(|e| e.with_skin_tone(tone.modifier()))
+        .map_or_else(|| emoji.to_string(), |e| e.as_str().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_skin_tone() {
+        assert_eq!(apply_skin_tone("👍", SkinTone::Medium), "👍\u{1F3FD}");
+        assert_eq!(apply_skin_tone("👋", SkinTone::Dark), "👋\u{1F3FF}");
+        assert_eq!(apply_skin_tone("👍", SkinTone::Default), "👍");
+    }
+
+    #[test]
+    fn test_unsupported_emoji_is_unchanged() {
+        assert_eq!(apply_skin_tone("🔥", SkinTone::Dark), "🔥");
+        assert_eq!(
+            apply_skin_tone("not an emoji", SkinTone::Light),
+            "not an emoji"
+        );
+    }
+
+    #[test]
+    fn test_next_wraps() {
+        assert_eq!(SkinTone::Default.next(), SkinTone::Light);
+        assert_eq!(SkinTone::Dark.next(), SkinTone::Default);
+    }
+}
@@ -1,6 +1,6 @@
 //! tarpc client for communicating with the daemon.
 
-use crate::ipc::commands::{ThemeInfo, ZlaunchServiceClient};
+use crate::ipc::commands::{LaunchMode, ThemeInfo, ZlaunchServiceClient};
 use crate::ipc::server::get_socket_path;
 use tarpc::client;
 use tarpc::context;
@@ -26,12 +26,12 @@ async fn connect() -> anyhow::Result<ZlaunchServiceClient> {
     Ok(client)
 }
 
-/// Show the launcher window.
-pub fn show() -> anyhow::Result<()> {
+/// Show the launcher window in the given view.
+pub fn show(mode: LaunchMode) -> anyhow::Result<()> {
     run_async(async {
         let client = connect().await?;
         client
-            .show(context::current())
+            .show(context::current(), mode)
             .await?
             .map_err(|e| anyhow::anyhow!(e))
     })
@@ -48,12 +48,12 @@ pub fn hide() -> anyhow::Result<()> {
     })
 }
 
-/// Toggle the launcher window visibility.
-pub fn toggle() -> anyhow::Result<()> {
+/// Toggle the launcher window visibility, showing it in the given view.
+pub fn toggle(mode: LaunchMode) -> anyhow::Result<()> {
     run_async(async {
         let client = connect().await?;
         client
-            .toggle(context::current())
+            .toggle(context::current(), mode)
             .await?
             .map_err(|e| anyhow::anyhow!(e))
     })
@@ -11,17 +11,30 @@ pub struct ThemeInfo {
     pub is_bundled: bool,
 }
 
+/// View the launcher opens in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum LaunchMode {
+    /// The main list of applications and windows
+    #[default]
+    Launcher,
+    /// The clipboard history
+    Clipboard,
+    /// The emoji picker
+    Emoji,
+}
+
 /// The zlaunch RPC service definition.
 #[tarpc::service]
 pub trait ZlaunchService {
-    /// Show the launcher window.
-    async fn show() -> Result<(), String>;
+    /// Show the launcher window, opening the given view.
+    async fn show(mode: LaunchMode) -> Result<(), String>;
 
     /// Hide the launcher window.
     async fn hide() -> Result<(), String>;
 
-    /// Toggle the launcher window visibility.
-    async fn toggle() -> Result<(), String>;
+    /// Toggle the launcher window visibility, opening the given view when shown.
+    async fn toggle(mode: LaunchMode) -> Result<(), String>;
 
     /// Quit the daemon.
     async fn quit() -> Result<(), String>;
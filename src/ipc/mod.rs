@@ -4,5 +4,5 @@ pub mod client;
 pub mod commands;
 pub mod server;
 
-pub use commands::{ThemeInfo, ZlaunchServiceClient};
+pub use commands::{LaunchMode, ThemeInfo, ZlaunchServiceClient};
 pub use server::{IpcServerHandle, get_socket_path, start_server};
@@ -1,7 +1,7 @@
 //! tarpc server implementation for the IPC daemon.
 
 use crate::app::DaemonEvent;
-use crate::ipc::commands::{ThemeInfo, ZlaunchService};
+use crate::ipc::commands::{LaunchMode, ThemeInfo, ZlaunchService};
 use crate::items::ThemeSource;
 use futures::prelude::*;
 use std::path::PathBuf;
@@ -44,10 +44,10 @@ struct ZlaunchServer {
 }
 
 impl ZlaunchService for ZlaunchServer {
-    async fn show(self, _: Context) -> Result<(), String> {
+    async fn show(self, _: Context, mode: LaunchMode) -> Result<(), String> {
         let (response_tx, response_rx) = oneshot::channel();
         self.event_tx
-            .send(DaemonEvent::Show { response_tx })
+            .send(DaemonEvent::Show { mode, response_tx })
             .map_err(|_| "Daemon channel closed".to_string())?;
         response_rx
             .await
@@ -64,10 +64,10 @@ impl ZlaunchService for ZlaunchServer {
             .unwrap_or(Err("Response channel closed".to_string()))
     }
 
-    async fn toggle(self, _: Context) -> Result<(), String> {
+    async fn toggle(self, _: Context, mode: LaunchMode) -> Result<(), String> {
         let (response_tx, response_rx) = oneshot::channel();
         self.event_tx
-            .send(DaemonEvent::Toggle { response_tx })
+            .send(DaemonEvent::Toggle { mode, response_tx })
             .map_err(|_| "Daemon channel closed".to_string())?;
         response_rx
             .await
@@ -114,6 +114,8 @@ impl ZlaunchService for ZlaunchServer {
 /// Start the tarpc IPC server.
 ///
 /// Returns Ok with handle on success, Err if another instance is running.
+/// The socket is bound before returning, so of two instances started at the
+/// same time only one becomes the daemon.
 pub fn start_server(event_tx: flume::Sender<DaemonEvent>) -> anyhow::Result<IpcServerHandle> {
     let socket_path = get_socket_path();
 
@@ -122,10 +124,21 @@ pub fn start_server(event_tx: flume::Sender<DaemonEvent>) -> anyhow::Result<IpcS
         if is_daemon_running() {
             anyhow::bail!("Another instance is already running");
         }
-        // Remove stale socket
+        // Nothing accepts connections, so the socket was left by a crashed instance
+        tracing::info!(path = ?socket_path, "Removing stale IPC socket");
         std::fs::remove_file(&socket_path)?;
     }
 
+    let std_listener = match std::os::unix::net::UnixListener::bind(&socket_path) {
+        Ok(listener) => listener,
+        // Another instance bound it since the check above
+        Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => {
+            anyhow::bail!("Another instance is already running")
+        }
+        Err(e) => return Err(e.into()),
+    };
+    std_listener.set_nonblocking(true)?;
+
     let socket_path_clone = socket_path.clone();
 
     // Spawn the server in a background thread with its own tokio runtime
@@ -137,7 +150,7 @@ pub fn start_server(event_tx: flume::Sender<DaemonEvent>) -> anyhow::Result<IpcS
 
         rt.block_on(async move {
             let listener =
-                UnixListener::bind(&socket_path_clone).expect("Failed to bind IPC socket");
+                UnixListener::from_std(std_listener).expect("Failed to register IPC socket");
 
             tracing::info!("IPC server listening on {:?}", socket_path_clone);
 
@@ -2,6 +2,7 @@ use std::os::unix::process::CommandExt;
 use std::process::Command;
 
 use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
+use crate::clipboard::{ClipboardItem, ClipboardSelection};
 
 /// The kind of action to perform.
 #[derive(Clone, Debug)]
@@ -12,12 +13,22 @@ pub enum ActionKind {
     Reboot,
     /// Suspend the system
     Suspend,
+    /// Hibernate the system
+    Hibernate,
     /// Lock the screen
     Lock,
     /// Log out of the session
     Logout,
+    /// Wipe the clipboard history
+    ClearClipboard,
+    /// Switch between the light and dark theme
+    ToggleAppearance,
     /// Custom command execution
     Command(String),
+    /// Shell command run in the terminal
+    TerminalCommand(String),
+    /// Copy the clipboard history item with this id again
+    CopyClipboardItem(u64),
 }
 
 /// An action item representing a functional command (shutdown, reboot, etc.).
@@ -28,6 +39,8 @@ pub struct ActionItem {
     pub description: Option<String>,
     pub icon_name: Option<String>,
     pub kind: ActionKind,
+    /// Whether the action must be confirmed a second time before it runs
+    pub requires_confirmation: bool,
 }
 
 impl ActionItem {
@@ -44,6 +57,7 @@ impl ActionItem {
             description,
             icon_name,
             kind,
+            requires_confirmation: false,
         }
     }
 
@@ -58,8 +72,41 @@ impl ActionItem {
             ),
             ActionKind::Reboot => ("action-reboot", "Reboot", "Restart the system", "reboot"),
             ActionKind::Suspend => ("action-suspend", "Suspend", "Suspend to RAM", "moon"),
+            ActionKind::Hibernate => ("action-hibernate", "Hibernate", "Suspend to disk", "moon"),
             ActionKind::Lock => ("action-lock", "Lock Screen", "Lock the session", "lock"),
             ActionKind::Logout => ("action-logout", "Log Out", "End the session", "sign-out"),
+            ActionKind::ClearClipboard => (
+                "action-clear-clipboard",
+                "Clear Clipboard History",
+                "Remove all clipboard history entries",
+                "clipboard",
+            ),
+            ActionKind::ToggleAppearance => (
+                "action-toggle-appearance",
+                "Toggle Light/Dark Theme",
+                "Switch between the light and dark theme",
+                "palette",
+            ),
+            ActionKind::TerminalCommand(cmd) => {
+                return Self {
+                    id: "action-terminal-command".to_string(),
+                    name: cmd.clone(),
+                    description: Some("Run in terminal".to_string()),
+                    icon_name: Some("terminal".to_string()),
+                    kind,
+                    requires_confirmation: false,
+                };
+            }
+            ActionKind::CopyClipboardItem(id) => {
+                return Self {
+                    id: format!("action-copy-clipboard-{}", id),
+                    name: "Clipboard Item".to_string(),
+                    description: Some("Copy from clipboard history".to_string()),
+                    icon_name: Some("clipboard".to_string()),
+                    kind,
+                    requires_confirmation: false,
+                };
+            }
             ActionKind::Command(cmd) => {
                 return Self {
                     id: format!("action-cmd-{}", cmd.len()),
@@ -67,27 +114,57 @@ impl ActionItem {
                     description: Some(cmd.clone()),
                     icon_name: Some("terminal".to_string()),
                     kind,
+                    requires_confirmation: false,
                 };
             }
         };
 
+        let requires_confirmation = matches!(
+            kind,
+            ActionKind::Shutdown | ActionKind::Reboot | ActionKind::Logout
+        );
+
         Self {
             id: id.to_string(),
             name: name.to_string(),
             description: Some(description.to_string()),
             icon_name: Some(icon_name.to_string()),
             kind,
+            requires_confirmation,
         }
     }
 
-    /// Get all built-in action items.
-    pub fn builtins() -> Vec<Self> {
+    /// The question asked before running the action (e.g. "Shut down?").
+    pub fn confirmation_prompt(&self) -> String {
+        match self.kind {
+            ActionKind::Shutdown => "Shut down?".to_string(),
+            ActionKind::Logout => "Log out?".to_string(),
+            _ => format!("{}?", self.name),
+        }
+    }
+
+    /// Create an action copying a clipboard history item again, named after
+    /// its content.
+    pub fn clipboard_item(item: &ClipboardItem) -> Self {
+        let mut action = Self::builtin(ActionKind::CopyClipboardItem(item.id));
+        action.name = item.preview();
+        action
+    }
+
+    /// Whether this action copies a clipboard history item.
+    pub fn is_clipboard_item(&self) -> bool {
+        matches!(self.kind, ActionKind::CopyClipboardItem(_))
+    }
+
+    /// Get the action items of the power submenu.
+    pub fn power_actions() -> Vec<Self> {
         vec![
             Self::builtin(ActionKind::Shutdown),
             Self::builtin(ActionKind::Reboot),
             Self::builtin(ActionKind::Suspend),
-            Self::builtin(ActionKind::Lock),
+            Self::builtin(ActionKind::Hibernate),
             Self::builtin(ActionKind::Logout),
+            Self::builtin(ActionKind::Lock),
         ]
     }
 }
@@ -128,6 +205,9 @@ impl Executable for ActionItem {
             ActionKind::Suspend => {
                 Command::new("systemctl").arg("suspend").spawn()?;
             }
+            ActionKind::Hibernate => {
+                Command::new("systemctl").arg("hibernate").spawn()?;
+            }
             ActionKind::Lock => {
                 Command::new("loginctl").arg("lock-session").spawn()?;
             }
@@ -136,6 +216,12 @@ impl Executable for ActionItem {
                     .args(["terminate-session", "self"])
                     .spawn()?;
             }
+            ActionKind::ClearClipboard => {
+                crate::clipboard::data::clear_history();
+            }
+            ActionKind::ToggleAppearance => {
+                crate::ui::theme::toggle_variant();
+            }
             ActionKind::Command(cmd) => {
                 // Custom commands should be disowned from daemon
                 // SAFETY: setsid() is async-signal-safe
@@ -152,6 +238,15 @@ impl Executable for ActionItem {
                         .spawn()?;
                 }
             }
+            ActionKind::TerminalCommand(cmd) => {
+                crate::desktop::run_in_terminal(cmd)?;
+            }
+            ActionKind::CopyClipboardItem(id) => {
+                let item = crate::clipboard::data::get_item(*id)
+                    .ok_or_else(|| anyhow::anyhow!("Clipboard item is no longer in history"))?;
+                crate::clipboard::copy_item(&item, ClipboardSelection::Clipboard, false)
+                    .map_err(anyhow::Error::msg)?;
+            }
         }
         Ok(())
     }
@@ -1,4 +1,5 @@
-use crate::desktop::DesktopEntry;
+use crate::assets::PhosphorIcon;
+use crate::desktop::{DesktopAction, DesktopEntry};
 use std::path::PathBuf;
 
 use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
@@ -13,6 +14,14 @@ pub struct ApplicationItem {
     pub description: Option<String>,
     pub terminal: bool,
     pub desktop_path: PathBuf,
+    /// User-defined search keywords (e.g. "ff" for Firefox)
+    pub aliases: Vec<String>,
+    /// Listed first among the matching applications (`pinned_apps`)
+    pub pinned: bool,
+    /// Additional desktop actions (e.g. "New Private Window")
+    pub actions: Vec<DesktopAction>,
+    /// Desktop entry categories (e.g. "Development")
+    pub categories: Vec<String>,
 }
 
 impl ApplicationItem {
@@ -33,8 +42,66 @@ impl ApplicationItem {
             description,
             terminal,
             desktop_path,
+            aliases: Vec::new(),
+            pinned: false,
+            actions: Vec::new(),
+            categories: Vec::new(),
         }
     }
+
+    /// Set the user-defined search aliases.
+    pub fn with_aliases(mut self, aliases: Vec<String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Set whether the application is pinned to the top of the results.
+    pub fn with_pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    /// Get the icon shown when the application's own icon is missing, chosen
+    /// from its categories.
+    pub fn fallback_icon(&self) -> PhosphorIcon {
+        let has = |category: &str| self.categories.iter().any(|c| c == category);
+        if has("TerminalEmulator") {
+            PhosphorIcon::Terminal
+        } else if has("Development") {
+            PhosphorIcon::Code
+        } else if has("WebBrowser") || has("Network") {
+            PhosphorIcon::Globe
+        } else if has("Graphics") {
+            PhosphorIcon::Image
+        } else if has("Office") || has("Education") {
+            PhosphorIcon::BookOpen
+        } else {
+            PhosphorIcon::AppWindow
+        }
+    }
+
+    /// Get the desktop actions as launchable items.
+    ///
+    /// Each action keeps the application's icon and terminal setting and
+    /// shows the application name as its description.
+    pub fn action_items(&self) -> Vec<ApplicationItem> {
+        self.actions
+            .iter()
+            .map(|action| Self {
+                id: format!("{}:{}", self.id, action.id),
+                name: action.name.clone(),
+                exec: action.exec.clone(),
+                icon_path: self.icon_path.clone(),
+                description: Some(self.name.clone()),
+                terminal: self.terminal,
+                desktop_path: self.desktop_path.clone(),
+                aliases: Vec::new(),
+                pinned: false,
+                actions: Vec::new(),
+                categories: self.categories.clone(),
+            })
+            .collect()
+    }
 }
 
 impl From<DesktopEntry> for ApplicationItem {
@@ -47,6 +114,10 @@ impl From<DesktopEntry> for ApplicationItem {
             description: entry.comment,
             terminal: entry.terminal,
             desktop_path: entry.path,
+            aliases: Vec::new(),
+            pinned: false,
+            actions: entry.actions,
+            categories: entry.categories,
         }
     }
 }
@@ -61,6 +132,10 @@ impl From<&DesktopEntry> for ApplicationItem {
             description: entry.comment.clone(),
             terminal: entry.terminal,
             desktop_path: entry.path.clone(),
+            aliases: Vec::new(),
+            pinned: false,
+            actions: entry.actions.clone(),
+            categories: entry.categories.clone(),
         }
     }
 }
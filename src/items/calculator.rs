@@ -14,15 +14,32 @@ pub struct CalculatorItem {
     pub clipboard_result: Option<String>,
     /// Whether this is an error result.
     pub is_error: bool,
+    /// Other representations of the result (e.g. "1/3", "0.33", "3.3333e-1").
+    pub alternatives: Vec<String>,
+    /// Which representation is copied: 0 for `clipboard_result`, then the
+    /// alternatives in order.
+    pub format_index: usize,
 }
 
 impl CalculatorItem {
     /// Get the text to copy to clipboard.
     pub fn text_for_clipboard(&self) -> &str {
+        if let Some(alternative) = self
+            .format_index
+            .checked_sub(1)
+            .and_then(|idx| self.alternatives.get(idx))
+        {
+            return alternative;
+        }
         self.clipboard_result
             .as_deref()
             .unwrap_or(&self.display_result)
     }
+
+    /// Copy the next representation of the result, wrapping around.
+    pub fn cycle_format(&mut self) {
+        self.format_index = (self.format_index + 1) % (self.alternatives.len() + 1);
+    }
 }
 
 impl DisplayItem for CalculatorItem {
@@ -0,0 +1,108 @@
+use crate::assets::PhosphorIcon;
+use crate::search::open_path;
+use std::path::{Path, PathBuf};
+
+use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
+
+/// A file or directory found by file search.
+#[derive(Clone, Debug)]
+pub struct FileItem {
+    /// Unique identifier for this item
+    pub id: String,
+    /// Full path of the file
+    pub path: PathBuf,
+    /// File name
+    pub name: String,
+    /// Parent directory, with the home directory shown as `~`
+    pub description: String,
+    /// Whether this is a directory
+    pub is_dir: bool,
+}
+
+impl FileItem {
+    /// Create a new file item.
+    pub fn new(path: PathBuf, is_dir: bool) -> Self {
+        let name = path.file_name().map_or_else(
+            || path.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        );
+        let description = path.parent().map(display_path).unwrap_or_default();
+        Self {
+            id: format!("file-{}", path.display()),
+            path,
+            name,
+            description,
+            is_dir,
+        }
+    }
+
+    /// Get the icon for this file, chosen by its extension.
+    pub fn icon(&self) -> PhosphorIcon {
+        if self.is_dir {
+            PhosphorIcon::Folder
+        } else {
+            file_icon(&self.path)
+        }
+    }
+}
+
+/// Get the icon for a file by its extension.
+pub fn file_icon(path: &Path) -> PhosphorIcon {
+    let Some(ext) = path.extension().and_then(|e| e.to_str()) else {
+        return PhosphorIcon::File;
+    };
+    match ext.to_lowercase().as_str() {
+        "png" | "jpg" | "jpeg" | "gif" | "webp" | "svg" => PhosphorIcon::FileImage,
+        "txt" | "md" | "rs" | "py" | "js" | "ts" | "json" | "yaml" | "yml" | "toml" | "html"
+        | "css" | "sh" => PhosphorIcon::FileText,
+        _ => PhosphorIcon::File,
+    }
+}
+
+/// Format a path for display, replacing the home directory with `~`.
+fn display_path(path: &Path) -> String {
+    if let Some(home) = dirs::home_dir()
+        && let Ok(relative) = path.strip_prefix(&home)
+    {
+        return Path::new("~").join(relative).display().to_string();
+    }
+    path.display().to_string()
+}
+
+impl DisplayItem for FileItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> Option<&str> {
+        Some(&self.description)
+    }
+
+    fn action_label(&self) -> &'static str {
+        "Open"
+    }
+}
+
+impl IconProvider for FileItem {
+    // Uses Phosphor icons via icon() method
+}
+
+impl Executable for FileItem {
+    fn execute(&self) -> anyhow::Result<()> {
+        open_path(&self.path)
+    }
+}
+
+impl Categorizable for FileItem {
+    fn section_name(&self) -> &'static str {
+        "Files"
+    }
+
+    fn sort_priority(&self) -> u8 {
+        2
+    }
+}
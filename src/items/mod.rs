@@ -2,7 +2,9 @@ mod action;
 mod ai;
 mod application;
 mod calculator;
+mod file;
 mod search;
+mod snippet;
 mod submenu;
 mod theme;
 mod traits;
@@ -12,7 +14,9 @@ pub use action::{ActionItem, ActionKind};
 pub use ai::AiItem;
 pub use application::ApplicationItem;
 pub use calculator::CalculatorItem;
+pub use file::{FileItem, file_icon};
 pub use search::SearchItem;
+pub use snippet::SnippetItem;
 pub use submenu::{SubmenuItem, SubmenuLayout};
 pub use theme::{ThemeItem, ThemeSource};
 pub use traits::{Categorizable, DisplayItem, Executable, IconProvider, Previewable};
@@ -36,6 +40,10 @@ pub enum ListItem {
     Calculator(CalculatorItem),
     /// A web search item
     Search(SearchItem),
+    /// A file found by file search
+    File(FileItem),
+    /// A user-defined text snippet
+    Snippet(SnippetItem),
     /// An AI query item
     Ai(AiItem),
     /// A theme item (boxed due to large size)
@@ -52,6 +60,8 @@ impl ListItem {
             Self::Submenu(item) => item.id(),
             Self::Calculator(item) => item.id(),
             Self::Search(item) => item.id(),
+            Self::File(item) => item.id(),
+            Self::Snippet(item) => item.id(),
             Self::Ai(item) => item.id(),
             Self::Theme(item) => item.id(),
         }
@@ -66,6 +76,8 @@ impl ListItem {
             Self::Submenu(item) => item.name(),
             Self::Calculator(item) => item.name(),
             Self::Search(item) => item.name(),
+            Self::File(item) => item.name(),
+            Self::Snippet(item) => item.name(),
             Self::Ai(item) => item.name(),
             Self::Theme(item) => item.name(),
         }
@@ -80,11 +92,26 @@ impl ListItem {
             Self::Submenu(item) => item.description(),
             Self::Calculator(item) => item.description(),
             Self::Search(item) => item.description(),
+            Self::File(item) => item.description(),
+            Self::Snippet(item) => item.description(),
             Self::Ai(item) => item.description(),
             Self::Theme(item) => item.description(),
         }
     }
 
+    /// Get the user-defined search aliases for this item.
+    pub fn aliases(&self) -> &[String] {
+        match self {
+            Self::Application(item) => &item.aliases,
+            _ => &[],
+        }
+    }
+
+    /// Check if this item is an application pinned to the top of the results.
+    pub fn is_pinned(&self) -> bool {
+        matches!(self, Self::Application(item) if item.pinned)
+    }
+
     /// Get the icon path for this item.
     pub fn icon_path(&self) -> Option<&PathBuf> {
         match self {
@@ -94,6 +121,8 @@ impl ListItem {
             Self::Submenu(item) => item.icon_path(),
             Self::Calculator(item) => item.icon_path(),
             Self::Search(item) => item.icon_path(),
+            Self::File(item) => item.icon_path(),
+            Self::Snippet(item) => item.icon_path(),
             Self::Ai(item) => item.icon_path(),
             Self::Theme(item) => item.icon_path(),
         }
@@ -108,6 +137,8 @@ impl ListItem {
             Self::Submenu(item) => item.icon_name(),
             Self::Calculator(item) => item.icon_name(),
             Self::Search(item) => item.icon_name(),
+            Self::File(item) => item.icon_name(),
+            Self::Snippet(item) => item.icon_name(),
             Self::Ai(item) => item.icon_name(),
             Self::Theme(item) => item.icon_name(),
         }
@@ -133,11 +164,87 @@ impl ListItem {
         matches!(self, Self::Action(_))
     }
 
+    /// Check if this item is a text snippet.
+    pub fn is_snippet(&self) -> bool {
+        matches!(self, Self::Snippet(_))
+    }
+
     /// Check if this item is a calculator result.
     pub fn is_calculator(&self) -> bool {
         matches!(self, Self::Calculator(_))
     }
 
+    /// Get the raw fields of this item as label/value pairs, for inspecting
+    /// what confirming it does.
+    pub fn metadata(&self) -> Vec<(&'static str, String)> {
+        let path = |path: Option<&PathBuf>| path.map(|p| p.display().to_string());
+        let mut fields = vec![("id", Some(self.id().to_string()))];
+        fields.extend(match self {
+            Self::Application(item) => vec![
+                ("exec", Some(item.exec.clone())),
+                ("desktop_path", path(Some(&item.desktop_path))),
+                ("terminal", Some(item.terminal.to_string())),
+                ("icon_path", path(item.icon_path.as_ref())),
+                ("pinned", Some(item.pinned.to_string())),
+                ("aliases", Some(item.aliases.join(", "))),
+                ("categories", Some(item.categories.join(", "))),
+                (
+                    "actions",
+                    Some(
+                        item.actions
+                            .iter()
+                            .map(|action| action.id.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                ),
+            ],
+            Self::Window(item) => vec![
+                ("address", Some(item.address.clone())),
+                ("app_id", Some(item.app_id.clone())),
+                ("title", Some(item.title.clone())),
+                ("workspace", Some(item.workspace.to_string())),
+                ("monitor", item.monitor.clone()),
+                ("focused", Some(item.focused.to_string())),
+                ("icon_path", path(item.icon_path.as_ref())),
+            ],
+            Self::Action(item) => vec![
+                ("kind", Some(format!("{:?}", item.kind))),
+                (
+                    "requires_confirmation",
+                    Some(item.requires_confirmation.to_string()),
+                ),
+            ],
+            Self::Submenu(item) => vec![("layout", Some(format!("{:?}", item.layout)))],
+            Self::Calculator(item) => vec![
+                ("expression", Some(item.expression.clone())),
+                ("clipboard", Some(item.text_for_clipboard().to_string())),
+            ],
+            Self::Search(item) => vec![
+                (
+                    "provider",
+                    item.provider.as_ref().map(|provider| provider.name.clone()),
+                ),
+                ("query", Some(item.query.clone())),
+                ("url", Some(item.url.clone())),
+            ],
+            Self::File(item) => vec![
+                ("path", path(Some(&item.path))),
+                ("is_dir", Some(item.is_dir.to_string())),
+            ],
+            Self::Snippet(item) => vec![("text", Some(item.text.clone()))],
+            Self::Ai(item) => vec![("query", Some(item.query.clone()))],
+            Self::Theme(item) => vec![("source", Some(format!("{:?}", item.source)))],
+        });
+        fields.push(("section", Some(self.section_name().to_string())));
+        fields.push(("sort_priority", Some(self.sort_priority().to_string())));
+
+        fields
+            .into_iter()
+            .filter_map(|(label, value)| Some((label, value.filter(|v| !v.is_empty())?)))
+            .collect()
+    }
+
     /// Get the action label to display (e.g., "Open", "Switch", "Run").
     pub fn action_label(&self) -> &'static str {
         match self {
@@ -147,6 +254,8 @@ impl ListItem {
             Self::Submenu(item) => item.action_label(),
             Self::Calculator(item) => item.action_label(),
             Self::Search(item) => item.action_label(),
+            Self::File(item) => item.action_label(),
+            Self::Snippet(item) => item.action_label(),
             Self::Ai(item) => item.action_label(),
             Self::Theme(item) => item.action_label(),
         }
@@ -162,6 +271,8 @@ impl ListItem {
             Self::Submenu(item) => item.sort_priority(),
             Self::Calculator(item) => item.sort_priority(),
             Self::Search(item) => item.sort_priority(),
+            Self::File(item) => item.sort_priority(),
+            Self::Snippet(item) => item.sort_priority(),
             Self::Ai(item) => item.sort_priority(),
             Self::Theme(item) => item.sort_priority(),
         }
@@ -176,6 +287,8 @@ impl ListItem {
             Self::Submenu(item) => item.section_name(),
             Self::Calculator(item) => item.section_name(),
             Self::Search(item) => item.section_name(),
+            Self::File(item) => item.section_name(),
+            Self::Snippet(item) => item.section_name(),
             Self::Ai(item) => item.section_name(),
             Self::Theme(item) => item.section_name(),
         }
@@ -220,6 +333,18 @@ impl From<SearchItem> for ListItem {
     }
 }
 
+impl From<FileItem> for ListItem {
+    fn from(item: FileItem) -> Self {
+        Self::File(item)
+    }
+}
+
+impl From<SnippetItem> for ListItem {
+    fn from(item: SnippetItem) -> Self {
+        Self::Snippet(item)
+    }
+}
+
 impl From<AiItem> for ListItem {
     fn from(item: AiItem) -> Self {
         Self::Ai(item)
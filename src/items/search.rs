@@ -1,20 +1,19 @@
-use std::os::unix::process::CommandExt;
-
 use crate::assets::PhosphorIcon;
-use crate::search::SearchProvider;
+use crate::search::{SearchProvider, open_url};
 
 use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
 
-/// A search item representing a web search query for a specific provider.
+/// A search item representing a web search query for a specific provider,
+/// or a URL from the query that is opened directly.
 #[derive(Clone, Debug)]
 pub struct SearchItem {
     /// Unique identifier for this item
     pub id: String,
     /// Display name (e.g., "Search on Google")
     pub name: String,
-    /// The search provider
-    pub provider: SearchProvider,
-    /// The search query
+    /// The search provider (None for a URL opened directly)
+    pub provider: Option<SearchProvider>,
+    /// The search query (the URL itself for a URL opened directly)
     pub query: String,
     /// The generated search URL
     pub url: String,
@@ -29,15 +28,28 @@ impl SearchItem {
         Self {
             id,
             name,
-            provider,
+            provider: Some(provider),
             query,
             url,
         }
     }
 
+    /// Create an item opening a URL directly.
+    pub fn url(url: String) -> Self {
+        Self {
+            id: format!("url-{}", url),
+            name: format!("Open {}", url),
+            provider: None,
+            query: url.clone(),
+            url,
+        }
+    }
+
     /// Get the icon for this search item.
     pub fn icon(&self) -> PhosphorIcon {
-        self.provider.icon
+        self.provider
+            .as_ref()
+            .map_or(PhosphorIcon::Link, |provider| provider.icon)
     }
 }
 
@@ -65,21 +77,8 @@ impl IconProvider for SearchItem {
 
 impl Executable for SearchItem {
     fn execute(&self) -> anyhow::Result<()> {
-        // Open URL in browser, disowned from daemon
-        // SAFETY: setsid() is async-signal-safe
-        unsafe {
-            std::process::Command::new("xdg-open")
-                .arg(&self.url)
-                .stdin(std::process::Stdio::null())
-                .stdout(std::process::Stdio::null())
-                .stderr(std::process::Stdio::null())
-                .pre_exec(|| {
-                    libc::setsid();
-                    Ok(())
-                })
-                .spawn()?;
-        }
-        Ok(())
+        // Open URL in browser
+        open_url(&self.url)
     }
 }
 
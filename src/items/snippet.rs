@@ -0,0 +1,78 @@
+use crate::assets::PhosphorIcon;
+use crate::clipboard::copy_to_clipboard;
+
+use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
+
+/// A user-defined text snippet, copied when confirmed.
+#[derive(Clone, Debug)]
+pub struct SnippetItem {
+    /// Unique identifier for this item
+    pub id: String,
+    /// Name the snippet is searched by
+    pub name: String,
+    /// Text with its placeholders not yet filled in
+    pub text: String,
+    /// First line of the text, shown below the name
+    pub description: String,
+}
+
+impl SnippetItem {
+    /// Create a snippet item, `index` being its position in the config.
+    pub fn new(index: usize, name: String, text: String) -> Self {
+        let description = text
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .unwrap_or_default()
+            .to_string();
+        Self {
+            id: format!("snippet-{}", index),
+            name,
+            text,
+            description,
+        }
+    }
+
+    /// Get the icon for snippet items.
+    pub fn icon(&self) -> PhosphorIcon {
+        PhosphorIcon::ClipboardText
+    }
+}
+
+impl DisplayItem for SnippetItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> Option<&str> {
+        Some(self.description.as_str()).filter(|desc| !desc.is_empty())
+    }
+
+    fn action_label(&self) -> &'static str {
+        "Copy"
+    }
+}
+
+impl IconProvider for SnippetItem {
+    // Uses Phosphor icons via icon() method
+}
+
+impl Executable for SnippetItem {
+    fn execute(&self) -> anyhow::Result<()> {
+        copy_to_clipboard(&crate::snippets::expand(&self.text)).map_err(anyhow::Error::msg)
+    }
+}
+
+impl Categorizable for SnippetItem {
+    fn section_name(&self) -> &'static str {
+        "Commands"
+    }
+
+    fn sort_priority(&self) -> u8 {
+        3
+    }
+}
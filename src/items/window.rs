@@ -8,7 +8,7 @@ use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
 pub struct WindowItem {
     /// Internal ID for the list
     pub id: String,
-    /// Compositor-specific window address (used for focusing)
+    /// Opaque window address, interpreted by the compositor backend (used for focusing)
     pub address: String,
     /// Window title
     pub title: String,
@@ -16,12 +16,16 @@ pub struct WindowItem {
     pub app_id: String,
     /// Human-readable application name
     pub app_name: String,
-    /// Pre-computed description (e.g., "Firefox - Workspace 2")
+    /// Pre-computed description (e.g., "Firefox - Workspace 2 on DP-1")
     pub description: String,
     /// Resolved icon path
     pub icon_path: Option<PathBuf>,
     /// Workspace number
     pub workspace: i32,
+    /// Name of the monitor showing the window, if known
+    pub monitor: Option<String>,
+    /// Whether this window is on the focused workspace
+    pub on_active_workspace: bool,
     /// Whether this window is currently focused
     pub focused: bool,
 }
@@ -41,7 +45,7 @@ impl WindowItem {
         workspace: i32,
         focused: bool,
     ) -> Self {
-        let description = format!("{} - Workspace {}", app_name, workspace);
+        let description = window_description(&app_name, workspace, None);
         Self {
             id,
             address,
@@ -51,6 +55,8 @@ impl WindowItem {
             description,
             icon_path,
             workspace,
+            monitor: None,
+            on_active_workspace: true,
             focused,
         }
     }
@@ -58,7 +64,7 @@ impl WindowItem {
     /// Create a WindowItem from compositor WindowInfo.
     pub fn from_window_info(info: WindowInfo, icon_path: Option<PathBuf>) -> Self {
         let app_name = titlecase_app_name(&info.class);
-        let description = format!("{} - Workspace {}", app_name, info.workspace);
+        let description = window_description(&app_name, info.workspace, info.monitor.as_deref());
         Self {
             id: format!("window-{}", info.address),
             address: info.address,
@@ -68,11 +74,27 @@ impl WindowItem {
             description,
             icon_path,
             workspace: info.workspace,
+            monitor: info.monitor,
+            on_active_workspace: info.on_active_workspace,
             focused: info.focused,
         }
     }
 }
 
+/// Describe where a window lives, e.g. "Firefox - Workspace 2 on DP-1".
+///
+/// Negative workspaces are ones the compositor couldn't place the window on.
+fn window_description(app_name: &str, workspace: i32, monitor: Option<&str>) -> String {
+    let mut description = app_name.to_string();
+    if workspace >= 0 {
+        description.push_str(&format!(" - Workspace {}", workspace));
+    }
+    if let Some(monitor) = monitor.filter(|m| !m.is_empty()) {
+        description.push_str(&format!(" on {}", monitor));
+    }
+    description
+}
+
 impl DisplayItem for WindowItem {
     fn id(&self) -> &str {
         &self.id
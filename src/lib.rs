@@ -12,4 +12,6 @@ pub mod emoji;
 pub mod ipc;
 pub mod items;
 pub mod search;
+pub mod snippets;
 pub mod ui;
+pub mod util;
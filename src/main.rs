@@ -8,6 +8,6 @@ fn main() -> Result<()> {
 
     match cli.command {
         Some(cmd) => handle_client_command(cmd),
-        None => daemon::run(),
+        None => daemon::run(cli.mode),
     }
 }
@@ -1,9 +1,19 @@
 //! Search trigger detection and query parsing.
 //!
 //! This module provides functionality to detect if user input contains a search trigger
-//! (e.g., "!g rust async") and parse out the provider and query.
+//! (e.g., "!g rust async") and parse out the provider and query, or is a URL to open.
 
-use super::providers::{SearchProvider, get_providers};
+use super::providers::{SearchProvider, find_provider_by_shortcut};
+use crate::ui::utils::parse_url;
+
+/// Top-level domains that make a scheme-less query a URL (e.g. "example.com/foo").
+///
+/// Kept short on purpose: file extensions like `.rs` or `.py` are also valid
+/// TLDs, and queries like "main.rs" should still be searched.
+const COMMON_TLDS: &[&str] = &[
+    "com", "org", "net", "io", "dev", "app", "edu", "gov", "info", "co", "uk", "de", "fr", "nl",
+    "eu",
+];
 
 /// The result of parsing a search query.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -21,8 +31,11 @@ pub enum SearchDetection {
 
 /// Detect if the input contains a search trigger and parse it.
 ///
+/// A trigger is a leading `!bang` token matching a provider's shortcut. Unknown
+/// bangs are not stripped, the whole input is treated as a normal query.
+///
 /// Returns:
-/// - `SearchDetection::Triggered` if input starts with a known trigger (e.g., "!g rust")
+/// - `SearchDetection::Triggered` if input starts with a known bang (e.g., "!g rust")
 /// - `SearchDetection::Fallback` if input should show all providers as fallback
 /// - `SearchDetection::None` if this is not a search query
 pub fn detect_search(input: &str) -> SearchDetection {
@@ -32,22 +45,23 @@ pub fn detect_search(input: &str) -> SearchDetection {
         return SearchDetection::None;
     }
 
-    // Check if input starts with a trigger
-    for provider in get_providers() {
-        if let Some(stripped) = trimmed.strip_prefix(provider.trigger) {
-            // Extract the query after the trigger
-            let query = stripped.trim();
-
-            if query.is_empty() {
-                // Just the trigger, no query yet - don't show anything
-                return SearchDetection::None;
-            }
+    // Check if input starts with a known bang
+    let (bang, rest) = trimmed
+        .split_once(char::is_whitespace)
+        .unwrap_or((trimmed, ""));
+    if let Some(provider) = bang.strip_prefix('!').and_then(find_provider_by_shortcut) {
+        // Extract the query after the bang
+        let query = rest.trim();
 
-            return SearchDetection::Triggered {
-                provider,
-                query: query.to_string(),
-            };
+        if query.is_empty() {
+            // Just the bang, no query yet - don't show anything
+            return SearchDetection::None;
         }
+
+        return SearchDetection::Triggered {
+            provider,
+            query: query.to_string(),
+        };
     }
 
     // No trigger found - this could be a fallback candidate
@@ -57,6 +71,44 @@ pub fn detect_search(input: &str) -> SearchDetection {
     }
 }
 
+/// Detect if the input is a URL to open directly.
+///
+/// Accepts http(s) URLs and scheme-less domains, which get `https://`
+/// prepended. A scheme-less domain needs a `www.` prefix or a common
+/// top-level domain, so ordinary queries containing dots aren't taken over.
+pub fn detect_url(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    if trimmed.contains("://") {
+        return parse_url(trimmed).map(str::to_string);
+    }
+    if trimmed.is_empty() || trimmed.chars().any(char::is_whitespace) {
+        return None;
+    }
+
+    let host = trimmed.split(['/', '?', '#']).next().unwrap_or("");
+    let host = match host.rsplit_once(':') {
+        Some((name, port)) if !port.is_empty() && port.chars().all(|c| c.is_ascii_digit()) => name,
+        _ => host,
+    };
+
+    let labels: Vec<&str> = host.split('.').collect();
+    let valid_labels = labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    if !valid_labels {
+        return None;
+    }
+
+    let tld = labels[labels.len() - 1].to_ascii_lowercase();
+    let is_www = labels[0].eq_ignore_ascii_case("www") && labels.len() >= 3;
+    if is_www || COMMON_TLDS.contains(&tld.as_str()) {
+        Some(format!("https://{}", trimmed))
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +137,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_detect_bang_is_whole_token() {
+        // "!gh" must not be read as "!g" followed by "h"
+        let result = detect_search("!gh rust serde");
+        match result {
+            SearchDetection::Triggered { provider, query } => {
+                assert_eq!(provider.name, "GitHub");
+                assert_eq!(query, "rust serde");
+            }
+            _ => panic!("Expected Triggered"),
+        }
+    }
+
+    #[test]
+    fn test_detect_unknown_bang() {
+        let result = detect_search("!nope rust serde");
+        match result {
+            SearchDetection::Fallback { query } => {
+                assert_eq!(query, "!nope rust serde");
+            }
+            _ => panic!("Expected Fallback"),
+        }
+    }
+
     #[test]
     fn test_detect_trigger_only() {
         let result = detect_search("!g");
@@ -113,4 +189,36 @@ mod tests {
         let result = detect_search("");
         assert_eq!(result, SearchDetection::None);
     }
+
+    #[test]
+    fn test_detect_url() {
+        assert_eq!(
+            detect_url(" https://example.com/foo "),
+            Some("https://example.com/foo".to_string())
+        );
+        assert_eq!(
+            detect_url("example.com/foo?q=1"),
+            Some("https://example.com/foo?q=1".to_string())
+        );
+        assert_eq!(
+            detect_url("www.example.xyz"),
+            Some("https://www.example.xyz".to_string())
+        );
+        assert_eq!(
+            detect_url("localhost.dev:8080/app"),
+            Some("https://localhost.dev:8080/app".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_url_rejects_searches() {
+        assert_eq!(detect_url("main.rs"), None);
+        // Uncommon TLDs need a scheme
+        assert_eq!(detect_url("docs.rs/serde"), None);
+        assert_eq!(detect_url("rust async"), None);
+        assert_eq!(detect_url("what is example.com"), None);
+        assert_eq!(detect_url("me@example.com"), None);
+        assert_eq!(detect_url("3.14"), None);
+        assert_eq!(detect_url("ftp://example.com"), None);
+    }
 }
@@ -0,0 +1,199 @@
+//! File search streaming provider.
+//!
+//! Queries starting with `~` or `/` browse a directory: `~/Doc` lists the
+//! entries of the home directory starting with "Doc". Other queries of at
+//! least `MIN_QUERY_LEN` characters search file names under the home
+//! directory, breadth first so shallow matches come first.
+
+use crate::items::{FileItem, ListItem};
+use crate::search::{BatchSink, StreamingProvider};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+/// Shortest plain query that starts a file name search.
+const MIN_QUERY_LEN: usize = 3;
+
+/// Maximum number of file results per query.
+const MAX_RESULTS: usize = 20;
+
+/// Maximum directory depth below home searched for file names.
+const MAX_DEPTH: usize = 6;
+
+/// Number of matches pushed to the list at once.
+const BATCH_SIZE: usize = 5;
+
+/// Directories that are never descended into (besides hidden ones).
+const SKIPPED_DIRS: &[&str] = &["node_modules", "target", "__pycache__"];
+
+/// Searches the file system for files by path or name.
+pub struct FileSearchProvider {
+    home: Option<PathBuf>,
+}
+
+impl FileSearchProvider {
+    pub fn new() -> Self {
+        Self {
+            home: dirs::home_dir(),
+        }
+    }
+
+    /// List the entries of a directory starting with a prefix.
+    fn browse(&self, dir: &Path, prefix: &str, sink: &BatchSink) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        let prefix = prefix.to_lowercase();
+        // Hidden files only show up when asked for
+        let show_hidden = prefix.starts_with('.');
+
+        let mut matches: Vec<(PathBuf, bool)> = entries
+            .flatten()
+            .filter(|entry| {
+                let name = entry.file_name().to_string_lossy().to_lowercase();
+                name.starts_with(&prefix) && (show_hidden || !name.starts_with('.'))
+            })
+            .map(|entry| {
+                let is_dir = entry.path().is_dir();
+                (entry.path(), is_dir)
+            })
+            .collect();
+
+        // Directories first, then by name
+        matches.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        matches.truncate(MAX_RESULTS);
+
+        sink.push(
+            matches
+                .into_iter()
+                .map(|(path, is_dir)| ListItem::File(FileItem::new(path, is_dir)))
+                .collect(),
+        );
+    }
+
+    /// Search file names under home containing the query, ignoring case.
+    fn search_names(&self, home: &Path, query: &str, sink: &BatchSink) {
+        let query = query.to_lowercase();
+        let mut queue = VecDeque::from([(home.to_path_buf(), 0)]);
+        let mut batch = Vec::new();
+        let mut found = 0;
+
+        while let Some((dir, depth)) = queue.pop_front() {
+            if sink.is_stale() {
+                return;
+            }
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().to_lowercase();
+                if name.starts_with('.') {
+                    continue;
+                }
+                // Not following symlinks keeps the search out of loops
+                let is_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+
+                if name.contains(&query) {
+                    batch.push(ListItem::File(FileItem::new(entry.path(), is_dir)));
+                    found += 1;
+                    if found >= MAX_RESULTS {
+                        sink.push(batch);
+                        return;
+                    }
+                    if batch.len() >= BATCH_SIZE && !sink.push(std::mem::take(&mut batch)) {
+                        return;
+                    }
+                }
+
+                if is_dir && depth < MAX_DEPTH && !SKIPPED_DIRS.contains(&name.as_str()) {
+                    queue.push_back((entry.path(), depth + 1));
+                }
+            }
+        }
+
+        sink.push(batch);
+    }
+}
+
+impl Default for FileSearchProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StreamingProvider for FileSearchProvider {
+    fn name(&self) -> &'static str {
+        "files"
+    }
+
+    fn handles(&self, query: &str) -> bool {
+        is_path_query(query) || query.trim().chars().count() >= MIN_QUERY_LEN
+    }
+
+    fn search(&self, query: &str, sink: &BatchSink) {
+        if is_path_query(query) {
+            if let Some((dir, prefix)) = split_path_query(query, self.home.as_deref()) {
+                self.browse(&dir, &prefix, sink);
+            }
+        } else if let Some(home) = &self.home {
+            self.search_names(home, query.trim(), sink);
+        }
+    }
+}
+
+/// Check whether a query is a path (`~`, `~/...` or `/...`).
+fn is_path_query(query: &str) -> bool {
+    query == "~" || query.starts_with("~/") || query.starts_with('/')
+}
+
+/// Split a path query into the directory to list and the name prefix.
+///
+/// `~` is expanded to the home directory. A trailing `/` lists the whole
+/// directory.
+fn split_path_query(query: &str, home: Option<&Path>) -> Option<(PathBuf, String)> {
+    let path = match query.strip_prefix('~') {
+        Some(rest) => PathBuf::from(format!("{}{}", home?.display(), rest)),
+        None => PathBuf::from(query),
+    };
+
+    if query == "~" || query.ends_with('/') {
+        return Some((path, String::new()));
+    }
+    let prefix = path.file_name()?.to_string_lossy().into_owned();
+    Some((path.parent()?.to_path_buf(), prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_path_query() {
+        assert!(is_path_query("~"));
+        assert!(is_path_query("~/Doc"));
+        assert!(is_path_query("/etc/"));
+        assert!(!is_path_query("~user"));
+        assert!(!is_path_query("report"));
+    }
+
+    #[test]
+    fn test_split_path_query() {
+        let home = Path::new("/home/me");
+        let split = |query| split_path_query(query, Some(home));
+        assert_eq!(
+            split("~/Doc"),
+            Some((PathBuf::from("/home/me"), "Doc".to_string()))
+        );
+        assert_eq!(
+            split("~/Documents/"),
+            Some((PathBuf::from("/home/me/Documents/"), String::new()))
+        );
+        assert_eq!(split("~"), Some((PathBuf::from("/home/me"), String::new())));
+        assert_eq!(
+            split("/etc/host"),
+            Some((PathBuf::from("/etc"), "host".to_string()))
+        );
+        assert_eq!(split("/"), Some((PathBuf::from("/"), String::new())));
+        assert_eq!(split_path_query("~/Doc", None), None);
+    }
+}
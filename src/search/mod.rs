@@ -1,12 +1,24 @@
 //! Search module for web search integration.
 //!
 //! This module provides functionality to:
-//! - Detect search triggers (e.g., !g, !wiki, !d, !yt)
+//! - Detect bang shortcuts (e.g., !g, !wiki, !d, !yt, !gh)
 //! - Parse search queries
+//! - Detect URLs typed or pasted into the query
 //! - Generate search URLs for various providers
+//! - Open URLs in the browser
+//! - Stream results from slow providers in batches
+//! - Search files by path or name
 
 mod detection;
+mod files;
+mod open;
 mod providers;
+mod streaming;
 
-pub use detection::{SearchDetection, detect_search};
-pub use providers::{SearchProvider, find_provider_by_trigger, get_providers};
+pub use detection::{SearchDetection, detect_search, detect_url};
+pub use files::FileSearchProvider;
+pub use open::{open_path, open_url, reveal_paths};
+pub use providers::{
+    SearchProvider, default_provider, find_provider_by_shortcut, get_providers, ordered_providers,
+};
+pub use streaming::{BatchSink, ResultBatch, StreamingProvider, spawn_providers};
@@ -0,0 +1,156 @@
+//! Opening URLs in the user's browser and files in their default application.
+
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+
+/// Placeholder for the URL in the browser command.
+const URL_PLACEHOLDER: &str = "{url}";
+
+/// Command used when no browser is configured.
+const DEFAULT_BROWSER: &str = "xdg-open";
+
+/// Command opening files in their default application.
+const FILE_OPENER: &str = "xdg-open";
+
+/// D-Bus name, path and interface of the file manager service.
+const FILE_MANAGER_SERVICE: &str = "org.freedesktop.FileManager1";
+const FILE_MANAGER_PATH: &str = "/org/freedesktop/FileManager1";
+
+/// Open a URL with the configured `browser` (`xdg-open` when unset),
+/// disowned from the daemon.
+pub fn open_url(url: &str) -> anyhow::Result<()> {
+    let browser = crate::config::config().browser;
+    spawn_disowned(&browser_command(
+        browser.as_deref().unwrap_or(DEFAULT_BROWSER),
+        url,
+    ))
+}
+
+/// Open a file or directory with `xdg-open`, disowned from the daemon.
+pub fn open_path(path: &Path) -> anyhow::Result<()> {
+    spawn_disowned(&[FILE_OPENER.to_string(), path.to_string_lossy().into_owned()])
+}
+
+/// Show files in the file manager.
+///
+/// A single file is selected in its folder when a file manager implements
+/// the `org.freedesktop.FileManager1` interface. Otherwise the closest folder
+/// containing all paths is opened with `xdg-open`.
+pub fn reveal_paths(paths: &[PathBuf]) -> anyhow::Result<()> {
+    if let [path] = paths
+        && path.exists()
+    {
+        match show_item(path) {
+            Ok(()) => return Ok(()),
+            Err(e) => tracing::debug!(%e, "File manager can't select files, opening folder"),
+        }
+    }
+
+    let Some(folder) = common_parent(paths) else {
+        anyhow::bail!("No folder to open");
+    };
+    open_path(&folder)
+}
+
+/// Select a file in the file manager over D-Bus.
+fn show_item(path: &Path) -> anyhow::Result<()> {
+    let uri = format!(
+        "file://{}",
+        path.to_string_lossy()
+            .split('/')
+            .map(|part| urlencoding::encode(part).into_owned())
+            .collect::<Vec<_>>()
+            .join("/")
+    );
+    let connection = zbus::blocking::Connection::session()?;
+    connection.call_method(
+        Some(FILE_MANAGER_SERVICE),
+        FILE_MANAGER_PATH,
+        Some(FILE_MANAGER_SERVICE),
+        "ShowItems",
+        &(vec![uri], ""),
+    )?;
+    Ok(())
+}
+
+/// Get the deepest folder containing all of the paths.
+fn common_parent(paths: &[PathBuf]) -> Option<PathBuf> {
+    let mut folder = paths.first()?.parent()?.to_path_buf();
+    while !paths.iter().all(|path| path.starts_with(&folder)) {
+        folder = folder.parent()?.to_path_buf();
+    }
+    Some(folder)
+}
+
+/// Spawn a command in its own session.
+fn spawn_disowned(command: &[String]) -> anyhow::Result<()> {
+    let Some((program, args)) = command.split_first() else {
+        anyhow::bail!("Empty command");
+    };
+
+    // SAFETY: setsid() is async-signal-safe
+    unsafe {
+        std::process::Command::new(program)
+            .args(args)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .pre_exec(|| {
+                libc::setsid();
+                Ok(())
+            })
+            .spawn()?;
+    }
+    Ok(())
+}
+
+/// Build the browser command line for a URL.
+///
+/// Every `{url}` argument is replaced by the URL; a template without the
+/// placeholder gets it appended.
+fn browser_command(template: &str, url: &str) -> Vec<String> {
+    let mut command: Vec<String> = template.split_whitespace().map(str::to_string).collect();
+
+    if command.iter().any(|part| part == URL_PLACEHOLDER) {
+        for part in command.iter_mut().filter(|part| *part == URL_PLACEHOLDER) {
+            *part = url.to_string();
+        }
+    } else {
+        command.push(url.to_string());
+    }
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_browser_command() {
+        let url = "https://example.com/?q=a%20b";
+        assert_eq!(
+            browser_command("firefox --new-tab {url}", url),
+            ["firefox", "--new-tab", url]
+        );
+        // Without a placeholder the URL is appended
+        assert_eq!(browser_command("xdg-open", url), ["xdg-open", url]);
+    }
+
+    #[test]
+    fn test_common_parent() {
+        let paths = |list: &[&str]| list.iter().map(PathBuf::from).collect::<Vec<_>>();
+        assert_eq!(
+            common_parent(&paths(&["/home/me/a.txt"])),
+            Some(PathBuf::from("/home/me"))
+        );
+        assert_eq!(
+            common_parent(&paths(&["/home/me/docs/a.txt", "/home/me/b.txt"])),
+            Some(PathBuf::from("/home/me"))
+        );
+        assert_eq!(
+            common_parent(&paths(&["/home/me/a", "/tmp/b"])),
+            Some(PathBuf::from("/"))
+        );
+        assert_eq!(common_parent(&[]), None);
+    }
+}
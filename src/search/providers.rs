@@ -1,62 +1,327 @@
 //! Search provider definitions.
 //!
-//! This module defines the available search providers (Google, DuckDuckGo, Wikipedia, YouTube)
-//! with their triggers, URL templates, and icons.
+//! This module defines the available search providers (Google, DuckDuckGo, Wikipedia, YouTube,
+//! GitHub) with their bang shortcuts, URL templates, and icons. Additional providers can be
+//! defined in the config file (`search_providers`).
 
 use crate::assets::PhosphorIcon;
+use crate::config::SearchProviderConfig;
+use std::sync::OnceLock;
+
+/// Placeholder replaced by the encoded query in URL templates.
+const QUERY_PLACEHOLDER: &str = "{query}";
+
+/// Provider used when `default_search_provider` is unset or unknown.
+const FALLBACK_DEFAULT_PROVIDER: &str = "Google";
 
 /// A search provider configuration.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SearchProvider {
     /// The provider name (e.g., "Google", "DuckDuckGo")
-    pub name: &'static str,
-    /// The trigger string (e.g., "!g", "!d", "!wiki")
-    pub trigger: &'static str,
+    pub name: String,
+    /// The bang shortcut without the leading `!` (e.g., "g" for "!g rust")
+    pub shortcut: Option<String>,
     /// The URL template with {query} placeholder
-    pub url_template: &'static str,
+    pub url_template: String,
     /// The Phosphor icon to use
     pub icon: PhosphorIcon,
 }
 
 impl SearchProvider {
+    /// Create a built-in provider.
+    fn builtin(name: &str, shortcut: &str, url_template: &str, icon: PhosphorIcon) -> Self {
+        Self {
+            name: name.to_string(),
+            shortcut: Some(shortcut.to_string()),
+            url_template: url_template.to_string(),
+            icon,
+        }
+    }
+
+    /// Create a provider from a user-defined config entry.
+    ///
+    /// Fails if the name is empty, the URL template lacks the `{query}`
+    /// placeholder, the shortcut contains whitespace, or the icon is unknown.
+    pub fn from_config(config: &SearchProviderConfig) -> anyhow::Result<Self> {
+        let name = config.name.trim();
+        if name.is_empty() {
+            anyhow::bail!("provider name is empty");
+        }
+        if !config.url.contains(QUERY_PLACEHOLDER) {
+            anyhow::bail!(
+                "url {:?} has no {} placeholder",
+                config.url,
+                QUERY_PLACEHOLDER
+            );
+        }
+
+        // Accept the shortcut with or without the leading `!`
+        let shortcut = config
+            .shortcut
+            .as_deref()
+            .map(|s| s.trim().trim_start_matches('!'))
+            .filter(|s| !s.is_empty());
+        if let Some(shortcut) = shortcut
+            && shortcut.contains(char::is_whitespace)
+        {
+            anyhow::bail!("shortcut {:?} contains whitespace", shortcut);
+        }
+
+        let icon = match config.icon.as_deref() {
+            Some(icon) => PhosphorIcon::from_name(icon)
+                .ok_or_else(|| anyhow::anyhow!("unknown icon {:?}", icon))?,
+            None => PhosphorIcon::MagnifyingGlass,
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            shortcut: shortcut.map(str::to_string),
+            url_template: config.url.clone(),
+            icon,
+        })
+    }
+
     /// Build a search URL with the given query.
     pub fn build_url(&self, query: &str) -> String {
         let encoded_query = urlencoding::encode(query);
-        self.url_template.replace("{query}", &encoded_query)
+        self.url_template.replace(QUERY_PLACEHOLDER, &encoded_query)
     }
 }
 
+/// Get the user-defined providers from the config.
+///
+/// Read once, so malformed entries are only reported a single time.
+fn custom_providers() -> &'static [SearchProvider] {
+    static CUSTOM: OnceLock<Vec<SearchProvider>> = OnceLock::new();
+    CUSTOM.get_or_init(|| {
+        crate::config::config()
+            .search_providers
+            .iter()
+            .filter_map(|config| match SearchProvider::from_config(config) {
+                Ok(provider) => Some(provider),
+                Err(e) => {
+                    tracing::error!(
+                        "Ignoring search provider {:?} from config: {}",
+                        config.name,
+                        e
+                    );
+                    None
+                }
+            })
+            .collect()
+    })
+}
+
 /// Get all available search providers.
+///
+/// User-defined providers come after the built-ins; one with the same name as
+/// a built-in provider replaces it.
 pub fn get_providers() -> Vec<SearchProvider> {
-    vec![
-        SearchProvider {
-            name: "Google",
-            trigger: "!g",
-            url_template: "https://www.google.com/search?q={query}",
-            icon: PhosphorIcon::MagnifyingGlass,
-        },
-        SearchProvider {
-            name: "DuckDuckGo",
-            trigger: "!d",
-            url_template: "https://duckduckgo.com/?q={query}",
-            icon: PhosphorIcon::Globe,
-        },
-        SearchProvider {
-            name: "Wikipedia",
-            trigger: "!wiki",
-            url_template: "https://en.wikipedia.org/wiki/Special:Search?search={query}",
-            icon: PhosphorIcon::BookOpen,
-        },
-        SearchProvider {
-            name: "YouTube",
-            trigger: "!yt",
-            url_template: "https://www.youtube.com/results?search_query={query}",
-            icon: PhosphorIcon::YoutubeLogo,
-        },
-    ]
-}
-
-/// Find a provider by its trigger.
-pub fn find_provider_by_trigger(trigger: &str) -> Option<SearchProvider> {
-    get_providers().into_iter().find(|p| p.trigger == trigger)
+    let mut providers = vec![
+        SearchProvider::builtin(
+            "Google",
+            "g",
+            "https://www.google.com/search?q={query}",
+            PhosphorIcon::MagnifyingGlass,
+        ),
+        SearchProvider::builtin(
+            "DuckDuckGo",
+            "d",
+            "https://duckduckgo.com/?q={query}",
+            PhosphorIcon::Globe,
+        ),
+        SearchProvider::builtin(
+            "Wikipedia",
+            "wiki",
+            "https://en.wikipedia.org/wiki/Special:Search?search={query}",
+            PhosphorIcon::BookOpen,
+        ),
+        SearchProvider::builtin(
+            "YouTube",
+            "yt",
+            "https://www.youtube.com/results?search_query={query}",
+            PhosphorIcon::YoutubeLogo,
+        ),
+        SearchProvider::builtin(
+            "GitHub",
+            "gh",
+            "https://github.com/search?q={query}",
+            PhosphorIcon::Code,
+        ),
+    ];
+
+    for custom in custom_providers() {
+        match providers
+            .iter_mut()
+            .find(|p| p.name.eq_ignore_ascii_case(&custom.name))
+        {
+            Some(existing) => *existing = custom.clone(),
+            None => providers.push(custom.clone()),
+        }
+    }
+
+    providers
+}
+
+/// Get the provider offered first for queries without a bang.
+///
+/// This is the configured `default_search_provider`, or Google when it is
+/// unset or doesn't name a known provider.
+pub fn default_provider() -> SearchProvider {
+    static WARNED: OnceLock<()> = OnceLock::new();
+
+    let providers = get_providers();
+    let configured = crate::config::config().default_search_provider;
+    if let Some(name) = configured.as_deref()
+        && find_by_name(&providers, name).is_none()
+    {
+        WARNED.get_or_init(|| {
+            tracing::warn!(
+                "Unknown default search provider {:?}, using {}",
+                name,
+                FALLBACK_DEFAULT_PROVIDER
+            );
+        });
+    }
+    resolve_default(providers, configured.as_deref())
+}
+
+/// Get the providers offered for queries without a bang.
+///
+/// The default provider comes first, then the providers named in
+/// `search_provider_order`, then the remaining ones in their usual order.
+pub fn ordered_providers() -> Vec<SearchProvider> {
+    let default = default_provider();
+    order_providers(
+        get_providers(),
+        &default.name,
+        &crate::config::config().search_provider_order,
+    )
+}
+
+/// Order providers: the default one, then by priority, then the rest.
+fn order_providers(
+    mut providers: Vec<SearchProvider>,
+    default: &str,
+    priority: &[String],
+) -> Vec<SearchProvider> {
+    let rank = |provider: &SearchProvider| {
+        if provider.name == default {
+            return 0;
+        }
+        priority
+            .iter()
+            .position(|name| provider.name.eq_ignore_ascii_case(name.trim()))
+            .map_or(usize::MAX, |idx| idx + 1)
+    };
+    // Stable, so unlisted providers keep their order
+    providers.sort_by_key(rank);
+    providers
+}
+
+/// Pick the default provider by name, falling back to the built-in default.
+fn resolve_default(providers: Vec<SearchProvider>, name: Option<&str>) -> SearchProvider {
+    name.and_then(|name| find_by_name(&providers, name))
+        .or_else(|| find_by_name(&providers, FALLBACK_DEFAULT_PROVIDER))
+        .or_else(|| providers.first())
+        .cloned()
+        .expect("built-in providers are never empty")
+}
+
+/// Find a provider by name, ignoring case.
+fn find_by_name<'a>(providers: &'a [SearchProvider], name: &str) -> Option<&'a SearchProvider> {
+    providers
+        .iter()
+        .find(|p| p.name.eq_ignore_ascii_case(name.trim()))
+}
+
+/// Find a provider by its bang shortcut (without the leading `!`).
+pub fn find_provider_by_shortcut(shortcut: &str) -> Option<SearchProvider> {
+    get_providers()
+        .into_iter()
+        .find(|p| p.shortcut.as_deref() == Some(shortcut))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(
+        name: &str,
+        url: &str,
+        icon: Option<&str>,
+        shortcut: Option<&str>,
+    ) -> SearchProviderConfig {
+        SearchProviderConfig {
+            name: name.to_string(),
+            url: url.to_string(),
+            icon: icon.map(str::to_string),
+            shortcut: shortcut.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_custom_provider() {
+        let provider = SearchProvider::from_config(&config(
+            "Wiki",
+            "https://wiki.example.com/search?q={query}",
+            Some("book-open"),
+            Some("!w"),
+        ))
+        .unwrap();
+        assert_eq!(provider.shortcut.as_deref(), Some("w"));
+        assert_eq!(provider.icon, PhosphorIcon::BookOpen);
+        assert_eq!(
+            provider.build_url("a b"),
+            "https://wiki.example.com/search?q=a%20b"
+        );
+
+        // Icon and shortcut are optional
+        let provider =
+            SearchProvider::from_config(&config("Wiki", "https://w/?q={query}", None, None))
+                .unwrap();
+        assert_eq!(provider.shortcut, None);
+        assert_eq!(provider.icon, PhosphorIcon::MagnifyingGlass);
+    }
+
+    #[test]
+    fn test_malformed_custom_provider() {
+        let url = "https://w/?q={query}";
+        let no_placeholder = "https://w/?q=";
+        assert!(SearchProvider::from_config(&config("Wiki", no_placeholder, None, None)).is_err());
+        assert!(SearchProvider::from_config(&config(" ", url, None, None)).is_err());
+        assert!(SearchProvider::from_config(&config("Wiki", url, Some("nope"), None)).is_err());
+        assert!(SearchProvider::from_config(&config("Wiki", url, None, Some("w x"))).is_err());
+    }
+
+    #[test]
+    fn test_resolve_default() {
+        assert_eq!(resolve_default(get_providers(), None).name, "Google");
+        assert_eq!(
+            resolve_default(get_providers(), Some("duckduckgo")).name,
+            "DuckDuckGo"
+        );
+        // Unknown names fall back to the built-in default
+        assert_eq!(
+            resolve_default(get_providers(), Some("Bing")).name,
+            "Google"
+        );
+    }
+
+    #[test]
+    fn test_order_providers() {
+        let priority = [
+            "github".to_string(),
+            "Bing".to_string(),
+            "Wikipedia".to_string(),
+        ];
+        let names: Vec<String> = order_providers(get_providers(), "DuckDuckGo", &priority)
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        assert_eq!(
+            names,
+            ["DuckDuckGo", "GitHub", "Wikipedia", "Google", "YouTube"]
+        );
+    }
 }
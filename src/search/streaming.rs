@@ -0,0 +1,133 @@
+//! Streaming result providers.
+//!
+//! Slow providers (file search, network lookups) don't fit into the synchronous
+//! filtering done by the item delegate. Instead they run on a background thread
+//! and push partial batches of results through a channel as they are found.
+//!
+//! Every batch is tagged with the query generation it was produced for, so the
+//! UI can discard batches that belong to a query the user has already replaced.
+
+use crate::items::ListItem;
+use flume::{Receiver, Sender};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A batch of results produced by a streaming provider.
+#[derive(Clone, Debug)]
+pub struct ResultBatch {
+    /// Query generation this batch belongs to
+    pub generation: u64,
+    /// Items found since the previous batch
+    pub items: Vec<ListItem>,
+}
+
+/// Handle used by providers to push result batches back to the UI.
+pub struct BatchSink {
+    generation: u64,
+    current_generation: Arc<AtomicU64>,
+    tx: Sender<ResultBatch>,
+}
+
+impl BatchSink {
+    /// Push a batch of results.
+    ///
+    /// Returns `false` when the query has been superseded or the receiver is
+    /// gone, in which case the provider should stop searching.
+    pub fn push(&self, items: Vec<ListItem>) -> bool {
+        if self.is_stale() {
+            return false;
+        }
+        if items.is_empty() {
+            return true;
+        }
+        self.tx
+            .send(ResultBatch {
+                generation: self.generation,
+                items,
+            })
+            .is_ok()
+    }
+
+    /// Check whether a newer query has replaced the one being searched.
+    pub fn is_stale(&self) -> bool {
+        self.current_generation.load(Ordering::Relaxed) != self.generation
+    }
+}
+
+/// A provider whose results arrive incrementally.
+pub trait StreamingProvider: Send + Sync {
+    /// Name of the provider (used for logging).
+    fn name(&self) -> &'static str;
+
+    /// Whether this provider wants to handle the given query.
+    fn handles(&self, query: &str) -> bool;
+
+    /// Search for the query, pushing results through `sink` as they are found.
+    ///
+    /// Runs on a background thread. Implementations should check the return
+    /// value of [`BatchSink::push`] and bail out early once it returns `false`.
+    fn search(&self, query: &str, sink: &BatchSink);
+}
+
+/// Start all providers interested in `query` on background threads.
+///
+/// Returns `None` when no provider handles the query. The receiver closes once
+/// every spawned provider has finished.
+pub fn spawn_providers(
+    providers: &[Arc<dyn StreamingProvider>],
+    query: &str,
+    generation: u64,
+    current_generation: Arc<AtomicU64>,
+) -> Option<Receiver<ResultBatch>> {
+    let active: Vec<_> = providers
+        .iter()
+        .filter(|provider| provider.handles(query))
+        .cloned()
+        .collect();
+
+    if active.is_empty() {
+        return None;
+    }
+
+    let (tx, rx) = flume::unbounded::<ResultBatch>();
+
+    for provider in active {
+        let sink = BatchSink {
+            generation,
+            current_generation: current_generation.clone(),
+            tx: tx.clone(),
+        };
+        let query = query.to_string();
+
+        std::thread::spawn(move || {
+            tracing::debug!(provider = provider.name(), %query, "Streaming provider started");
+            provider.search(&query, &sink);
+        });
+    }
+
+    Some(rx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stale_sink_drops_batches() {
+        let current = Arc::new(AtomicU64::new(1));
+        let (tx, rx) = flume::unbounded();
+        let sink = BatchSink {
+            generation: 1,
+            current_generation: current.clone(),
+            tx,
+        };
+
+        assert!(sink.push(Vec::new()));
+        assert!(!sink.is_stale());
+
+        current.store(2, Ordering::Relaxed);
+        assert!(sink.is_stale());
+        assert!(!sink.push(Vec::new()));
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,81 @@
+//! User-defined text snippets.
+//!
+//! Snippets are configured with `[[snippets]]` entries and listed with the
+//! commands of the main view, where confirming one copies its text. The text
+//! can contain placeholders that are filled in when it's copied:
+//! - `{date}`: today's date ("2026-10-16")
+//! - `{time}`: the current time ("14:05")
+//! - `{clipboard}`: the latest text copied to the clipboard
+
+use crate::items::SnippetItem;
+
+/// Get the configured snippets as list items.
+pub fn snippet_items() -> Vec<SnippetItem> {
+    crate::config::config()
+        .snippets
+        .into_iter()
+        .enumerate()
+        .map(|(idx, snippet)| SnippetItem::new(idx, snippet.name, snippet.text))
+        .collect()
+}
+
+/// Fill in the placeholders of a snippet's text.
+pub fn expand(text: &str) -> String {
+    let now = chrono::Local::now();
+    expand_with(text, |name| match name {
+        "date" => Some(now.format("%Y-%m-%d").to_string()),
+        "time" => Some(now.format("%H:%M").to_string()),
+        "clipboard" => Some(crate::clipboard::data::latest_text().unwrap_or_default()),
+        _ => None,
+    })
+}
+
+/// Replace `{name}` placeholders with the values `lookup` returns for them.
+///
+/// Unknown placeholders and unmatched braces are kept as they are.
+fn expand_with(text: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut expanded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let placeholder = after
+            .find('}')
+            .and_then(|end| Some((end, lookup(&after[..end])?)));
+        match placeholder {
+            Some((end, value)) => {
+                expanded.push_str(&value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                expanded.push('{');
+                rest = after;
+            }
+        }
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_placeholders() {
+        let lookup = |name: &str| match name {
+            "date" => Some("2026-10-16".to_string()),
+            "clipboard" => Some("{date}".to_string()),
+            _ => None,
+        };
+        assert_eq!(
+            expand_with("Sent {date}: {clipboard}", lookup),
+            "Sent 2026-10-16: {date}"
+        );
+        // Unknown placeholders and stray braces stay
+        assert_eq!(
+            expand_with("fn {name}() { {{date}} }", lookup),
+            "fn {name}() { {2026-10-16} }"
+        );
+    }
+}
@@ -183,7 +183,7 @@ fn render_text_content(name: &str, description: Option<&str>, selected: bool) ->
 
     let name_element = div()
         .w_full()
-        .text_sm()
+        .text_size(theme.item_title_font_size)
         .line_height(theme.item_title_line_height)
         .text_color(theme.item_title_color)
         .whitespace_nowrap()
@@ -205,7 +205,7 @@ fn render_text_content(name: &str, description: Option<&str>, selected: bool) ->
     if let Some(desc) = description {
         let description_element = div()
             .w_full()
-            .text_xs()
+            .text_size(theme.item_description_font_size)
             .h(theme.layout.item_description_height)
             .text_color(theme.item_description_color)
             .whitespace_nowrap()
@@ -0,0 +1,197 @@
+use crate::items::{ActionItem, ListItem};
+use crate::ui::delegates::BaseDelegate;
+use crate::ui::theme::theme;
+use crate::ui::views::render_item;
+use gpui::{App, Context, SharedString, Task, Window, div, prelude::*};
+use gpui_component::IndexPath;
+use gpui_component::list::{ListDelegate, ListItem as GpuiListItem, ListState};
+
+/// Delegate for the actions of a submenu (e.g. the power menu).
+pub struct ActionListDelegate {
+    /// Base delegate handling common behavior
+    base: BaseDelegate<ActionItem>,
+}
+
+impl ActionListDelegate {
+    /// Create a new action list delegate
+    pub fn new(items: Vec<ActionItem>) -> Self {
+        Self {
+            base: BaseDelegate::new(items),
+        }
+    }
+
+    /// Set the confirm callback (run the action)
+    pub fn set_on_confirm(&mut self, callback: impl Fn(&ActionItem) + Send + Sync + 'static) {
+        self.base.set_on_confirm(callback);
+    }
+
+    /// Set the cancel callback
+    pub fn set_on_cancel(&mut self, callback: impl Fn() + Send + Sync + 'static) {
+        self.base.set_on_cancel(callback);
+    }
+
+    /// Get the currently selected index
+    pub fn selected_index(&self) -> Option<usize> {
+        self.base.selected_index()
+    }
+
+    /// Get the total count of filtered items
+    pub fn filtered_count(&self) -> usize {
+        self.base.filtered_count()
+    }
+
+    /// Set the query and filter
+    pub fn set_query(&mut self, query: String) {
+        self.base.set_query(query);
+        self.filter_items();
+    }
+
+    /// Filter items based on the current query
+    fn filter_items(&mut self) {
+        let query = self.base.query();
+        if query.is_empty() {
+            self.base.reset_filter();
+        } else {
+            let query_lower = query.to_lowercase();
+            let filtered_indices: Vec<usize> = self
+                .base
+                .items()
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.name.to_lowercase().contains(&query_lower))
+                .map(|(idx, _)| idx)
+                .collect();
+            self.base.apply_filtered_indices(filtered_indices);
+        }
+    }
+
+    /// Get the currently selected item
+    pub fn selected_item(&self) -> Option<&ActionItem> {
+        self.base.selected_item()
+    }
+
+    /// Execute confirm callback
+    pub fn do_confirm(&self) {
+        self.base.do_confirm();
+    }
+
+    /// Execute cancel callback
+    pub fn do_cancel(&self) {
+        self.base.do_cancel();
+    }
+
+    /// Move selection down
+    pub fn select_down(&mut self) {
+        self.base.select_down();
+    }
+
+    /// Move selection up
+    pub fn select_up(&mut self) {
+        self.base.select_up();
+    }
+
+    /// Move selection down by a page (clamped)
+    pub fn select_page_down(&mut self, page: usize) {
+        self.base.select_page_down(page);
+    }
+
+    /// Move selection up by a page (clamped)
+    pub fn select_page_up(&mut self, page: usize) {
+        self.base.select_page_up(page);
+    }
+
+    /// Select the first item
+    pub fn select_first(&mut self) {
+        self.base.select_first();
+    }
+
+    /// Select the last item
+    pub fn select_last(&mut self) {
+        self.base.select_last();
+    }
+}
+
+/// Implement ListDelegate trait for GPUI integration.
+impl ListDelegate for ActionListDelegate {
+    type Item = GpuiListItem;
+
+    fn sections_count(&self, _cx: &App) -> usize {
+        1
+    }
+
+    fn items_count(&self, _section: usize, _cx: &App) -> usize {
+        self.filtered_count()
+    }
+
+    fn render_item(
+        &mut self,
+        ix: IndexPath,
+        _window: &mut Window,
+        _cx: &mut Context<'_, ListState<Self>>,
+    ) -> Option<Self::Item> {
+        let item = self.base.get_filtered_item(ix.row)?;
+        let is_selected = self.base.selected_index() == Some(ix.row);
+
+        let element = render_item(&ListItem::Action(item.clone()), is_selected, ix.row, &[]);
+
+        // Reset ListItem default padding - we handle all styling ourselves
+        Some(
+            GpuiListItem::new(("action-item", ix.row))
+                .py_0()
+                .px_0()
+                .child(element),
+        )
+    }
+
+    fn set_selected_index(
+        &mut self,
+        ix: Option<IndexPath>,
+        _window: &mut Window,
+        _cx: &mut Context<ListState<Self>>,
+    ) {
+        self.base.set_selected(ix.map(|i| i.row).unwrap_or(0));
+    }
+
+    fn perform_search(
+        &mut self,
+        query: &str,
+        _window: &mut Window,
+        _cx: &mut Context<ListState<Self>>,
+    ) -> Task<()> {
+        self.set_query(query.to_string());
+        Task::ready(())
+    }
+
+    fn confirm(
+        &mut self,
+        _secondary: bool,
+        _window: &mut Window,
+        _cx: &mut Context<ListState<Self>>,
+    ) {
+        self.do_confirm();
+    }
+
+    fn cancel(&mut self, _window: &mut Window, _cx: &mut Context<ListState<Self>>) {
+        self.do_cancel();
+    }
+
+    fn render_empty(
+        &mut self,
+        _window: &mut Window,
+        _cx: &mut Context<'_, ListState<Self>>,
+    ) -> impl IntoElement {
+        let theme = theme();
+        div()
+            .w_full()
+            .h(theme.empty_state_height)
+            .flex()
+            .items_center()
+            .justify_center()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(theme.empty_state_color)
+                    .child(SharedString::from("No actions found")),
+            )
+    }
+}
@@ -130,9 +130,14 @@ impl<T: Clone> BaseDelegate<T> {
 
     /// Execute the confirm callback
     pub fn do_confirm(&self) {
-        if let Some(item) = self.selected_item()
-            && let Some(ref callback) = self.on_confirm
-        {
+        if let Some(item) = self.selected_item() {
+            self.confirm_item(item);
+        }
+    }
+
+    /// Execute the confirm callback with the given item instead of the selected one
+    pub fn confirm_item(&self, item: &T) {
+        if let Some(ref callback) = self.on_confirm {
             callback(item);
         }
     }
@@ -168,11 +173,52 @@ impl<T: Clone> BaseDelegate<T> {
         self.selected_index = Some(prev);
     }
 
+    /// Move selection down by `page` items (clamped, no wrapping)
+    pub fn select_page_down(&mut self, page: usize) {
+        let count = self.filtered_count();
+        if count == 0 {
+            return;
+        }
+
+        let current = self.selected_index.unwrap_or(0);
+        self.selected_index = Some((current + page).min(count - 1));
+    }
+
+    /// Move selection up by `page` items (clamped, no wrapping)
+    pub fn select_page_up(&mut self, page: usize) {
+        if self.filtered_count() == 0 {
+            return;
+        }
+
+        let current = self.selected_index.unwrap_or(0);
+        self.selected_index = Some(current.saturating_sub(page));
+    }
+
+    /// Select the first item
+    pub fn select_first(&mut self) {
+        if self.filtered_count() > 0 {
+            self.selected_index = Some(0);
+        }
+    }
+
+    /// Select the last item
+    pub fn select_last(&mut self) {
+        let count = self.filtered_count();
+        if count > 0 {
+            self.selected_index = Some(count - 1);
+        }
+    }
+
     /// Get all items (for external filtering)
     pub fn items(&self) -> &[T] {
         &self.items
     }
 
+    /// Get all items mutably (caller should re-filter afterwards)
+    pub fn items_mut(&mut self) -> &mut Vec<T> {
+        &mut self.items
+    }
+
     /// Get the raw filtered indices
     pub fn filtered_indices(&self) -> &[usize] {
         &self.filtered_indices
@@ -211,4 +257,41 @@ mod tests {
         delegate.select_up(); // Wraps to 2
         assert_eq!(delegate.selected_index(), Some(2));
     }
+
+    #[test]
+    fn test_page_navigation_clamps() {
+        let items: Vec<usize> = (0..20).collect();
+        let mut delegate = BaseDelegate::new(items);
+
+        delegate.select_page_down(8);
+        assert_eq!(delegate.selected_index(), Some(8));
+
+        delegate.select_page_down(8);
+        delegate.select_page_down(8); // Clamps to the last item
+        assert_eq!(delegate.selected_index(), Some(19));
+
+        delegate.select_page_up(8);
+        assert_eq!(delegate.selected_index(), Some(11));
+
+        delegate.select_page_up(8);
+        delegate.select_page_up(8); // Clamps to the first item
+        assert_eq!(delegate.selected_index(), Some(0));
+    }
+
+    #[test]
+    fn test_select_first_last() {
+        let mut delegate = BaseDelegate::new(vec!["a", "b", "c"]);
+
+        delegate.select_last();
+        assert_eq!(delegate.selected_index(), Some(2));
+        delegate.select_last(); // No wrapping
+        assert_eq!(delegate.selected_index(), Some(2));
+
+        delegate.select_first();
+        assert_eq!(delegate.selected_index(), Some(0));
+
+        let mut empty: BaseDelegate<&str> = BaseDelegate::new(Vec::new());
+        empty.select_last();
+        assert_eq!(empty.selected_index(), None);
+    }
 }
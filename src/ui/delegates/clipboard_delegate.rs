@@ -1,30 +1,169 @@
-use crate::clipboard::ClipboardItem;
+use crate::clipboard::{ClipboardContent, ClipboardItem, ClipboardSelection};
 use crate::ui::delegates::BaseDelegate;
 use crate::ui::theme::theme;
+use crate::ui::utils::{
+    LineRange, find_color, is_line_range_query, parse_line_range, parse_tags, split_tag_query,
+};
 use crate::ui::views::render_clipboard_item;
-use gpui::{App, Context, SharedString, Task, Window, div, prelude::*};
+use gpui::{
+    App, Context, MouseMoveEvent, Pixels, Point, SharedString, Task, Window, div, prelude::*,
+};
 use gpui_component::IndexPath;
 use gpui_component::list::{ListDelegate, ListItem as GpuiListItem, ListState};
+use std::sync::Arc;
+
+/// Type alias for confirm callback (item, the selection to copy it to and
+/// whether to keep the formatting of rich text)
+type ConfirmCallback = Arc<dyn Fn(&ClipboardItem, ClipboardSelection, bool) + Send + Sync>;
+
+/// Content type filter for the clipboard history.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClipboardContentFilter {
+    /// Show every item
+    #[default]
+    All,
+    /// Plain and rich text (including links), but not colors or file URLs
+    Text,
+    /// Copied images
+    Images,
+    /// Copied files and file:// URLs
+    Files,
+    /// Text that parses as a color
+    Colors,
+}
+
+impl ClipboardContentFilter {
+    /// All filters in the order they are shown (and numbered) in the filter bar.
+    pub const ALL: [Self; 5] = [
+        Self::All,
+        Self::Text,
+        Self::Images,
+        Self::Files,
+        Self::Colors,
+    ];
+
+    /// Get a human readable label for the filter.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::All => "All",
+            Self::Text => "Text",
+            Self::Images => "Images",
+            Self::Files => "Files",
+            Self::Colors => "Colors",
+        }
+    }
+
+    /// Check whether an item belongs to this filter's category.
+    pub fn matches(&self, item: &ClipboardItem) -> bool {
+        let text = match &item.content {
+            ClipboardContent::Text(text) => Some(text.as_str()),
+            ClipboardContent::RichText { plain, .. } => Some(plain.as_str()),
+            _ => None,
+        };
+        let is_file_url = text.is_some_and(|t| t.trim().starts_with("file://"));
+        let is_color = text.is_some_and(|t| find_color(t).is_some());
+
+        match self {
+            Self::All => true,
+            Self::Text => text.is_some() && !is_file_url && !is_color,
+            Self::Images => matches!(item.content, ClipboardContent::Image { .. }),
+            Self::Files => matches!(item.content, ClipboardContent::FilePaths(_)) || is_file_url,
+            Self::Colors => is_color,
+        }
+    }
+}
+
+/// Tags being typed for a clipboard item.
+struct TagEdit {
+    /// Id of the item the tags are for
+    item_id: u64,
+    /// Text typed so far
+    input: String,
+    /// Search query to restore when done
+    query: String,
+}
 
 /// Delegate for the clipboard history list.
 ///
 /// This is a simple delegate that composes with BaseDelegate<ClipboardItem>.
+/// Pinned items are ordered first and shown in their own section.
 pub struct ClipboardListDelegate {
     /// Base delegate handling common behavior
     base: BaseDelegate<ClipboardItem>,
+    /// Number of pinned items at the start of the filtered list
+    pinned_count: usize,
+    /// Whether the history was just cleared (changes the empty state message)
+    cleared: bool,
+    /// Only show items captured from this selection (None shows all)
+    source_filter: Option<ClipboardSelection>,
+    /// Only show items of this content type
+    content_filter: ClipboardContentFilter,
+    /// Last pointer position seen over the list (for hover selection)
+    last_pointer_position: Option<Point<Pixels>>,
+    /// Confirm callback (stored here to pass the copy target along)
+    on_confirm: Option<ConfirmCallback>,
+    /// Lines of the previewed text file picked with a `:10-20` query
+    line_range: Option<LineRange>,
+    /// Tags being edited in the search input
+    tag_edit: Option<TagEdit>,
 }
 
 impl ClipboardListDelegate {
     /// Create a new clipboard list delegate
     pub fn new(items: Vec<ClipboardItem>) -> Self {
-        Self {
+        let mut delegate = Self {
             base: BaseDelegate::new(items),
-        }
+            pinned_count: 0,
+            cleared: false,
+            source_filter: None,
+            content_filter: ClipboardContentFilter::All,
+            last_pointer_position: None,
+            on_confirm: None,
+            line_range: None,
+            tag_edit: None,
+        };
+        delegate.filter_items();
+        delegate
+    }
+
+    /// Set the confirm callback (paste clipboard item into the given selection)
+    pub fn set_on_confirm(
+        &mut self,
+        callback: impl Fn(&ClipboardItem, ClipboardSelection, bool) + Send + Sync + 'static,
+    ) {
+        self.on_confirm = Some(Arc::new(callback));
+    }
+
+    /// Get the active source filter
+    pub fn source_filter(&self) -> Option<ClipboardSelection> {
+        self.source_filter
+    }
+
+    /// Cycle the source filter: all → clipboard → primary → all
+    pub fn cycle_source_filter(&mut self) {
+        self.source_filter = match self.source_filter {
+            None => Some(ClipboardSelection::Clipboard),
+            Some(ClipboardSelection::Clipboard) => Some(ClipboardSelection::Primary),
+            Some(ClipboardSelection::Primary) => None,
+        };
+        self.filter_items();
+    }
+
+    /// Get the active content type filter
+    pub fn content_filter(&self) -> ClipboardContentFilter {
+        self.content_filter
+    }
+
+    /// Set the content type filter (combined with the search query)
+    pub fn set_content_filter(&mut self, filter: ClipboardContentFilter) {
+        self.content_filter = filter;
+        self.filter_items();
     }
 
-    /// Set the confirm callback (paste clipboard item)
-    pub fn set_on_confirm(&mut self, callback: impl Fn(&ClipboardItem) + Send + Sync + 'static) {
-        self.base.set_on_confirm(callback);
+    /// Selection that confirmed items are copied to.
+    /// Follows the source filter so primary items can be re-copied to primary.
+    pub fn copy_target(&self) -> ClipboardSelection {
+        self.source_filter.unwrap_or_default()
     }
 
     /// Set the cancel callback
@@ -54,34 +193,230 @@ impl ClipboardListDelegate {
 
     /// Clear the query
     pub fn clear_query(&mut self) {
-        self.base.clear_query();
+        self.line_range = None;
+        self.base.set_query(String::new());
+        self.filter_items();
     }
 
     /// Set the query and filter
+    ///
+    /// A `:10-20` query picks lines of the previewed file instead, keeping
+    /// the list and its selection as they are. While editing tags the input
+    /// holds the tags rather than a query.
     pub fn set_query(&mut self, query: String) {
+        if let Some(edit) = &mut self.tag_edit {
+            edit.input = query;
+            return;
+        }
+        if is_line_range_query(&query) {
+            self.line_range = parse_line_range(&query);
+            return;
+        }
+        self.line_range = None;
         self.base.set_query(query);
         self.filter_items();
     }
 
-    /// Filter items based on the current query
+    /// Get the lines picked with a `:10-20` query
+    pub fn line_range(&self) -> Option<LineRange> {
+        self.line_range
+    }
+
+    /// Whether the search input is editing the tags of an item
+    pub fn is_editing_tags(&self) -> bool {
+        self.tag_edit.is_some()
+    }
+
+    /// Start editing the tags of the selected item.
+    ///
+    /// Returns the current tags as text for the input, or None if nothing is
+    /// selected.
+    pub fn start_tag_edit(&mut self) -> Option<String> {
+        let item = self.selected_item()?;
+        let item_id = item.id;
+        let mut input = item.tags.join(" ");
+        if !input.is_empty() {
+            input.push(' ');
+        }
+        self.tag_edit = Some(TagEdit {
+            item_id,
+            input: input.clone(),
+            query: self.base.query().to_string(),
+        });
+        Some(input)
+    }
+
+    /// Save the typed tags to the edited item.
+    ///
+    /// The item stays selected. Returns the new tags and the query to put
+    /// back into the input, or None if no tags were being edited.
+    pub fn finish_tag_edit(&mut self) -> Option<(Vec<String>, String)> {
+        let edit = self.tag_edit.take()?;
+        let tags = parse_tags(&edit.input);
+
+        let item_idx = self
+            .base
+            .items()
+            .iter()
+            .position(|item| item.id == edit.item_id);
+        if let Some(item_idx) = item_idx {
+            self.base.items_mut()[item_idx].tags = tags.clone();
+            crate::clipboard::data::set_tags(edit.item_id, tags.clone());
+        }
+
+        self.filter_items();
+        if let Some(pos) = item_idx.and_then(|item_idx| {
+            self.base
+                .filtered_indices()
+                .iter()
+                .position(|&idx| idx == item_idx)
+        }) {
+            self.base.set_selected(pos);
+        }
+
+        Some((tags, edit.query))
+    }
+
+    /// Stop editing tags without saving them.
+    ///
+    /// Returns the query to put back into the input, or None if no tags were
+    /// being edited.
+    pub fn cancel_tag_edit(&mut self) -> Option<String> {
+        self.tag_edit.take().map(|edit| edit.query)
+    }
+
+    /// Filter items based on the current query and filters (pinned items first)
+    ///
+    /// `#tag` terms of the query only keep items with a tag starting with
+    /// them.
     fn filter_items(&mut self) {
-        let query = self.base.query();
-        if query.is_empty() {
-            self.base.reset_filter();
-        } else {
-            let items = self.base.items();
-            let query_lower = query.to_lowercase();
-            let filtered_indices: Vec<usize> = items
+        let items = self.base.items();
+        let (tags, query) = split_tag_query(self.base.query(), |term| {
+            items
                 .iter()
-                .enumerate()
-                .filter(|(_, item)| {
-                    // Search in text content (preview and full_content)
-                    item.preview().to_lowercase().contains(&query_lower)
-                        || item.full_content().to_lowercase().contains(&query_lower)
-                })
-                .map(|(idx, _)| idx)
-                .collect();
-            self.base.apply_filtered_indices(filtered_indices);
+                .any(|item| item.tags.iter().any(|tag| tag.starts_with(term)))
+        });
+        let query_lower = query.to_lowercase();
+        let source_filter = self.source_filter;
+        let content_filter = self.content_filter;
+        let (mut filtered_indices, unpinned): (Vec<usize>, Vec<usize>) = items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| source_filter.is_none_or(|source| item.source == source))
+            .filter(|(_, item)| content_filter.matches(item))
+            .filter(|(_, item)| {
+                tags.iter()
+                    .all(|term| item.tags.iter().any(|tag| tag.starts_with(term)))
+            })
+            .filter(|(_, item)| {
+                // Search in text content (preview and full_content)
+                query_lower.is_empty()
+                    || item.preview().to_lowercase().contains(&query_lower)
+                    || item.full_content().to_lowercase().contains(&query_lower)
+            })
+            .map(|(idx, _)| idx)
+            .partition(|&idx| items[idx].pinned);
+
+        self.pinned_count = filtered_indices.len();
+        filtered_indices.extend(unpinned);
+        self.base.apply_filtered_indices(filtered_indices);
+    }
+
+    /// Toggle the pinned flag of the selected item.
+    ///
+    /// The selection follows the item to its new position.
+    /// Returns the new pinned state, or None if nothing is selected.
+    pub fn toggle_pin_selected(&mut self) -> Option<bool> {
+        let selected = self.base.selected_index()?;
+        let item_idx = *self.base.filtered_indices().get(selected)?;
+
+        let item = self.base.items_mut().get_mut(item_idx)?;
+        item.pinned = !item.pinned;
+        let (id, pinned) = (item.id, item.pinned);
+        crate::clipboard::data::set_pinned(id, pinned);
+
+        self.filter_items();
+        if let Some(pos) = self
+            .base
+            .filtered_indices()
+            .iter()
+            .position(|&idx| idx == item_idx)
+        {
+            self.base.set_selected(pos);
+        }
+
+        Some(pinned)
+    }
+
+    /// Remove the selected item from the list and the clipboard history.
+    ///
+    /// The selection moves to the next item, or the previous one if the
+    /// removed item was the last. Returns the removed item.
+    pub fn remove_selected(&mut self) -> Option<ClipboardItem> {
+        let selected = self.base.selected_index()?;
+        let item_idx = *self.base.filtered_indices().get(selected)?;
+
+        let item = self.base.items_mut().remove(item_idx);
+        crate::clipboard::data::remove_item(item.id);
+
+        // Re-run the active filter so indices stay consistent
+        self.filter_items();
+        let count = self.filtered_count();
+        if count > 0 {
+            self.base.set_selected(selected.min(count - 1));
+        }
+
+        Some(item)
+    }
+
+    /// Drop the items evicted from the clipboard history since the list was
+    /// loaded, keeping the selected item selected if it's still there.
+    pub fn drop_evicted(&mut self) {
+        let ids = crate::clipboard::data::item_ids();
+        let selected_id = self.selected_item().map(|item| item.id);
+
+        self.base.items_mut().retain(|item| ids.contains(&item.id));
+        self.filter_items();
+
+        let items = self.base.items();
+        let selected = selected_id
+            .and_then(|id| {
+                self.base
+                    .filtered_indices()
+                    .iter()
+                    .position(|&idx| items[idx].id == id)
+            })
+            .unwrap_or(0);
+        if self.filtered_count() > 0 {
+            self.base.set_selected(selected);
+        }
+    }
+
+    /// Remove all items from the list and wipe the clipboard history.
+    pub fn clear_all(&mut self) {
+        self.base.items_mut().clear();
+        crate::clipboard::data::clear_history();
+        self.filter_items();
+        self.cleared = true;
+    }
+
+    /// Convert a filtered index to its section+row IndexPath.
+    pub fn index_path(&self, index: usize) -> IndexPath {
+        if self.pinned_count == 0 {
+            IndexPath::new(index)
+        } else if index < self.pinned_count {
+            IndexPath::new(index).section(0)
+        } else {
+            IndexPath::new(index - self.pinned_count).section(1)
+        }
+    }
+
+    /// Convert a section+row IndexPath to a filtered index.
+    fn filtered_index(&self, ix: IndexPath) -> usize {
+        if self.pinned_count > 0 && ix.section == 1 {
+            self.pinned_count + ix.row
+        } else {
+            ix.row
         }
     }
 
@@ -97,7 +432,20 @@ impl ClipboardListDelegate {
 
     /// Execute confirm callback
     pub fn do_confirm(&self) {
-        self.base.do_confirm();
+        self.confirm_selected(false);
+    }
+
+    /// Execute confirm callback, copying rich text with its formatting
+    pub fn do_confirm_formatted(&self) {
+        self.confirm_selected(true);
+    }
+
+    fn confirm_selected(&self, formatted: bool) {
+        if let Some(item) = self.selected_item()
+            && let Some(ref callback) = self.on_confirm
+        {
+            callback(item, self.copy_target(), formatted);
+        }
     }
 
     /// Execute cancel callback
@@ -115,6 +463,41 @@ impl ClipboardListDelegate {
         self.base.select_up();
     }
 
+    /// Select the row under the pointer.
+    ///
+    /// Only reacts to actual pointer movement, so rows scrolling under a
+    /// resting pointer during keyboard navigation don't steal the selection.
+    /// Returns whether the selection changed.
+    pub fn hover_select(&mut self, index: usize, position: Point<Pixels>) -> bool {
+        if self.last_pointer_position.replace(position) == Some(position)
+            || self.selected_index() == Some(index)
+        {
+            return false;
+        }
+        self.set_selected(index);
+        true
+    }
+
+    /// Move selection down by a page (clamped)
+    pub fn select_page_down(&mut self, page: usize) {
+        self.base.select_page_down(page);
+    }
+
+    /// Move selection up by a page (clamped)
+    pub fn select_page_up(&mut self, page: usize) {
+        self.base.select_page_up(page);
+    }
+
+    /// Select the first item
+    pub fn select_first(&mut self) {
+        self.base.select_first();
+    }
+
+    /// Select the last item
+    pub fn select_last(&mut self) {
+        self.base.select_last();
+    }
+
     /// Get all items
     pub fn items(&self) -> &[ClipboardItem] {
         self.base.items()
@@ -126,11 +509,51 @@ impl ListDelegate for ClipboardListDelegate {
     type Item = GpuiListItem;
 
     fn sections_count(&self, _cx: &App) -> usize {
-        1
+        // Pinned section + history section, or a single section without pins
+        if self.pinned_count > 0 { 2 } else { 1 }
+    }
+
+    fn items_count(&self, section: usize, _cx: &App) -> usize {
+        if self.pinned_count == 0 {
+            self.filtered_count()
+        } else if section == 0 {
+            self.pinned_count
+        } else {
+            self.filtered_count() - self.pinned_count
+        }
     }
 
-    fn items_count(&self, _section: usize, _cx: &App) -> usize {
-        self.filtered_count()
+    fn render_section_header(
+        &mut self,
+        section: usize,
+        _window: &mut Window,
+        _cx: &mut Context<'_, ListState<Self>>,
+    ) -> Option<impl IntoElement> {
+        // Headers are only needed to separate pinned items from history
+        if self.pinned_count == 0 {
+            return None;
+        }
+
+        let title = if section == 0 {
+            "Pinned"
+        } else if self.filtered_count() > self.pinned_count {
+            "History"
+        } else {
+            return None;
+        };
+
+        let theme = theme();
+        Some(
+            div()
+                .w_full()
+                .px(theme.item_padding_x)
+                .pt(theme.section_header.margin_top)
+                .pb(theme.section_header.margin_bottom)
+                .text_xs()
+                .font_weight(gpui::FontWeight::EXTRA_BOLD)
+                .text_color(theme.section_header.color)
+                .child(SharedString::from(title)),
+        )
     }
 
     fn render_item(
@@ -139,16 +562,24 @@ impl ListDelegate for ClipboardListDelegate {
         _window: &mut Window,
         cx: &mut Context<'_, ListState<Self>>,
     ) -> Option<Self::Item> {
-        let item = self.base.get_filtered_item(ix.row)?;
-        let is_selected = self.base.selected_index() == Some(ix.row);
-        let row = ix.row;
+        let row = self.filtered_index(ix);
+        let item = self.base.get_filtered_item(row)?;
+        let is_selected = self.base.selected_index() == Some(row);
 
-        let element = render_clipboard_item(item, is_selected, row).on_click(cx.listener(
-            move |state, _, _window, _cx| {
+        let element = render_clipboard_item(item, is_selected, row)
+            .on_mouse_move(
+                cx.listener(move |state, event: &MouseMoveEvent, window, cx| {
+                    if state.delegate_mut().hover_select(row, event.position) {
+                        state.set_selected_index(Some(ix), window, cx);
+                        cx.notify();
+                    }
+                }),
+            )
+            .on_click(cx.listener(move |state, _, _window, _cx| {
+                // Usually already selected by hovering
                 state.delegate_mut().set_selected(row);
                 state.delegate().do_confirm();
-            },
-        ));
+            }));
 
         Some(GpuiListItem::new(("clipboard-item", row)).child(element))
     }
@@ -159,7 +590,8 @@ impl ListDelegate for ClipboardListDelegate {
         _window: &mut Window,
         _cx: &mut Context<ListState<Self>>,
     ) {
-        self.base.set_selected(ix.map(|i| i.row).unwrap_or(0));
+        let index = ix.map(|i| self.filtered_index(i)).unwrap_or(0);
+        self.base.set_selected(index);
     }
 
     fn perform_search(
@@ -174,11 +606,11 @@ impl ListDelegate for ClipboardListDelegate {
 
     fn confirm(
         &mut self,
-        _secondary: bool,
+        secondary: bool,
         _window: &mut Window,
         _cx: &mut Context<ListState<Self>>,
     ) {
-        self.do_confirm();
+        self.confirm_selected(secondary);
     }
 
     fn cancel(&mut self, _window: &mut Window, _cx: &mut Context<ListState<Self>>) {
@@ -191,6 +623,13 @@ impl ListDelegate for ClipboardListDelegate {
         _cx: &mut Context<'_, ListState<Self>>,
     ) -> impl IntoElement {
         let theme = theme();
+        let message = if self.cleared {
+            "History cleared"
+        } else if self.content_filter != ClipboardContentFilter::All {
+            "No matching items"
+        } else {
+            "No clipboard history"
+        };
         div()
             .w_full()
             .h(theme.empty_state_height)
@@ -201,7 +640,7 @@ impl ListDelegate for ClipboardListDelegate {
                 div()
                     .text_sm()
                     .text_color(theme.empty_state_color)
-                    .child(SharedString::from("No clipboard history")),
+                    .child(SharedString::from(message)),
             )
     }
 }
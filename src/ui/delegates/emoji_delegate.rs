@@ -1,28 +1,39 @@
-use crate::emoji::EmojiItem;
+use crate::emoji::{EmojiItem, SkinTone, apply_skin_tone, filter_emojis, record_recent_emoji};
 use crate::ui::delegates::BaseDelegate;
 use crate::ui::theme::theme;
 use crate::ui::views::render_emoji_row;
 use gpui::{App, Context, SharedString, Task, Window, div, prelude::*};
 use gpui_component::IndexPath;
 use gpui_component::list::{ListDelegate, ListItem as GpuiListItem, ListState};
+use std::ops::Range;
 
 /// Delegate for the emoji picker grid.
 ///
 /// This is a simplified delegate that composes with BaseDelegate<EmojiItem>
-/// and adds grid-specific navigation logic.
+/// and adds grid-specific navigation logic. Without a query, the recently
+/// used emojis are shown in their own section above the full grid.
 pub struct EmojiGridDelegate {
     /// Base delegate handling common behavior
     base: BaseDelegate<EmojiItem>,
     /// Number of columns in the grid
     columns: usize,
+    /// Number of recently used emojis at the front of the items
+    recent_count: usize,
+    /// Skin tone applied to emojis that support it
+    skin_tone: SkinTone,
 }
 
 impl EmojiGridDelegate {
-    /// Create a new emoji grid delegate
-    pub fn new(items: Vec<EmojiItem>, columns: usize) -> Self {
+    /// Create a new emoji grid delegate with the recently used emojis
+    pub fn new(items: Vec<EmojiItem>, recent: Vec<EmojiItem>, columns: usize) -> Self {
+        let recent_count = recent.len();
+        let mut all = recent;
+        all.extend(items);
         Self {
-            base: BaseDelegate::new(items),
+            base: BaseDelegate::new(all),
             columns,
+            recent_count,
+            skin_tone: crate::config::config().emoji_skin_tone,
         }
     }
 
@@ -41,9 +52,13 @@ impl EmojiGridDelegate {
         self.base.selected_index()
     }
 
-    /// Get the selected row (for scrolling in grid layout)
-    pub fn selected_row(&self) -> Option<usize> {
-        self.selected_index().map(|idx| idx / self.columns)
+    /// Get the section and row of the selection (for scrolling in grid layout)
+    pub fn selected_index_path(&self) -> Option<IndexPath> {
+        let selected = self.selected_index()?;
+        let sections = self.sections();
+        let section = sections.iter().position(|s| s.contains(&selected))?;
+        let row = (selected - sections[section].start) / self.columns;
+        Some(IndexPath::new(row).section(section))
     }
 
     /// Get the total count of filtered items
@@ -73,15 +88,11 @@ impl EmojiGridDelegate {
         if query.is_empty() {
             self.base.reset_filter();
         } else {
-            let items = self.base.items();
-            let query_lower = query.to_lowercase();
-            let filtered_indices: Vec<usize> = items
-                .iter()
-                .enumerate()
-                .filter(|(_, item)| {
-                    item.emoji.contains(query) || item.name.to_lowercase().contains(&query_lower)
-                })
-                .map(|(idx, _)| idx)
+            // Search the full list only, so recent emojis don't show up twice
+            let offset = self.recent_count;
+            let filtered_indices = filter_emojis(&self.base.items()[offset..], query)
+                .into_iter()
+                .map(|idx| idx + offset)
                 .collect();
             self.base.apply_filtered_indices(filtered_indices);
         }
@@ -92,9 +103,26 @@ impl EmojiGridDelegate {
         self.base.get_filtered_item(index)
     }
 
-    /// Execute confirm callback
+    /// Execute confirm callback with the emoji in the current skin tone,
+    /// moving the emoji to the front of the recents
     pub fn do_confirm(&self) {
-        self.base.do_confirm();
+        if let Some(item) = self.base.selected_item() {
+            record_recent_emoji(&item.emoji);
+            self.base.confirm_item(&self.with_skin_tone(item));
+        }
+    }
+
+    /// Switch to the next skin tone
+    pub fn cycle_skin_tone(&mut self) {
+        self.skin_tone = self.skin_tone.next();
+    }
+
+    /// Get an emoji in the current skin tone (unchanged without tone support)
+    fn with_skin_tone(&self, item: &EmojiItem) -> EmojiItem {
+        EmojiItem {
+            emoji: apply_skin_tone(&item.emoji, self.skin_tone),
+            ..item.clone()
+        }
     }
 
     /// Execute cancel callback
@@ -102,80 +130,119 @@ impl EmojiGridDelegate {
         self.base.do_cancel();
     }
 
-    /// Move selection right (for grid navigation)
+    /// Move selection right by one (for grid navigation)
     pub fn select_right(&mut self) {
-        // In a grid, "right" is the same as "next"
+        // In a grid, "right" is the same as "next": past the end of a row it
+        // wraps to the start of the next one
         self.base.select_down();
     }
 
-    /// Move selection left (for grid navigation)
+    /// Move selection left by one (for grid navigation)
     pub fn select_left(&mut self) {
-        // In a grid, "left" is the same as "previous"
+        // In a grid, "left" is the same as "previous": past the start of a
+        // row it wraps to the end of the previous one
         self.base.select_up();
     }
 
     /// Move selection down (by one row)
     pub fn select_down(&mut self) {
-        let count = self.filtered_count();
-        if count == 0 {
-            return;
-        }
-
-        if let Some(current) = self.selected_index() {
-            let next = current + self.columns;
-            if next < count {
-                self.base.set_selected(next);
-            } else {
-                // Wrap to first item in same column
-                self.base.set_selected(current % self.columns);
-            }
-        }
+        self.select_adjacent_row(true);
     }
 
     /// Move selection up (by one row)
     pub fn select_up(&mut self) {
-        let count = self.filtered_count();
-        if count == 0 {
+        self.select_adjacent_row(false);
+    }
+
+    /// Move selection to the next or previous grid row, wrapping around.
+    /// Keeps the column, or picks the last emoji of a shorter row.
+    fn select_adjacent_row(&mut self, down: bool) {
+        let Some(current) = self.selected_index() else {
             return;
-        }
+        };
+        let rows = self.rows();
+        let Some(row) = rows.iter().position(|r| r.contains(&current)) else {
+            return;
+        };
+
+        let column = current - rows[row].start;
+        let target = if down {
+            &rows[(row + 1) % rows.len()]
+        } else {
+            &rows[(row + rows.len() - 1) % rows.len()]
+        };
+        self.base
+            .set_selected((target.start + column).min(target.end - 1));
+    }
+
+    /// Move selection down by `rows` grid rows (clamped to the last emoji)
+    pub fn select_page_down(&mut self, rows: usize) {
+        self.base.select_page_down(rows * self.columns);
+    }
+
+    /// Move selection up by `rows` grid rows (clamped to the first emoji)
+    pub fn select_page_up(&mut self, rows: usize) {
+        self.base.select_page_up(rows * self.columns);
+    }
+
+    /// Select the first emoji
+    pub fn select_first(&mut self) {
+        self.base.select_first();
+    }
 
-        if let Some(current) = self.selected_index() {
-            if current >= self.columns {
-                self.base.set_selected(current - self.columns);
-            } else {
-                // Wrap to last row in same column
-                let col = current % self.columns;
-                let last_row = (count - 1) / self.columns;
-                let target = last_row * self.columns + col;
-                if target < count {
-                    self.base.set_selected(target);
-                } else {
-                    // If last row doesn't have this column, go to previous row
-                    let prev_row = last_row - 1;
-                    self.base.set_selected(prev_row * self.columns + col);
-                }
-            }
+    /// Select the last emoji
+    pub fn select_last(&mut self) {
+        self.base.select_last();
+    }
+
+    /// Get the number of recent emojis shown (only without a query).
+    fn shown_recent_count(&self) -> usize {
+        if self.query().is_empty() {
+            self.recent_count.min(self.filtered_count())
+        } else {
+            0
         }
     }
 
-    /// Get the number of rows needed for the grid.
-    fn row_count(&self) -> usize {
+    /// Get the filtered index ranges of the sections: the recent emojis (if
+    /// shown) and the rest.
+    fn sections(&self) -> Vec<Range<usize>> {
+        let recent = self.shown_recent_count();
         let count = self.filtered_count();
-        if count == 0 {
-            0
+        if recent == 0 {
+            vec![0..count]
         } else {
-            count.div_ceil(self.columns)
+            vec![0..recent, recent..count]
         }
     }
 
-    /// Get emojis for a specific row.
-    fn emojis_for_row(&self, row: usize) -> Vec<EmojiItem> {
-        let start = row * self.columns;
-        let end = (start + self.columns).min(self.filtered_count());
-        (start..end)
-            .filter_map(|i| self.base.get_filtered_item(i).cloned())
+    /// Get the filtered index ranges of all grid rows, across sections.
+    fn rows(&self) -> Vec<Range<usize>> {
+        let columns = self.columns;
+        self.sections()
+            .into_iter()
+            .flat_map(|section| {
+                let end = section.end;
+                section
+                    .step_by(columns)
+                    .map(move |start| start..(start + columns).min(end))
+            })
             .collect()
     }
+
+    /// Get the first filtered index and the emojis of a row.
+    fn emojis_for_row(&self, ix: IndexPath) -> (usize, Vec<EmojiItem>) {
+        let Some(section) = self.sections().get(ix.section).cloned() else {
+            return (0, Vec::new());
+        };
+        let start = section.start + ix.row * self.columns;
+        let end = (start + self.columns).min(section.end);
+        let emojis = (start..end)
+            .filter_map(|i| self.base.get_filtered_item(i))
+            .map(|item| self.with_skin_tone(item))
+            .collect();
+        (start, emojis)
+    }
 }
 
 /// Implement ListDelegate trait for GPUI integration.
@@ -183,11 +250,44 @@ impl ListDelegate for EmojiGridDelegate {
     type Item = GpuiListItem;
 
     fn sections_count(&self, _cx: &App) -> usize {
-        1
+        self.sections().len()
     }
 
-    fn items_count(&self, _section: usize, _cx: &App) -> usize {
-        self.row_count()
+    fn items_count(&self, section: usize, _cx: &App) -> usize {
+        self.sections()
+            .get(section)
+            .map_or(0, |s| s.len().div_ceil(self.columns))
+    }
+
+    fn render_section_header(
+        &mut self,
+        section: usize,
+        _window: &mut Window,
+        _cx: &mut Context<'_, ListState<Self>>,
+    ) -> Option<impl IntoElement> {
+        // Headers are only needed to separate recent emojis from the rest
+        if self.shown_recent_count() == 0 {
+            return None;
+        }
+
+        let title = if section == 0 {
+            "Recently Used"
+        } else {
+            "All Emojis"
+        };
+
+        let theme = theme();
+        Some(
+            div()
+                .w_full()
+                .px(theme.item_padding_x)
+                .pt(theme.section_header.margin_top)
+                .pb(theme.section_header.margin_bottom)
+                .text_xs()
+                .font_weight(gpui::FontWeight::EXTRA_BOLD)
+                .text_color(theme.section_header.color)
+                .child(SharedString::from(title)),
+        )
     }
 
     fn render_item(
@@ -196,9 +296,7 @@ impl ListDelegate for EmojiGridDelegate {
         _window: &mut Window,
         _cx: &mut Context<'_, ListState<Self>>,
     ) -> Option<Self::Item> {
-        let row = ix.row;
-        let emojis = self.emojis_for_row(row);
-        let start_index = row * self.columns;
+        let (start_index, emojis) = self.emojis_for_row(ix);
 
         let row_element = render_emoji_row(
             &emojis,
@@ -208,7 +306,7 @@ impl ListDelegate for EmojiGridDelegate {
         );
 
         Some(
-            GpuiListItem::new(("emoji-row", row))
+            GpuiListItem::new(("emoji-row", start_index))
                 .py_0()
                 .px_0()
                 .child(row_element),
@@ -222,8 +320,10 @@ impl ListDelegate for EmojiGridDelegate {
         _cx: &mut Context<ListState<Self>>,
     ) {
         // Convert row to first item in that row
-        self.base
-            .set_selected(ix.map(|i| i.row * self.columns).unwrap_or(0));
+        let index = ix
+            .and_then(|ix| Some(self.sections().get(ix.section)?.start + ix.row * self.columns))
+            .unwrap_or(0);
+        self.base.set_selected(index);
     }
 
     fn perform_search(
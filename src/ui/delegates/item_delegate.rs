@@ -1,16 +1,42 @@
-use crate::calculator::evaluate_expression;
-use crate::config::{ConfigModule, config};
-use crate::items::{ActionItem, AiItem, CalculatorItem, ListItem, SearchItem, SubmenuItem};
-use crate::search::{SearchDetection, detect_search, get_providers};
+use crate::calculator::{evaluate_expression, has_answer_reference};
+use crate::config::{ConfigModule, ResultOrder, config};
+use crate::items::{
+    ActionItem, ActionKind, AiItem, CalculatorItem, ListItem, SearchItem, SubmenuItem,
+};
+use crate::search::{ResultBatch, SearchDetection, detect_search, detect_url, ordered_providers};
 use crate::ui::delegates::BaseDelegate;
 use crate::ui::theme::theme;
-use crate::ui::views::render_item;
+use crate::ui::utils::fold_diacritics;
+use crate::ui::views::{render_grid_item, render_item};
 use fuzzy_matcher::FuzzyMatcher;
 use fuzzy_matcher::skim::SkimMatcherV2;
-use gpui::{App, Context, SharedString, Task, Window, div, prelude::*};
+use gpui::{
+    App, ClickEvent, Context, Div, MouseMoveEvent, Pixels, Point, SharedString, Stateful, Task,
+    Window, div, prelude::*,
+};
 use gpui_component::IndexPath;
 use gpui_component::list::{ListDelegate, ListItem as GpuiListItem, ListState};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Fuzzy score bonus per point of launch frecency.
+const FRECENCY_WEIGHT: f64 = 10.0;
+
+/// Upper bound of the frecency bonus, so a poor match can't beat a good one.
+const MAX_FRECENCY_BONUS: i64 = 60;
+
+/// Number of recently launched applications in the home view.
+const HOME_RECENT_APPS: usize = 8;
+
+/// Number of clipboard entries in the home view.
+const HOME_CLIPBOARD_ITEMS: usize = 3;
+
+/// Item count from which filtering is split across threads.
+const PARALLEL_FILTER_MIN_ITEMS: usize = 1000;
+
+/// Id prefix of the category entries listed for a lone `@` query.
+pub const CATEGORY_ID_PREFIX: &str = "category-";
 
 /// Section information for tracking item counts by type
 #[derive(Clone, Debug, Default)]
@@ -19,6 +45,26 @@ struct SectionInfo {
     window_count: usize,
     command_count: usize,
     app_count: usize,
+    /// Matches of every type, when ordered by relevance only
+    result_count: usize,
+    /// Pinned applications in the home view
+    pinned_count: usize,
+    /// Recently launched applications in the home view
+    recent_count: usize,
+    /// Clipboard entries in the home view
+    clipboard_count: usize,
+}
+
+/// How the regular items are grouped into sections.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Grouping {
+    /// Windows, commands and applications
+    #[default]
+    Types,
+    /// A single section ordered by relevance
+    Mixed,
+    /// Pinned, recent and clipboard items of the home view
+    Home,
 }
 
 /// Type alias for confirm callback
@@ -30,18 +76,45 @@ type ConfirmCallback = Arc<dyn Fn(&ListItem) + Send + Sync>;
 /// - Dynamic calculator results
 /// - AI query detection
 /// - Web search suggestions
+/// - Results streamed in from slow providers
 /// - Section management
 pub struct ItemListDelegate {
     /// Base delegate handling common behavior
     base: BaseDelegate<ListItem>,
     /// Section counts for organizing items
     section_info: SectionInfo,
+    /// Matched name character indices, parallel to the base filtered indices
+    match_positions: Vec<Vec<usize>>,
     /// Calculator result (shown at top when query is math expression)
     calculator_item: Option<CalculatorItem>,
+    /// Terminal command (shown at top when the query starts with `>`)
+    command_item: Option<ActionItem>,
     /// AI item (shown when query starts with !ai)
     ai_item: Option<AiItem>,
     /// Search items (shown when query triggers search providers)
     search_items: Vec<SearchItem>,
+    /// Items pushed incrementally by streaming providers for the current query
+    streamed_items: Vec<ListItem>,
+    /// Query generation, bumped on every query change to discard stale batches
+    generation: Arc<AtomicU64>,
+    /// Whether only the windows of the focused workspace are listed
+    windows_scoped: bool,
+    /// Lowercase names of the collapsed sections
+    collapsed_sections: HashSet<String>,
+    /// Maximum number of results shown per section while searching (0 for no limit)
+    section_result_limit: usize,
+    /// Sections showing all their results for the current query
+    expanded_sections: HashSet<SectionType>,
+    /// Number of columns of the grid layout (1 for a plain list)
+    grid_columns: usize,
+    /// Whether search results are grouped by type or ordered by score only
+    result_order: ResultOrder,
+    /// Whether the home view is shown while the query is empty
+    home_view: bool,
+    /// Grouping of the currently filtered items
+    grouping: Grouping,
+    /// Last pointer position seen over the list (for hover selection)
+    last_pointer_position: Option<Point<Pixels>>,
     /// Confirm callback (stored here to handle dynamic items)
     on_confirm: Option<ConfirmCallback>,
 }
@@ -73,27 +146,68 @@ impl ItemListDelegate {
                     .with_icon("palette"),
             ));
         }
+        items.push(ListItem::Submenu(
+            SubmenuItem::list("submenu-power", "Power")
+                .with_description("Shut down, reboot, suspend or lock")
+                .with_icon("power"),
+        ));
 
         // Add built-in action items
-        for action in ActionItem::builtins() {
-            items.push(ListItem::Action(action));
+        if !disabled_modules.contains(&ConfigModule::Clipboard) {
+            items.push(ListItem::Action(ActionItem::builtin(
+                ActionKind::ClearClipboard,
+            )));
         }
+        if !disabled_modules.contains(&ConfigModule::Themes) {
+            items.push(ListItem::Action(ActionItem::builtin(
+                ActionKind::ToggleAppearance,
+            )));
+        }
+
+        // Add user-defined snippets (listed with the commands)
+        items.extend(
+            crate::snippets::snippet_items()
+                .into_iter()
+                .map(ListItem::Snippet),
+        );
 
         // Sort items by priority to ensure correct section order
         // (Windows=2, Commands=3, Applications=4)
         items.sort_by_key(|item| item.sort_priority());
 
-        let section_info =
-            Self::compute_section_info(&items, &(0..items.len()).collect::<Vec<_>>());
+        let section_info = Self::compute_section_info(
+            &items,
+            &(0..items.len()).collect::<Vec<_>>(),
+            Grouping::Types,
+        );
 
-        Self {
+        let mut delegate = Self {
             base: BaseDelegate::new(items),
             section_info,
+            match_positions: Vec::new(),
             calculator_item: None,
+            command_item: None,
             ai_item: None,
             search_items: Vec::new(),
+            streamed_items: Vec::new(),
+            generation: Arc::new(AtomicU64::new(0)),
+            windows_scoped: config().current_workspace_windows,
+            collapsed_sections: config()
+                .collapsed_sections
+                .iter()
+                .map(|name| name.to_lowercase())
+                .collect(),
+            section_result_limit: config().section_result_limit,
+            expanded_sections: HashSet::new(),
+            grid_columns: config().main_grid_columns.max(1),
+            result_order: config().result_order,
+            home_view: config().home_view,
+            grouping: Grouping::Types,
+            last_pointer_position: None,
             on_confirm: None,
-        }
+        };
+        delegate.filter_items();
+        delegate
     }
 
     /// Set the confirm callback
@@ -123,10 +237,10 @@ impl ItemListDelegate {
 
     /// Get the total count of filtered items (including dynamic items)
     pub fn filtered_count(&self) -> usize {
-        let calc_count = if self.calculator_item.is_some() { 1 } else { 0 };
+        let top_count = self.top_offset();
         let ai_count = if self.ai_item.is_some() { 1 } else { 0 };
         let search_count = self.search_items.len();
-        self.base.filtered_count() + calc_count + ai_count + search_count
+        self.base.filtered_count() + top_count + self.streamed_items.len() + ai_count + search_count
     }
 
     /// Get the current query
@@ -137,26 +251,101 @@ impl ItemListDelegate {
     /// Clear the query and reset all dynamic items
     pub fn clear_query(&mut self) {
         self.calculator_item = None;
+        self.command_item = None;
         self.ai_item = None;
         self.search_items.clear();
+        self.streamed_items.clear();
+        self.expanded_sections.clear();
+        self.generation.fetch_add(1, Ordering::Relaxed);
         self.base.clear_query();
-        self.update_section_info();
+        // Re-sort so the latest launch frecency is reflected
+        self.filter_items();
     }
 
     /// Set the query and trigger filtering
     pub fn set_query(&mut self, query: String) {
+        self.streamed_items.clear();
+        self.expanded_sections.clear();
+        self.generation.fetch_add(1, Ordering::Relaxed);
         self.base.set_query(query.clone());
         self.process_query(&query);
     }
 
+    /// Switch between the windows of the focused workspace and all windows.
+    pub fn toggle_window_scope(&mut self) {
+        self.windows_scoped = !self.windows_scoped;
+        self.filter_items();
+        self.set_selected(0);
+    }
+
+    /// Copy the next representation of the calculator result.
+    ///
+    /// Returns false if there's no result with other representations.
+    pub fn cycle_calculator_format(&mut self) -> bool {
+        match &mut self.calculator_item {
+            Some(calc) if !calc.alternatives.is_empty() => {
+                calc.cycle_format();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the generation of the current query
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Relaxed)
+    }
+
+    /// Get a shared handle to the generation counter (for streaming providers)
+    pub fn generation_handle(&self) -> Arc<AtomicU64> {
+        self.generation.clone()
+    }
+
+    /// Merge a batch of streamed results into the list.
+    ///
+    /// Batches produced for an older query are discarded. Returns `true` if the
+    /// batch was applied and the list needs to be redrawn.
+    pub fn push_batch(&mut self, batch: ResultBatch) -> bool {
+        if batch.generation != self.generation() || batch.items.is_empty() {
+            return false;
+        }
+
+        // Streamed items are inserted before the Search and AI section, so keep
+        // the selection on the same item if it was below the insertion point
+        let streamed_end = self.streamed_start_index() + self.streamed_items.len();
+        let added = batch.items.len();
+        self.streamed_items.extend(batch.items);
+
+        if let Some(selected) = self.base.selected_index() {
+            if selected >= streamed_end && selected + added < self.filtered_count() {
+                self.base.set_selected_unchecked(selected + added);
+            }
+        } else {
+            self.base.set_selected_unchecked(0);
+        }
+
+        true
+    }
+
+    /// Global index of the first streamed item
+    fn streamed_start_index(&self) -> usize {
+        let top_offset = self.top_offset();
+        top_offset + self.base.filtered_count()
+    }
+
     /// Process the query to detect special items (calculator, AI, search)
     fn process_query(&mut self, query: &str) {
         // Get the config disabled modules
         let disabled_modules = config().disabled_modules.unwrap_or_default();
 
+        // A leading `>` runs the rest of the query in a terminal
+        self.command_item = parse_command_query(query)
+            .map(|command| ActionItem::builtin(ActionKind::TerminalCommand(command)));
+
         // Check for calculator expression
-        if !disabled_modules.contains(&ConfigModule::Calculator)
-            && query.chars().any(|c| c.is_numeric())
+        if self.command_item.is_none()
+            && !disabled_modules.contains(&ConfigModule::Calculator)
+            && (query.chars().any(|c| c.is_numeric()) || has_answer_reference(query))
             && let Ok(result) = evaluate_expression(query)
         {
             self.calculator_item = Some(result);
@@ -181,9 +370,13 @@ impl ItemListDelegate {
         // Logic:
         // 1. If !ai trigger → only show AI item
         // 2. Else if search trigger (!g, !ddg, etc.) → only show that search provider
-        // 3. Else if query not empty → always show AI item + all search providers at bottom
+        // 3. Else if query not empty → always show AI item + the first search providers at bottom
 
-        if !disabled_modules.contains(&ConfigModule::Ai) && has_ai_trigger {
+        if self.command_item.is_some() {
+            // Commands are neither searched nor asked to the AI
+        } else if self.filters_by_category() {
+            // Neither are category filters
+        } else if !disabled_modules.contains(&ConfigModule::Ai) && has_ai_trigger {
             // Only show AI item when !ai trigger is used
             let ai_query = trimmed.strip_prefix("!ai").unwrap().trim();
             if !ai_query.is_empty() {
@@ -195,7 +388,7 @@ impl ItemListDelegate {
                 self.search_items.push(SearchItem::new(provider, query));
             }
         } else if !trimmed.is_empty() {
-            // Always show AI item and all search providers when query is not empty
+            // Always show AI item and search providers when query is not empty
             // These appear at the bottom in "Search and AI" section
             if !disabled_modules.contains(&ConfigModule::Ai) {
                 self.ai_item = Some(AiItem::new(trimmed.to_string()));
@@ -203,9 +396,21 @@ impl ItemListDelegate {
             if !disabled_modules.contains(&ConfigModule::Search)
                 && let SearchDetection::Fallback { query } = search_detection
             {
-                for provider in get_providers() {
-                    self.search_items
-                        .push(SearchItem::new(provider, query.clone()));
+                // A URL in the query is offered first, opened directly
+                if let Some(url) = detect_url(&query) {
+                    self.search_items.push(SearchItem::url(url));
+                }
+
+                // Web searches are only suggested when no application is an obvious match.
+                // The default provider comes first so a plain Enter uses it.
+                if !self.has_strong_app_match(&query) {
+                    for provider in ordered_providers()
+                        .into_iter()
+                        .take(config().max_search_suggestions)
+                    {
+                        self.search_items
+                            .push(SearchItem::new(provider, query.clone()));
+                    }
                 }
             }
         }
@@ -217,21 +422,95 @@ impl ItemListDelegate {
         if self.base.selected_index().is_none() && self.filtered_count() > 0 {
             self.base.set_selected_unchecked(0);
         }
+        self.skip_hidden_selection();
     }
 
     /// Filter items based on the current query
     fn filter_items(&mut self) {
+        // Clipboard entries are only listed in the home view, with the
+        // latest history each time it's shown
+        let home = self.home_view && self.base.query().is_empty();
+        self.base
+            .items_mut()
+            .retain(|item| !Self::is_clipboard_entry(item) && !Self::is_category_entry(item));
+        if home
+            && !config()
+                .disabled_modules
+                .unwrap_or_default()
+                .contains(&ConfigModule::Clipboard)
+        {
+            self.base.items_mut().extend(
+                crate::clipboard::data::recent_items(HOME_CLIPBOARD_ITEMS)
+                    .iter()
+                    .map(|item| ListItem::Action(ActionItem::clipboard_item(item))),
+            );
+        }
+
+        // A lone `@` lists the categories to pick from
+        let category_query = parse_category_query(self.base.query());
+        let list_categories = category_query
+            .as_ref()
+            .is_some_and(|(term, search)| term.is_empty() && search.is_empty());
+        if list_categories {
+            let categories = Self::category_items(self.base.items());
+            self.base.items_mut().extend(categories);
+        }
+
         let query = self.base.query();
         let items = self.base.items();
 
-        if query.is_empty() {
-            // Sort by priority even when showing all items
-            // This ensures sections (Windows, Commands, Applications) appear in correct order
+        let frecency = crate::desktop::frecency::scores();
+
+        if home && let Some(indices) = Self::home_indices(items, &frecency) {
+            self.grouping = Grouping::Home;
+            self.match_positions.clear();
+            self.base.apply_filtered_indices(indices);
+        } else if list_categories {
+            self.grouping = Grouping::Types;
+            self.match_positions.clear();
+            self.base.apply_filtered_indices(
+                (0..items.len())
+                    .filter(|&idx| Self::is_category_entry(&items[idx]))
+                    .collect(),
+            );
+        } else if let Some((term, search)) = category_query {
+            self.grouping = Grouping::Types;
+            let (filtered_indices, match_positions) = if search.is_empty() {
+                let mut sorted_indices: Vec<usize> = (0..items.len()).collect();
+                Self::sort_unfiltered(items, &mut sorted_indices, &frecency);
+                sorted_indices
+                    .into_iter()
+                    .map(|idx| (idx, Vec::new()))
+                    .collect::<Vec<_>>()
+            } else {
+                Self::filter_items_sync(items, &search, &frecency, ResultOrder::Types)
+            }
+            .into_iter()
+            .filter(|(idx, _)| Self::in_category(&items[*idx], &term))
+            .unzip();
+            self.match_positions = match_positions;
+            self.base.apply_filtered_indices(filtered_indices);
+        } else if query.is_empty() {
+            self.grouping = Grouping::Types;
             let mut sorted_indices: Vec<usize> = (0..items.len()).collect();
-            sorted_indices.sort_by_key(|&idx| items[idx].sort_priority());
+            Self::sort_unfiltered(items, &mut sorted_indices, &frecency);
+            sorted_indices.retain(|&idx| {
+                !Self::is_out_of_scope(&items[idx], self.windows_scoped)
+                    && !Self::is_clipboard_entry(&items[idx])
+            });
+            self.match_positions.clear();
             self.base.apply_filtered_indices(sorted_indices);
         } else {
-            let filtered_indices = Self::filter_items_sync(items, query);
+            let (filtered_indices, match_positions) =
+                Self::filter_items_sync(items, query, &frecency, self.result_order)
+                    .into_iter()
+                    .filter(|(idx, _)| !Self::is_out_of_scope(&items[*idx], self.windows_scoped))
+                    .unzip();
+            self.grouping = match self.result_order {
+                ResultOrder::Types => Grouping::Types,
+                ResultOrder::Relevance => Grouping::Mixed,
+            };
+            self.match_positions = match_positions;
             self.base.apply_filtered_indices(filtered_indices);
         }
         self.update_section_info();
@@ -240,44 +519,337 @@ impl ItemListDelegate {
         if self.base.selected_index().is_none() && self.filtered_count() > 0 {
             self.base.set_selected_unchecked(0);
         }
+        self.skip_hidden_selection();
     }
 
-    /// Filter items synchronously using fuzzy matching
-    fn filter_items_sync(items: &[ListItem], query: &str) -> Vec<usize> {
-        if query.is_empty() {
-            return (0..items.len()).collect();
+    /// Get the items of the home view: pinned applications, recently
+    /// launched applications by frecency, then clipboard entries.
+    ///
+    /// None if no application was pinned or launched yet, so there's
+    /// nothing to show but the full list.
+    fn home_indices(items: &[ListItem], frecency: &HashMap<String, f64>) -> Option<Vec<usize>> {
+        let by_frecency = |indices: &mut Vec<usize>| {
+            indices.sort_by(|&a, &b| {
+                Self::frecency_of(&items[b], frecency)
+                    .total_cmp(&Self::frecency_of(&items[a], frecency))
+            });
+        };
+        let apps = || (0..items.len()).filter(|&idx| items[idx].is_application());
+
+        let mut pinned: Vec<usize> = apps().filter(|&idx| items[idx].is_pinned()).collect();
+        by_frecency(&mut pinned);
+        let mut recent: Vec<usize> = apps()
+            .filter(|&idx| {
+                !items[idx].is_pinned() && Self::frecency_of(&items[idx], frecency) > 0.0
+            })
+            .collect();
+        by_frecency(&mut recent);
+        recent.truncate(HOME_RECENT_APPS);
+        if pinned.is_empty() && recent.is_empty() {
+            return None;
         }
 
-        let matcher = SkimMatcherV2::default();
-        let mut scored: Vec<(usize, i64)> = items
+        let clipboard = (0..items.len()).filter(|&idx| Self::is_clipboard_entry(&items[idx]));
+        Some(pinned.into_iter().chain(recent).chain(clipboard).collect())
+    }
+
+    /// Sort items by type even when showing all of them.
+    ///
+    /// This ensures sections (Windows, Commands, Applications) appear in
+    /// correct order, with pinned and then frequently and recently launched
+    /// apps first within a section.
+    fn sort_unfiltered(items: &[ListItem], indices: &mut [usize], frecency: &HashMap<String, f64>) {
+        indices.sort_by(|&a, &b| {
+            items[a]
+                .sort_priority()
+                .cmp(&items[b].sort_priority())
+                .then_with(|| items[b].is_pinned().cmp(&items[a].is_pinned()))
+                .then_with(|| {
+                    Self::frecency_of(&items[b], frecency)
+                        .total_cmp(&Self::frecency_of(&items[a], frecency))
+                })
+        });
+    }
+
+    /// Whether the query filters applications by category (`@dev`).
+    pub fn filters_by_category(&self) -> bool {
+        parse_category_query(self.base.query()).is_some()
+    }
+
+    /// Whether an application has a category starting with `term`
+    /// (lowercase).
+    fn in_category(item: &ListItem, term: &str) -> bool {
+        matches!(item, ListItem::Application(app) if app
+            .categories
             .iter()
-            .enumerate()
-            .filter_map(|(idx, item)| {
-                matcher
-                    .fuzzy_match(item.name(), query)
-                    .map(|score| (idx, score))
+            .any(|category| category.to_lowercase().starts_with(term)))
+    }
+
+    /// Get an entry for every category of the applications, by name, with
+    /// the number of applications in it.
+    fn category_items(items: &[ListItem]) -> Vec<ListItem> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for item in items {
+            if let ListItem::Application(app) = item {
+                for category in &app.categories {
+                    *counts.entry(category.as_str()).or_default() += 1;
+                }
+            }
+        }
+
+        counts
+            .into_iter()
+            .map(|(category, count)| {
+                let description = match count {
+                    1 => "1 application".to_string(),
+                    n => format!("{} applications", n),
+                };
+                ListItem::Submenu(
+                    SubmenuItem::list(format!("{}{}", CATEGORY_ID_PREFIX, category), category)
+                        .with_description(description)
+                        .with_icon("folder"),
+                )
             })
-            .collect();
+            .collect()
+    }
+
+    /// Whether an item is a category listed for a lone `@` query.
+    fn is_category_entry(item: &ListItem) -> bool {
+        matches!(item, ListItem::Submenu(submenu) if submenu.id.starts_with(CATEGORY_ID_PREFIX))
+    }
 
-        // Sort by priority first, then by score
+    /// Whether an item copies a clipboard entry (only in the home view).
+    fn is_clipboard_entry(item: &ListItem) -> bool {
+        matches!(item, ListItem::Action(action) if action.is_clipboard_item())
+    }
+
+    /// Filter items synchronously using fuzzy matching.
+    ///
+    /// Returns the matching item indices together with the character indices
+    /// of the name that matched the query (used for highlighting). Items also
+    /// match through their aliases, and launch frecency adds a bounded bonus
+    /// to the match score. `order` decides whether the matches are grouped by
+    /// type before being sorted by score.
+    fn filter_items_sync(
+        items: &[ListItem],
+        query: &str,
+        frecency: &HashMap<String, f64>,
+        order: ResultOrder,
+    ) -> Vec<(usize, Vec<usize>)> {
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        Self::filter_items_on_threads(items, query, frecency, order, threads)
+    }
+
+    /// Filter items, scoring large item sets in chunks on up to `threads`
+    /// threads.
+    ///
+    /// The chunks are merged back in item order before the stable sort, so
+    /// the result is the same as scoring on a single thread.
+    fn filter_items_on_threads(
+        items: &[ListItem],
+        query: &str,
+        frecency: &HashMap<String, f64>,
+        order: ResultOrder,
+        threads: usize,
+    ) -> Vec<(usize, Vec<usize>)> {
+        if query.is_empty() {
+            return (0..items.len()).map(|idx| (idx, Vec::new())).collect();
+        }
+
+        let mut scored = if threads <= 1 || items.len() < PARALLEL_FILTER_MIN_ITEMS {
+            Self::score_items(items, 0, query, frecency)
+        } else {
+            let chunk_size = items.len().div_ceil(threads);
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = items
+                    .chunks(chunk_size)
+                    .enumerate()
+                    .map(|(chunk_idx, chunk)| {
+                        scope.spawn(move || {
+                            Self::score_items(chunk, chunk_idx * chunk_size, query, frecency)
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().unwrap())
+                    .collect()
+            })
+        };
+
+        // Sort by priority first, then pinned applications, then by score.
+        // By relevance, pinned applications come first, then the best score
+        // of any type, with the priority only breaking ties.
         scored.sort_by(|a, b| {
             let priority_a = items[a.0].sort_priority();
             let priority_b = items[b.0].sort_priority();
-            priority_a.cmp(&priority_b).then_with(|| b.1.cmp(&a.1))
+            let pinned = items[b.0].is_pinned().cmp(&items[a.0].is_pinned());
+            match order {
+                ResultOrder::Types => priority_a
+                    .cmp(&priority_b)
+                    .then(pinned)
+                    .then_with(|| b.1.cmp(&a.1)),
+                ResultOrder::Relevance => pinned
+                    .then_with(|| b.1.cmp(&a.1))
+                    .then(priority_a.cmp(&priority_b)),
+            }
         });
 
-        scored.into_iter().map(|(idx, _)| idx).collect()
+        scored
+            .into_iter()
+            .map(|(idx, _, positions)| (idx, positions))
+            .collect()
+    }
+
+    /// Score the items matching the query, numbering them from `offset`.
+    ///
+    /// Names and aliases are matched with diacritics stripped from both sides,
+    /// so "cafe" matches "Café". Folding keeps one character per character,
+    /// so the match positions still highlight the displayed name.
+    fn score_items(
+        items: &[ListItem],
+        offset: usize,
+        query: &str,
+        frecency: &HashMap<String, f64>,
+    ) -> Vec<(usize, i64, Vec<usize>)> {
+        let matcher = SkimMatcherV2::default();
+        let query = fold_diacritics(query);
+        items
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, item)| {
+                let name_match = matcher.fuzzy_indices(&fold_diacritics(item.name()), &query);
+                let alias_score = Self::alias_score(&matcher, item.aliases(), &query);
+
+                // Only highlight the name if it matched at least as well as an alias
+                let (score, positions) = match (name_match, alias_score) {
+                    (Some((name_score, positions)), Some(alias_score))
+                        if alias_score > name_score =>
+                    {
+                        (alias_score, Vec::new())
+                    }
+                    (Some(name_match), _) => name_match,
+                    (None, Some(alias_score)) => (alias_score, Vec::new()),
+                    (None, None) => return None,
+                };
+
+                let bonus = (Self::frecency_of(item, frecency) * FRECENCY_WEIGHT) as i64;
+                Some((
+                    offset + idx,
+                    score + bonus.min(MAX_FRECENCY_BONUS),
+                    positions,
+                ))
+            })
+            .collect()
+    }
+
+    /// Best match score of the query against an item's aliases.
+    ///
+    /// An alias starting with the query scores twice a perfect match of the
+    /// query, so alias prefixes rank at least as high as name prefixes.
+    /// Aliases are folded like names, so the query must be folded already.
+    fn alias_score(matcher: &SkimMatcherV2, aliases: &[String], query: &str) -> Option<i64> {
+        let query_lower = query.to_lowercase();
+        aliases
+            .iter()
+            .filter_map(|alias| {
+                let alias = fold_diacritics(alias);
+                if alias.to_lowercase().starts_with(&query_lower) {
+                    matcher
+                        .fuzzy_match(&query_lower, &query_lower)
+                        .map(|score| score * 2)
+                } else {
+                    matcher.fuzzy_match(&alias, query)
+                }
+            })
+            .max()
+    }
+
+    /// Check whether a matched application's name or alias starts with the query.
+    fn has_strong_app_match(&self, query: &str) -> bool {
+        let query_lower = fold_diacritics(query).to_lowercase();
+        let starts_with_query = |text: &str| {
+            fold_diacritics(text)
+                .to_lowercase()
+                .starts_with(&query_lower)
+        };
+        let items = self.base.items();
+        self.base.filtered_indices().iter().any(|&idx| {
+            let item = &items[idx];
+            item.is_application()
+                && (starts_with_query(item.name())
+                    || item.aliases().iter().any(|alias| starts_with_query(alias)))
+        })
+    }
+
+    /// Whether an item is a window hidden by scoping to the focused workspace.
+    fn is_out_of_scope(item: &ListItem, windows_scoped: bool) -> bool {
+        windows_scoped && matches!(item, ListItem::Window(win) if !win.on_active_workspace)
+    }
+
+    /// Launch frecency of an item (only applications are tracked).
+    fn frecency_of(item: &ListItem, frecency: &HashMap<String, f64>) -> f64 {
+        match item {
+            ListItem::Application(app) => frecency.get(&app.id).copied().unwrap_or(0.0),
+            _ => 0.0,
+        }
+    }
+
+    /// Whether the calculator result or the terminal command is shown on top
+    fn has_top_item(&self) -> bool {
+        self.calculator_item.is_some() || self.command_item.is_some()
+    }
+
+    /// Number of items before the regular items (0 or 1)
+    fn top_offset(&self) -> usize {
+        if self.has_top_item() { 1 } else { 0 }
+    }
+
+    /// Get the calculator result or terminal command shown on top
+    fn top_item(&self) -> Option<ListItem> {
+        self.calculator_item
+            .clone()
+            .map(ListItem::Calculator)
+            .or_else(|| self.command_item.clone().map(ListItem::Action))
+    }
+
+    /// Get the matched name character indices of the item at a global index.
+    ///
+    /// Only regular items are fuzzy matched; dynamic items have no matches.
+    fn match_positions_at(&self, global_index: usize) -> &[usize] {
+        let top_offset = self.top_offset();
+        global_index
+            .checked_sub(top_offset)
+            .and_then(|filtered_idx| self.match_positions.get(filtered_idx))
+            .map_or(&[], Vec::as_slice)
     }
 
-    /// Compute section counts from filtered indices
-    fn compute_section_info(items: &[ListItem], filtered_indices: &[usize]) -> SectionInfo {
+    /// Compute section counts from filtered indices.
+    ///
+    /// Mixed results (ordered by relevance) are all counted in one section,
+    /// and the home view counts its own sections.
+    fn compute_section_info(
+        items: &[ListItem],
+        filtered_indices: &[usize],
+        grouping: Grouping,
+    ) -> SectionInfo {
         let mut info = SectionInfo::default();
 
         for &idx in filtered_indices {
             if let Some(item) = items.get(idx) {
-                if item.is_window() {
+                if grouping == Grouping::Mixed {
+                    info.result_count += 1;
+                } else if grouping == Grouping::Home {
+                    if Self::is_clipboard_entry(item) {
+                        info.clipboard_count += 1;
+                    } else if item.is_pinned() {
+                        info.pinned_count += 1;
+                    } else {
+                        info.recent_count += 1;
+                    }
+                } else if item.is_window() {
                     info.window_count += 1;
-                } else if item.is_submenu() || item.is_action() {
+                } else if item.is_submenu() || item.is_action() || item.is_snippet() {
                     info.command_count += 1;
                 } else if item.is_application() {
                     info.app_count += 1;
@@ -291,33 +863,42 @@ impl ItemListDelegate {
 
     /// Update section info after filtering
     fn update_section_info(&mut self) {
-        self.section_info =
-            Self::compute_section_info(self.base.items(), self.base.filtered_indices());
+        self.section_info = Self::compute_section_info(
+            self.base.items(),
+            self.base.filtered_indices(),
+            self.grouping,
+        );
         self.section_info.search_count = self.search_items.len();
     }
 
     /// Get an item at a global index (including dynamic items)
-    /// Order: Calculator, Regular items (Windows/Commands/Apps), AI, Search
+    /// Order: Calculator or command, Regular items (Windows/Commands/Apps), Streamed, AI, Search
     pub fn get_item_at(&self, global_index: usize) -> Option<ListItem> {
-        let calc_offset = if self.calculator_item.is_some() { 1 } else { 0 };
+        let top_offset = self.top_offset();
         let regular_count = self.base.filtered_count();
         let ai_offset = if self.ai_item.is_some() { 1 } else { 0 };
 
-        // Calculator item (always first if present)
-        if global_index == 0 && self.calculator_item.is_some() {
-            return self.calculator_item.clone().map(ListItem::Calculator);
+        // Calculator or command item (always first if present)
+        if global_index == 0 && self.has_top_item() {
+            return self.top_item();
         }
 
         // Regular filtered items (Windows, Commands, Applications)
-        let regular_start = calc_offset;
+        let regular_start = top_offset;
         let regular_end = regular_start + regular_count;
         if global_index >= regular_start && global_index < regular_end {
             let filtered_idx = global_index - regular_start;
             return self.base.get_filtered_item(filtered_idx).cloned();
         }
 
+        // Streamed items
+        let streamed_end = regular_end + self.streamed_items.len();
+        if global_index >= regular_end && global_index < streamed_end {
+            return self.streamed_items.get(global_index - regular_end).cloned();
+        }
+
         // AI item
-        let ai_start = regular_end;
+        let ai_start = streamed_end;
         if global_index == ai_start && self.ai_item.is_some() {
             return self.ai_item.clone().map(ListItem::Ai);
         }
@@ -340,6 +921,7 @@ impl ItemListDelegate {
     /// Execute confirm callback for the selected item
     pub fn do_confirm(&self) {
         if let Some(idx) = self.selected_index()
+            && !self.is_hidden(idx)
             && let Some(item) = self.get_item_at(idx)
             && let Some(ref callback) = self.on_confirm
         {
@@ -352,273 +934,646 @@ impl ItemListDelegate {
         self.base.do_cancel();
     }
 
-    /// Move selection down
+    /// Move selection down, skipping the items of collapsed sections.
+    ///
+    /// In grid sections this moves to the row below.
     pub fn select_down(&mut self) {
-        let count = self.filtered_count();
-        if count == 0 {
-            return;
+        if let Some(next) = self.vertical_neighbor(true) {
+            self.set_selected(next);
+        }
+    }
+
+    /// Move selection up, skipping the items of collapsed sections.
+    ///
+    /// In grid sections this moves to the row above.
+    pub fn select_up(&mut self) {
+        if let Some(prev) = self.vertical_neighbor(false) {
+            self.set_selected(prev);
         }
+    }
 
+    /// Move selection to the next item, wrapping from the end of a grid row
+    /// to the start of the next.
+    pub fn select_right(&mut self) {
         let current = self.selected_index().unwrap_or(0);
-        let next = if current + 1 >= count { 0 } else { current + 1 };
-        self.set_selected(next);
+        if let Some(next) = self.step_visible(current, true) {
+            self.set_selected(next);
+        }
     }
 
-    /// Move selection up
-    pub fn select_up(&mut self) {
+    /// Move selection to the previous item, wrapping from the start of a
+    /// grid row to the end of the previous.
+    pub fn select_left(&mut self) {
+        let current = self.selected_index().unwrap_or(0);
+        if let Some(prev) = self.step_visible(current, false) {
+            self.set_selected(prev);
+        }
+    }
+
+    /// Find the next or previous shown item after a global index, wrapping
+    /// around the ends of the list.
+    fn step_visible(&self, from: usize, forward: bool) -> Option<usize> {
+        let count = self.filtered_count();
+        (1..=count)
+            .map(|step| {
+                if forward {
+                    (from + step) % count
+                } else {
+                    (from + count - step % count) % count
+                }
+            })
+            .find(|&idx| !self.is_hidden(idx))
+    }
+
+    /// Find the item below or above the selection.
+    ///
+    /// Within a grid section this is the item in the same column of the next
+    /// row. Past the end of a section it's the nearest row of the next shown
+    /// section, keeping the column where possible.
+    fn vertical_neighbor(&self, down: bool) -> Option<usize> {
+        let current = self.selected_index().unwrap_or(0);
+        let Some(section) = self
+            .section_type_of(current)
+            .filter(|_| !self.is_hidden(current))
+        else {
+            return self.step_visible(current, down);
+        };
+
+        let columns = self.columns(section);
+        let start = self.section_start_index(section);
+        let shown = self.shown_len(section);
+        let (row, column) = ((current - start) / columns, (current - start) % columns);
+        if down && row + 1 < shown.div_ceil(columns) {
+            return Some((current + columns).min(start + shown - 1));
+        }
+        if !down && row > 0 {
+            return Some(current - columns);
+        }
+
+        // Step over the section boundary, then line up with the column
+        let boundary = if down { start + shown - 1 } else { start };
+        let neighbor = self.step_visible(boundary, down)?;
+        let section = self.section_type_of(neighbor)?;
+        let columns = self.columns(section);
+        let start = self.section_start_index(section);
+        let shown = self.shown_len(section);
+        let row_start = if down {
+            start
+        } else {
+            start + (shown - 1) / columns * columns
+        };
+        Some((row_start + column.min(columns - 1)).min(start + shown - 1))
+    }
+
+    /// Number of columns the section of the selection is laid out in.
+    fn selected_columns(&self) -> usize {
+        self.selected_index()
+            .and_then(|idx| self.section_type_of(idx))
+            .map_or(1, |section| self.columns(section))
+    }
+
+    /// Move selection down by `page` items, or rows in a grid (clamped, no wrapping)
+    pub fn select_page_down(&mut self, page: usize) {
         let count = self.filtered_count();
         if count == 0 {
             return;
         }
 
+        let page = page * self.selected_columns();
         let current = self.selected_index().unwrap_or(0);
-        let prev = if current == 0 { count - 1 } else { current - 1 };
-        self.set_selected(prev);
+        let target = (current + page).min(count - 1);
+        if let Some(target) = self
+            .nearest_visible(target, false)
+            .filter(|&idx| idx > current)
+            .or_else(|| self.nearest_visible(target, true))
+        {
+            self.set_selected(target);
+        }
     }
 
-    /// Get all items for external access
-    pub fn items(&self) -> Arc<Vec<ListItem>> {
-        Arc::new(self.base.items().to_vec())
+    /// Move selection up by `page` items, or rows in a grid (clamped, no wrapping)
+    pub fn select_page_up(&mut self, page: usize) {
+        let page = page * self.selected_columns();
+        let current = self.selected_index().unwrap_or(0);
+        let target = current.saturating_sub(page);
+        if let Some(target) = self
+            .nearest_visible(target, true)
+            .filter(|&idx| idx < current)
+            .or_else(|| self.nearest_visible(target, false))
+        {
+            self.set_selected(target);
+        }
     }
 
-    /// Determine what type of section is at the given section index.
-    /// Order: Calculator, Windows, Commands, Applications, SearchAndAi
-    fn section_type_at(&self, section: usize) -> SectionType {
-        let has_calc = self.calculator_item.is_some();
-        let has_windows = self.section_info.window_count > 0;
-        let has_commands = self.section_info.command_count > 0;
-        let has_apps = self.section_info.app_count > 0;
-        let has_search_and_ai = self.ai_item.is_some() || !self.search_items.is_empty();
+    /// Select the row under the pointer.
+    ///
+    /// Only reacts to actual pointer movement, so rows scrolling under a
+    /// resting pointer during keyboard navigation don't steal the selection.
+    /// Returns whether the selection changed.
+    pub fn hover_select(&mut self, index: usize, position: Point<Pixels>) -> bool {
+        if self.last_pointer_position.replace(position) == Some(position)
+            || self.selected_index() == Some(index)
+        {
+            return false;
+        }
+        self.set_selected(index);
+        self.selected_index() == Some(index)
+    }
 
-        let mut current_section = 0;
+    /// Select the first item (including dynamic items)
+    pub fn select_first(&mut self) {
+        if let Some(first) = self.nearest_visible(0, true) {
+            self.set_selected(first);
+        }
+    }
 
-        // Calculator always first
-        if has_calc {
-            if section == current_section {
-                return SectionType::Calculator;
-            }
-            current_section += 1;
+    /// Select the last item (including dynamic items)
+    pub fn select_last(&mut self) {
+        if let Some(last) = self
+            .filtered_count()
+            .checked_sub(1)
+            .and_then(|last| self.nearest_visible(last, false))
+        {
+            self.set_selected(last);
         }
+    }
 
-        // Regular items in the middle
-        if has_windows {
-            if section == current_section {
-                return SectionType::Windows;
-            }
-            current_section += 1;
+    /// Collapse the section of the selected item.
+    ///
+    /// The selection moves on to the next shown item. When every section
+    /// is collapsed, they are all expanded again instead.
+    pub fn toggle_selected_section(&mut self) {
+        let section = self
+            .selected_index()
+            .filter(|&idx| !self.is_hidden(idx))
+            .and_then(|idx| self.section_type_of(idx));
+        match section {
+            Some(section) => self.toggle_section(section),
+            None => self.expand_sections(),
         }
+    }
 
-        if has_commands {
-            if section == current_section {
-                return SectionType::Commands;
+    /// Collapse or expand a section.
+    fn toggle_section(&mut self, section: SectionType) {
+        let Some(name) = self.section_key(section) else {
+            return;
+        };
+        if !self.collapsed_sections.remove(&name) {
+            self.collapsed_sections.insert(name);
+        }
+        self.skip_hidden_selection();
+    }
+
+    /// Expand all collapsed sections.
+    pub fn expand_sections(&mut self) {
+        self.collapsed_sections.clear();
+    }
+
+    /// Lowercase name of a section, used to remember it collapsed.
+    ///
+    /// The calculator and command row can't be collapsed.
+    fn section_key(&self, section: SectionType) -> Option<String> {
+        let name = match section {
+            SectionType::Top => return None,
+            SectionType::Pinned => "Pinned",
+            SectionType::Recent => "Recent",
+            SectionType::Clipboard => "Clipboard",
+            SectionType::Windows => "Windows",
+            SectionType::Commands => "Commands",
+            SectionType::Applications => "Applications",
+            SectionType::Results => "Results",
+            SectionType::Streamed => self.streamed_items.first()?.section_name(),
+            SectionType::SearchAndAi => "Search and AI",
+        };
+        Some(name.to_lowercase())
+    }
+
+    /// Show all results of the selected section.
+    ///
+    /// Only applies when the last shown result of a section with more
+    /// results is selected. Returns whether the section was expanded.
+    pub fn expand_selected_section(&mut self) -> bool {
+        let Some(selected) = self.selected_index().filter(|&idx| !self.is_hidden(idx)) else {
+            return false;
+        };
+        match self.section_type_of(selected) {
+            Some(section)
+                if self.is_truncated(section)
+                    && selected + 1
+                        == self.section_start_index(section) + self.shown_len(section) =>
+            {
+                self.expanded_sections.insert(section);
+                true
             }
-            current_section += 1;
+            _ => false,
         }
+    }
+
+    /// Whether the rows of a section are hidden.
+    fn is_collapsed(&self, section: SectionType) -> bool {
+        self.section_key(section)
+            .is_some_and(|name| self.collapsed_sections.contains(&name))
+    }
 
-        if has_apps {
-            if section == current_section {
-                return SectionType::Applications;
+    /// Number of items in a section, whether collapsed or not.
+    fn section_len(&self, section: SectionType) -> usize {
+        match section {
+            SectionType::Top => self.top_offset(),
+            SectionType::Pinned => self.section_info.pinned_count,
+            SectionType::Recent => self.section_info.recent_count,
+            SectionType::Clipboard => self.section_info.clipboard_count,
+            SectionType::Windows => self.section_info.window_count,
+            SectionType::Commands => self.section_info.command_count,
+            SectionType::Applications => self.section_info.app_count,
+            SectionType::Results => self.section_info.result_count,
+            SectionType::Streamed => self.streamed_items.len(),
+            SectionType::SearchAndAi => {
+                let ai_count = if self.ai_item.is_some() { 1 } else { 0 };
+                ai_count + self.search_items.len()
             }
-            current_section += 1;
         }
+    }
+
+    /// Whether a section has more results than the limit shown while
+    /// searching. Ranking happens first, so the best matches are shown.
+    fn is_truncated(&self, section: SectionType) -> bool {
+        let limited = matches!(
+            section,
+            SectionType::Windows
+                | SectionType::Commands
+                | SectionType::Applications
+                | SectionType::Results
+                | SectionType::Streamed
+        );
+        limited
+            && self.section_result_limit > 0
+            && !self.base.query().is_empty()
+            && !self.is_collapsed(section)
+            && !self.expanded_sections.contains(&section)
+            && self.section_len(section) > self.section_result_limit
+    }
+
+    /// Whether sections are laid out in a grid.
+    pub fn is_grid(&self) -> bool {
+        self.grid_columns > 1
+    }
+
+    /// Number of columns a section is laid out in.
+    ///
+    /// The calculator or command row and the search and AI items always
+    /// span the full width.
+    fn columns(&self, section: SectionType) -> usize {
+        match section {
+            SectionType::Pinned
+            | SectionType::Recent
+            | SectionType::Windows
+            | SectionType::Commands
+            | SectionType::Applications
+            | SectionType::Results
+            | SectionType::Streamed => self.grid_columns,
+            SectionType::Top | SectionType::Clipboard | SectionType::SearchAndAi => 1,
+        }
+    }
+
+    /// Number of items of a section shown in the list.
+    fn shown_len(&self, section: SectionType) -> usize {
+        if self.is_collapsed(section) {
+            0
+        } else if self.is_truncated(section) {
+            self.section_result_limit
+        } else {
+            self.section_len(section)
+        }
+    }
+
+    /// Get the section of the item at a global index.
+    fn section_type_of(&self, global_idx: usize) -> Option<SectionType> {
+        SectionType::ALL.into_iter().find(|&section| {
+            let start = self.section_start_index(section);
+            (start..start + self.section_len(section)).contains(&global_idx)
+        })
+    }
+
+    /// Whether the item at a global index is in a collapsed section or past
+    /// the result limit of its section.
+    fn is_hidden(&self, global_idx: usize) -> bool {
+        self.section_type_of(global_idx).is_some_and(|section| {
+            global_idx - self.section_start_index(section) >= self.shown_len(section)
+        })
+    }
+
+    /// Find the nearest shown item from a global index, looking forward or
+    /// backward.
+    fn nearest_visible(&self, from: usize, forward: bool) -> Option<usize> {
+        let count = self.filtered_count();
+        if forward {
+            (from..count).find(|&idx| !self.is_hidden(idx))
+        } else {
+            (0..=from.min(count.checked_sub(1)?))
+                .rev()
+                .find(|&idx| !self.is_hidden(idx))
+        }
+    }
+
+    /// Move the selection off an item of a collapsed section.
+    fn skip_hidden_selection(&mut self) {
+        if let Some(selected) = self.selected_index()
+            && self.is_hidden(selected)
+            && let Some(visible) = self
+                .nearest_visible(selected, true)
+                .or_else(|| self.nearest_visible(selected, false))
+        {
+            self.base.set_selected_unchecked(visible);
+        }
+    }
+
+    /// Remove a closed window from the list.
+    ///
+    /// The selection stays at the same position so the next window is
+    /// selected right away.
+    pub fn remove_window(&mut self, address: &str) {
+        let selected = self.selected_index();
+        self.base
+            .items_mut()
+            .retain(|item| !matches!(item, ListItem::Window(win) if win.address == address));
+        self.filter_items();
 
-        // SearchAndAi section at the end (combined, no gap)
-        if has_search_and_ai && section == current_section {
-            return SectionType::SearchAndAi;
+        if let Some(selected) = selected
+            && let Some(last) = self.filtered_count().checked_sub(1)
+        {
+            self.set_selected(selected.min(last));
         }
+    }
+
+    /// Get all items for external access
+    pub fn items(&self) -> Arc<Vec<ListItem>> {
+        Arc::new(self.base.items().to_vec())
+    }
+
+    /// Get the sections in the list, in order.
+    /// Order: Calculator or command, home view sections, Windows, Commands,
+    /// Applications, relevance-ordered results, Streamed, SearchAndAi
+    fn sections(&self) -> Vec<SectionType> {
+        SectionType::ALL
+            .into_iter()
+            .filter(|&section| self.section_len(section) > 0)
+            .collect()
+    }
 
-        // Default (shouldn't reach here)
-        SectionType::Applications
+    /// Determine what type of section is at the given section index.
+    fn section_type_at(&self, section: usize) -> SectionType {
+        // Default (shouldn't happen)
+        self.sections()
+            .get(section)
+            .copied()
+            .unwrap_or(SectionType::Applications)
     }
 
     /// Get the starting global index for a given section type.
-    /// Order: Calculator, Windows, Commands, Applications, SearchAndAi
+    ///
+    /// Sections follow each other in the order of `SectionType::ALL`, and
+    /// the regular items fill the sections of their grouping only.
     fn section_start_index(&self, section_type: SectionType) -> usize {
-        let calc_offset = if self.calculator_item.is_some() { 1 } else { 0 };
-
-        match section_type {
-            SectionType::Calculator => 0,
-            SectionType::Windows => calc_offset,
-            SectionType::Commands => calc_offset + self.section_info.window_count,
-            SectionType::Applications => {
-                calc_offset + self.section_info.window_count + self.section_info.command_count
-            }
-            SectionType::SearchAndAi => {
-                calc_offset
-                    + self.section_info.window_count
-                    + self.section_info.command_count
-                    + self.section_info.app_count
-            }
-        }
+        SectionType::ALL
+            .into_iter()
+            .take_while(|&section| section != section_type)
+            .map(|section| self.section_len(section))
+            .sum()
     }
 
     /// Convert section+row to global index.
+    ///
+    /// Rows of grid sections map to the first item of the row.
     fn section_row_to_global(&self, section: usize, row: usize) -> usize {
         let section_type = self.section_type_at(section);
-        self.section_start_index(section_type) + row
+        self.section_start_index(section_type) + row * self.columns(section_type)
     }
 
     /// Convert global index to section+row IndexPath.
-    /// Order: Calculator, Windows, Commands, Applications, SearchAndAi
+    /// Items of collapsed sections have no row and return None.
     pub fn global_to_index_path(&self, global_idx: usize) -> Option<IndexPath> {
-        let calc_offset = if self.calculator_item.is_some() { 1 } else { 0 };
-        let regular_count = self.base.filtered_count();
-
-        let mut current_section = 0;
-
-        // Calculator section
-        if self.calculator_item.is_some() {
-            if global_idx < calc_offset {
-                return Some(IndexPath::new(global_idx).section(current_section));
-            }
-            current_section += 1;
+        if self.is_hidden(global_idx) {
+            return None;
         }
+        let section_type = self.section_type_of(global_idx)?;
+        let section = self.sections().iter().position(|&s| s == section_type)?;
+        let row =
+            (global_idx - self.section_start_index(section_type)) / self.columns(section_type);
+        Some(IndexPath::new(row).section(section))
+    }
 
-        // Regular filtered items (Windows, Commands, Applications)
-        let regular_start = calc_offset;
-        let regular_end = regular_start + regular_count;
-
-        if global_idx >= regular_start && global_idx < regular_end {
-            let regular_idx = global_idx - regular_start;
-
-            // Windows section
-            if self.section_info.window_count > 0 {
-                if regular_idx < self.section_info.window_count {
-                    return Some(IndexPath::new(regular_idx).section(current_section));
-                }
-                current_section += 1;
-            }
-
-            // Commands section
-            if self.section_info.command_count > 0 {
-                let cmd_start = self.section_info.window_count;
-                let cmd_end = cmd_start + self.section_info.command_count;
-                if regular_idx >= cmd_start && regular_idx < cmd_end {
-                    return Some(IndexPath::new(regular_idx - cmd_start).section(current_section));
-                }
-                current_section += 1;
-            }
-
-            // Applications section
-            if self.section_info.app_count > 0 {
-                let app_start = self.section_info.window_count + self.section_info.command_count;
-                if regular_idx >= app_start {
-                    return Some(IndexPath::new(regular_idx - app_start).section(current_section));
-                }
-                current_section += 1;
-            }
+    /// Render a section header, which collapses or expands the section when
+    /// clicked. Collapsed sections show their item count.
+    fn render_header(
+        &self,
+        section: SectionType,
+        title: &str,
+        collapsed: bool,
+        cx: &mut Context<'_, ListState<Self>>,
+    ) -> Stateful<Div> {
+        let theme = theme();
+        let title = if collapsed {
+            format!("{} · {}", title, self.section_len(section))
         } else {
-            // Skip past regular sections in section counter
-            if self.section_info.window_count > 0 {
-                current_section += 1;
-            }
-            if self.section_info.command_count > 0 {
-                current_section += 1;
-            }
-            if self.section_info.app_count > 0 {
-                current_section += 1;
-            }
+            title.to_string()
+        };
+        let header = div()
+            .id(SharedString::from(format!("section-header-{:?}", section)))
+            .w_full()
+            .px(theme.item_margin_x + theme.item_padding_x)
+            .pt(theme.section_header.margin_top)
+            .pb(theme.section_header.margin_bottom)
+            .text_xs()
+            .font_weight(gpui::FontWeight::EXTRA_BOLD)
+            .text_color(theme.section_header.color)
+            .child(SharedString::from(title));
+
+        if self.section_key(section).is_none() {
+            return header;
         }
+        header
+            .cursor_pointer()
+            .on_click(cx.listener(move |state, _: &ClickEvent, window, cx| {
+                let delegate = state.delegate_mut();
+                delegate.toggle_section(section);
+                if let Some(idx) = delegate.selected_index()
+                    && let Some(index_path) = delegate.global_to_index_path(idx)
+                {
+                    state.set_selected_index(Some(index_path), window, cx);
+                }
+                cx.notify();
+            }))
+    }
 
-        // SearchAndAi section (combined AI + Search, no gap)
-        if self.ai_item.is_some() || !self.search_items.is_empty() {
-            let search_and_ai_start = regular_end;
-            let ai_count = if self.ai_item.is_some() { 1 } else { 0 };
-            let search_and_ai_end = search_and_ai_start + ai_count + self.search_items.len();
+    /// Render a row of a grid section, with an item in each column.
+    fn render_grid_row(
+        &self,
+        ix: IndexPath,
+        section: SectionType,
+        cx: &mut Context<'_, ListState<Self>>,
+    ) -> Option<GpuiListItem> {
+        let theme = theme();
+        let columns = self.columns(section);
+        let section_end = self.section_start_index(section) + self.shown_len(section);
+        let row_start = self.section_row_to_global(ix.section, ix.row);
 
-            if global_idx >= search_and_ai_start && global_idx < search_and_ai_end {
-                return Some(
-                    IndexPath::new(global_idx - search_and_ai_start).section(current_section),
-                );
-            }
+        let mut row = div()
+            .w_full()
+            .px(theme.item_margin_x)
+            .flex()
+            .flex_row()
+            .gap(theme.item_margin_x);
+        for global_idx in row_start..(row_start + columns).min(section_end) {
+            let item = self.get_item_at(global_idx)?;
+            let selected = self.base.selected_index() == Some(global_idx);
+            row = row.child(render_grid_item(&item, selected, global_idx).on_mouse_move(
+                cx.listener(move |state, event: &MouseMoveEvent, window, cx| {
+                    if state
+                        .delegate_mut()
+                        .hover_select(global_idx, event.position)
+                    {
+                        state.set_selected_index(Some(ix), window, cx);
+                        cx.notify();
+                    }
+                }),
+            ));
+        }
+        // Pad the last row so the items keep the width of a column
+        for _ in section_end..row_start + columns {
+            row = row.child(div().flex_1());
         }
 
-        None
+        Some(
+            GpuiListItem::new(("list-row", row_start))
+                .py_0()
+                .px_0()
+                .child(row),
+        )
+    }
+
+    /// Render the row after the shown results of a section over the result
+    /// limit, which shows all of them when clicked.
+    fn render_more_row(
+        &self,
+        section: SectionType,
+        cx: &mut Context<'_, ListState<Self>>,
+    ) -> GpuiListItem {
+        let theme = theme();
+        let more = self.section_len(section) - self.shown_len(section);
+        let row = div()
+            .id(SharedString::from(format!("more-results-{:?}", section)))
+            .w_full()
+            .px(theme.item_margin_x + theme.item_padding_x)
+            .py(theme.section_header.margin_bottom)
+            .text_xs()
+            .text_color(theme.section_header.color)
+            .cursor_pointer()
+            .child(SharedString::from(format!(
+                "+{} more — press → to expand",
+                more
+            )))
+            .on_click(cx.listener(move |state, _: &ClickEvent, _window, cx| {
+                cx.stop_propagation();
+                state.delegate_mut().expanded_sections.insert(section);
+                cx.notify();
+            }));
+
+        GpuiListItem::new(SharedString::from(format!("list-more-{:?}", section)))
+            .py_0()
+            .px_0()
+            .child(row)
     }
 }
 
+/// Split an `@category search` query into the lowercase category term and
+/// the search.
+///
+/// Returns None for queries that don't start with `@`.
+fn parse_category_query(query: &str) -> Option<(String, String)> {
+    let rest = query.trim_start().strip_prefix('@')?;
+    let (term, search) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    Some((term.to_lowercase(), search.trim().to_string()))
+}
+
+/// Get the shell command of a `> command` query.
+///
+/// Control characters (e.g. pasted newlines) are dropped so the command
+/// stays on a single line.
+fn parse_command_query(query: &str) -> Option<String> {
+    let command: String = query
+        .trim_start()
+        .strip_prefix('>')?
+        .chars()
+        .filter(|c| !c.is_control())
+        .collect();
+    let command = command.trim();
+    (!command.is_empty()).then(|| command.to_string())
+}
+
 /// Section types for organizing items in the list.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 enum SectionType {
-    Calculator,
+    Top,       // Calculator result or terminal command
+    Pinned,    // Pinned applications in the home view
+    Recent,    // Recently launched applications in the home view
+    Clipboard, // Latest clipboard entries in the home view
     Windows,
     Commands,
     Applications,
+    Results,     // Matches of every type, when ordered by relevance
+    Streamed,    // Results pushed by streaming providers
     SearchAndAi, // Combined AI + Search section (no gap between them)
 }
 
+impl SectionType {
+    /// All section types, in list order.
+    const ALL: [SectionType; 10] = [
+        SectionType::Top,
+        SectionType::Pinned,
+        SectionType::Recent,
+        SectionType::Clipboard,
+        SectionType::Windows,
+        SectionType::Commands,
+        SectionType::Applications,
+        SectionType::Results,
+        SectionType::Streamed,
+        SectionType::SearchAndAi,
+    ];
+}
+
 /// Implement ListDelegate trait for GPUI integration.
 impl ListDelegate for ItemListDelegate {
     type Item = GpuiListItem;
 
     fn sections_count(&self, _cx: &App) -> usize {
-        let has_calc = self.calculator_item.is_some();
-        let has_search_and_ai = self.ai_item.is_some() || !self.search_items.is_empty();
-        let has_windows = self.section_info.window_count > 0;
-        let has_commands = self.section_info.command_count > 0;
-        let has_apps = self.section_info.app_count > 0;
-
-        let mut count = 0;
-        if has_calc {
-            count += 1;
-        }
-        if has_windows {
-            count += 1;
-        }
-        if has_commands {
-            count += 1;
-        }
-        if has_apps {
-            count += 1;
-        }
-        if has_search_and_ai {
-            count += 1; // Combined AI + Search section
-        }
-
-        count
+        self.sections().len()
     }
 
     fn items_count(&self, section: usize, _cx: &App) -> usize {
         let section_type = self.section_type_at(section);
-        match section_type {
-            SectionType::Calculator => 1,
-            SectionType::Windows => self.section_info.window_count,
-            SectionType::Commands => self.section_info.command_count,
-            SectionType::Applications => self.section_info.app_count,
-            SectionType::SearchAndAi => {
-                let ai_count = if self.ai_item.is_some() { 1 } else { 0 };
-                ai_count + self.search_items.len()
-            }
-        }
+        // Collapsed sections only show their header, and sections over the
+        // result limit end with a row showing how many more there are
+        self.shown_len(section_type)
+            .div_ceil(self.columns(section_type))
+            + self.is_truncated(section_type) as usize
     }
 
     fn render_section_header(
         &mut self,
         section: usize,
         _window: &mut Window,
-        _cx: &mut Context<'_, ListState<Self>>,
+        cx: &mut Context<'_, ListState<Self>>,
     ) -> Option<impl IntoElement> {
         let section_type = self.section_type_at(section);
+        let collapsed = self.is_collapsed(section_type);
 
         // Show "Search and AI" header when we have regular items above
-        let has_regular_items = self.section_info.window_count > 0
-            || self.section_info.command_count > 0
-            || self.section_info.app_count > 0
-            || self.calculator_item.is_some();
-
-        if section_type == SectionType::SearchAndAi && has_regular_items {
-            let theme = theme();
-            return Some(
-                div()
-                    .w_full()
-                    .px(theme.item_margin_x + theme.item_padding_x)
-                    .pt(theme.section_header.margin_top)
-                    .pb(theme.section_header.margin_bottom)
-                    .text_xs()
-                    .font_weight(gpui::FontWeight::EXTRA_BOLD)
-                    .text_color(theme.section_header.color)
-                    .child(SharedString::from("Search and AI")),
-            );
+        let has_regular_items = self.base.filtered_count() > 0
+            || !self.streamed_items.is_empty()
+            || self.has_top_item();
+
+        if section_type == SectionType::SearchAndAi && (has_regular_items || collapsed) {
+            return Some(self.render_header(section_type, "Search and AI", collapsed, cx));
         }
 
         // SearchAndAi (without regular items) has no header
@@ -627,52 +1582,79 @@ impl ListDelegate for ItemListDelegate {
         }
 
         // Count how many non-special sections we have
-        let has_windows = self.section_info.window_count > 0;
-        let has_commands = self.section_info.command_count > 0;
-        let has_apps = self.section_info.app_count > 0;
-        let non_special_section_count =
-            has_windows as usize + has_commands as usize + has_apps as usize;
+        let non_special_section_count = self
+            .sections()
+            .into_iter()
+            .filter(|&section| !matches!(section, SectionType::Top | SectionType::SearchAndAi))
+            .count();
         let has_search_and_ai = self.ai_item.is_some() || !self.search_items.is_empty();
 
         // Show headers if we have multiple non-special sections
-        // OR if we have SearchAndAi section (to visually separate them from regular items)
-        if non_special_section_count <= 1 && !has_search_and_ai {
+        // OR if we have SearchAndAi section (to visually separate them from regular items).
+        // Collapsed sections always keep their header.
+        if non_special_section_count <= 1 && !has_search_and_ai && !collapsed {
             return None;
         }
 
-        let theme = theme();
         let title = match section_type {
-            SectionType::Calculator => "Calculator",
+            SectionType::Top if self.command_item.is_some() => "Run Command",
+            SectionType::Top => "Calculator",
             SectionType::SearchAndAi => return None,
+            SectionType::Pinned => "Pinned",
+            SectionType::Recent => "Recent",
+            SectionType::Clipboard => "Clipboard",
+            SectionType::Windows if self.windows_scoped => "Windows (this workspace)",
             SectionType::Windows => "Windows",
             SectionType::Commands => "Commands",
             SectionType::Applications => "Applications",
+            SectionType::Results => "Results",
+            SectionType::Streamed => self
+                .streamed_items
+                .first()
+                .map(|item| item.section_name())
+                .unwrap_or("Results"),
         };
 
-        Some(
-            div()
-                .w_full()
-                .px(theme.item_margin_x + theme.item_padding_x)
-                .pt(theme.section_header.margin_top)
-                .pb(theme.section_header.margin_bottom)
-                .text_xs()
-                .font_weight(gpui::FontWeight::EXTRA_BOLD)
-                .text_color(theme.section_header.color)
-                .child(SharedString::from(title)),
-        )
+        Some(self.render_header(section_type, title, collapsed, cx))
     }
 
     fn render_item(
         &mut self,
         ix: IndexPath,
         _window: &mut Window,
-        _cx: &mut Context<'_, ListState<Self>>,
+        cx: &mut Context<'_, ListState<Self>>,
     ) -> Option<Self::Item> {
+        let section_type = self.section_type_at(ix.section);
+        let columns = self.columns(section_type);
+        let shown = self.shown_len(section_type);
+        if self.is_truncated(section_type) && ix.row == shown.div_ceil(columns) {
+            return Some(self.render_more_row(section_type, cx));
+        }
+        if columns > 1 {
+            return self.render_grid_row(ix, section_type, cx);
+        }
+
         let global_idx = self.section_row_to_global(ix.section, ix.row);
         let selected = self.base.selected_index() == Some(global_idx);
 
         let item = self.get_item_at(global_idx)?;
-        let item_content = render_item(&item, selected, global_idx);
+        let item_content = render_item(
+            &item,
+            selected,
+            global_idx,
+            self.match_positions_at(global_idx),
+        )
+        .on_mouse_move(
+            cx.listener(move |state, event: &MouseMoveEvent, window, cx| {
+                if state
+                    .delegate_mut()
+                    .hover_select(global_idx, event.position)
+                {
+                    state.set_selected_index(Some(ix), window, cx);
+                    cx.notify();
+                }
+            }),
+        );
 
         // Reset ListItem default padding - we handle all styling ourselves
         Some(
@@ -693,6 +1675,21 @@ impl ListDelegate for ItemListDelegate {
             .map(|i| self.section_row_to_global(i.section, i.row))
             .unwrap_or(0);
 
+        // The "more results" row maps to the first hidden item, which can't be selected
+        if self.is_hidden(global_idx) {
+            return;
+        }
+
+        // Grid rows map to their first item, keep the selected item of the row
+        let columns = ix.map_or(1, |ix| self.columns(self.section_type_at(ix.section)));
+        if self
+            .base
+            .selected_index()
+            .is_some_and(|selected| (global_idx..global_idx + columns).contains(&selected))
+        {
+            return;
+        }
+
         // Use unchecked method to allow selection of dynamic items (AI, Search)
         // that are beyond the base filtered count
         self.base.set_selected_unchecked(global_idx);
@@ -741,3 +1738,376 @@ impl ListDelegate for ItemListDelegate {
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::ApplicationItem;
+    use std::path::PathBuf;
+
+    fn app(id: &str, name: &str, aliases: &[&str]) -> ListItem {
+        ListItem::Application(
+            ApplicationItem::new(
+                id.to_string(),
+                name.to_string(),
+                id.to_string(),
+                None,
+                None,
+                false,
+                PathBuf::new(),
+            )
+            .with_aliases(aliases.iter().map(|a| a.to_string()).collect()),
+        )
+    }
+
+    #[test]
+    fn test_alias_only_match() {
+        let items = vec![
+            app("firefox", "Firefox", &["web", "browser"]),
+            app("files", "Files", &[]),
+        ];
+
+        let matches = ItemListDelegate::filter_items_sync(
+            &items,
+            "browser",
+            &HashMap::new(),
+            ResultOrder::Types,
+        );
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0, 0);
+        // Nothing in the name matched, so nothing is highlighted
+        assert!(matches[0].1.is_empty());
+    }
+
+    #[test]
+    fn test_alias_ranks_with_name_prefix() {
+        let items = vec![
+            app("webcam", "Webcam Viewer", &[]),
+            app("firefox", "Firefox", &["web"]),
+        ];
+
+        let matches =
+            ItemListDelegate::filter_items_sync(&items, "web", &HashMap::new(), ResultOrder::Types);
+        let order: Vec<usize> = matches.iter().map(|(idx, _)| *idx).collect();
+        assert_eq!(order, vec![1, 0]);
+        assert_eq!(matches[1].1, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_accented_names_match_folded_query() {
+        let items = vec![
+            app("cafe", "Café", &[]),
+            app("espanol", "Diccionario Español", &["traducción"]),
+            app("calc", "Calculator", &[]),
+        ];
+        let filter = |query: &str| {
+            ItemListDelegate::filter_items_sync(&items, query, &HashMap::new(), ResultOrder::Types)
+        };
+
+        let matches = filter("cafe");
+        assert_eq!(matches, vec![(0, vec![0, 1, 2, 3])]);
+        assert_eq!(items[0].name(), "Café");
+        // Accents in the query are folded too
+        assert_eq!(filter("café"), matches);
+
+        let matches = filter("espanol");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0, 1);
+        assert_eq!(matches[0].1, (12..19).collect::<Vec<_>>());
+        assert_eq!(filter("traduccion")[0].0, 1);
+
+        // Unaccented names still match as before
+        assert_eq!(filter("calc")[0], (2, vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_pinned_apps_rank_first() {
+        let pinned = |item: ListItem| match item {
+            ListItem::Application(app) => ListItem::Application(app.with_pinned(true)),
+            item => item,
+        };
+        let items = vec![
+            app("terminal", "Terminal", &[]),
+            pinned(app("alacritty", "Alacritty", &["terminal"])),
+            pinned(app("firefox", "Firefox", &[])),
+        ];
+
+        let matches = ItemListDelegate::filter_items_sync(
+            &items,
+            "term",
+            &HashMap::new(),
+            ResultOrder::Types,
+        );
+        let order: Vec<usize> = matches.iter().map(|(idx, _)| *idx).collect();
+        // Pinned apps that don't match stay hidden
+        assert_eq!(order, vec![1, 0]);
+    }
+
+    #[test]
+    fn test_relevance_order() {
+        let items = vec![
+            ListItem::Submenu(SubmenuItem::list("submenu-cache", "Clear Files Cache")),
+            app("files", "Files", &[]),
+        ];
+
+        let order = |order| -> Vec<usize> {
+            ItemListDelegate::filter_items_sync(&items, "files", &HashMap::new(), order)
+                .into_iter()
+                .map(|(idx, _)| idx)
+                .collect()
+        };
+        // Commands come before applications, but the app name matches better
+        assert_eq!(order(ResultOrder::Types), vec![0, 1]);
+        assert_eq!(order(ResultOrder::Relevance), vec![1, 0]);
+
+        let mut delegate = ItemListDelegate::new(items);
+        delegate.result_order = ResultOrder::Relevance;
+        delegate.set_query("files".to_string());
+        assert_eq!(delegate.section_len(SectionType::Results), 2);
+        assert_eq!(delegate.section_len(SectionType::Commands), 0);
+        assert!(matches!(
+            delegate.get_item_at(0),
+            Some(ListItem::Application(_))
+        ));
+
+        // Without a query the list is grouped again
+        delegate.set_query(String::new());
+        assert_eq!(delegate.section_len(SectionType::Results), 0);
+    }
+
+    #[test]
+    fn test_home_view_items() {
+        let items = vec![
+            app("firefox", "Firefox", &[]),
+            ListItem::Action(ActionItem::builtin(ActionKind::CopyClipboardItem(7))),
+            app("files", "Files", &[]),
+            match app("terminal", "Terminal", &[]) {
+                ListItem::Application(app) => ListItem::Application(app.with_pinned(true)),
+                item => item,
+            },
+            app("editor", "Editor", &[]),
+        ];
+        let frecency = HashMap::from([
+            ("firefox".to_string(), 1.0),
+            ("files".to_string(), 2.0),
+            ("terminal".to_string(), 3.0),
+        ]);
+
+        // Pinned first, then launched apps by frecency, then clipboard entries
+        let indices = ItemListDelegate::home_indices(&items, &frecency).unwrap();
+        assert_eq!(indices, vec![3, 2, 0, 1]);
+        let info = ItemListDelegate::compute_section_info(&items, &indices, Grouping::Home);
+        assert_eq!(
+            (info.pinned_count, info.recent_count, info.clipboard_count),
+            (1, 2, 1)
+        );
+
+        // Nothing pinned or launched yet falls back to the full list
+        let unused = vec![app("firefox", "Firefox", &[]), items[1].clone()];
+        assert_eq!(
+            ItemListDelegate::home_indices(&unused, &HashMap::new()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_category_filter() {
+        let with_categories = |item: ListItem, categories: &[&str]| match item {
+            ListItem::Application(mut app) => {
+                app.categories = categories.iter().map(|c| c.to_string()).collect();
+                ListItem::Application(app)
+            }
+            item => item,
+        };
+        let items = vec![
+            with_categories(app("code", "Code", &[]), &["Development", "IDE"]),
+            with_categories(app("gimp", "GIMP", &[]), &["Graphics"]),
+            with_categories(app("kate", "Kate", &[]), &["Development"]),
+        ];
+
+        assert_eq!(
+            parse_category_query("@Dev  kat"),
+            Some(("dev".to_string(), "kat".to_string()))
+        );
+        assert_eq!(parse_category_query("dev"), None);
+        assert!(ItemListDelegate::in_category(&items[0], "dev"));
+        assert!(!ItemListDelegate::in_category(&items[1], "dev"));
+
+        // Categories are listed by name with their application count
+        let categories: Vec<(String, Option<String>)> = ItemListDelegate::category_items(&items)
+            .iter()
+            .map(|item| {
+                assert!(ItemListDelegate::is_category_entry(item));
+                (
+                    item.name().to_string(),
+                    item.description().map(str::to_string),
+                )
+            })
+            .collect();
+        assert_eq!(
+            categories,
+            vec![
+                (
+                    "Development".to_string(),
+                    Some("2 applications".to_string())
+                ),
+                ("Graphics".to_string(), Some("1 application".to_string())),
+                ("IDE".to_string(), Some("1 application".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parallel_filter_matches_serial() {
+        let words = [
+            "fire", "fox", "term", "code", "files", "web", "mail", "edit",
+        ];
+        let items: Vec<ListItem> = (0..5000)
+            .map(|i| {
+                let name = format!(
+                    "{} {} {}",
+                    words[i % words.len()],
+                    words[(i / 8) % words.len()],
+                    i
+                );
+                app(&format!("app-{i}"), &name, &[words[(i / 64) % words.len()]])
+            })
+            .collect();
+        let frecency: HashMap<String, f64> = (0..5000)
+            .step_by(7)
+            .map(|i| (format!("app-{i}"), (i % 13) as f64))
+            .collect();
+
+        for query in ["fi", "fox te", "web 12", "mail", "zzz"] {
+            let serial = ItemListDelegate::filter_items_on_threads(
+                &items,
+                query,
+                &frecency,
+                ResultOrder::Types,
+                1,
+            );
+            let parallel = ItemListDelegate::filter_items_on_threads(
+                &items,
+                query,
+                &frecency,
+                ResultOrder::Types,
+                6,
+            );
+            assert_eq!(serial, parallel, "query {query:?}");
+        }
+    }
+
+    #[test]
+    fn test_navigation_skips_collapsed_sections() {
+        let mut delegate = ItemListDelegate::new(vec![
+            app("firefox", "Firefox", &[]),
+            app("files", "Files", &[]),
+        ]);
+        // The built-in commands come before the applications
+        let apps_start = delegate.section_start_index(SectionType::Applications);
+        assert!(apps_start > 0);
+        assert_eq!(delegate.selected_index(), Some(0));
+
+        delegate.toggle_section(SectionType::Commands);
+        assert_eq!(delegate.selected_index(), Some(apps_start));
+        assert_eq!(delegate.global_to_index_path(0), None);
+
+        delegate.select_down();
+        assert_eq!(delegate.selected_index(), Some(apps_start + 1));
+        delegate.select_down();
+        assert_eq!(delegate.selected_index(), Some(apps_start));
+        delegate.select_up();
+        assert_eq!(delegate.selected_index(), Some(apps_start + 1));
+        delegate.select_page_up(10);
+        assert_eq!(delegate.selected_index(), Some(apps_start));
+        delegate.select_first();
+        assert_eq!(delegate.selected_index(), Some(apps_start));
+
+        delegate.toggle_selected_section();
+        delegate.toggle_selected_section();
+        // Everything was collapsed, so the sections are expanded again
+        delegate.select_first();
+        assert_eq!(delegate.selected_index(), Some(0));
+    }
+
+    #[test]
+    fn test_section_result_limit() {
+        let apps = (1..=6)
+            .map(|i| app(&format!("qz{i}"), &format!("Qz App {i}"), &[]))
+            .collect();
+        let mut delegate = ItemListDelegate::new(apps);
+        delegate.section_result_limit = 3;
+        delegate.set_query("qz".to_string());
+
+        let start = delegate.section_start_index(SectionType::Applications);
+        assert!(delegate.is_truncated(SectionType::Applications));
+        assert!(!delegate.is_hidden(start + 2));
+        assert!(delegate.is_hidden(start + 3));
+        assert_eq!(delegate.global_to_index_path(start + 3), None);
+
+        // Navigation skips the results past the limit
+        delegate.set_selected(start + 2);
+        delegate.select_down();
+        assert!(delegate.selected_index() >= Some(start + 6));
+        delegate.select_up();
+        assert_eq!(delegate.selected_index(), Some(start + 2));
+
+        // Only the last shown result expands the section
+        delegate.select_up();
+        assert!(!delegate.expand_selected_section());
+        delegate.select_down();
+        assert!(delegate.expand_selected_section());
+        delegate.select_down();
+        assert_eq!(delegate.selected_index(), Some(start + 3));
+
+        // A new query starts out limited again
+        delegate.set_query("qz ap".to_string());
+        assert!(delegate.is_truncated(SectionType::Applications));
+    }
+
+    #[test]
+    fn test_grid_navigation() {
+        let apps = (1..=7)
+            .map(|i| app(&format!("app{i}"), &format!("App {i}"), &[]))
+            .collect();
+        let mut delegate = ItemListDelegate::new(apps);
+        delegate.grid_columns = 3;
+        delegate.toggle_section(SectionType::Commands);
+        let start = delegate.section_start_index(SectionType::Applications);
+        assert_eq!(delegate.selected_index(), Some(start));
+
+        // Rows of three: [0 1 2] [3 4 5] [6]
+        let row_of = |delegate: &ItemListDelegate, idx| delegate.global_to_index_path(idx).unwrap();
+        assert_eq!(row_of(&delegate, start + 4).row, 1);
+        assert_eq!(row_of(&delegate, start + 6).row, 2);
+
+        delegate.select_right();
+        assert_eq!(delegate.selected_index(), Some(start + 1));
+        delegate.select_down();
+        assert_eq!(delegate.selected_index(), Some(start + 4));
+        // The last row is shorter, so the selection moves to its last item
+        delegate.select_down();
+        assert_eq!(delegate.selected_index(), Some(start + 6));
+        delegate.select_down();
+        assert_eq!(delegate.selected_index(), Some(start));
+        delegate.select_up();
+        assert_eq!(delegate.selected_index(), Some(start + 6));
+        // Left and right wrap between rows
+        delegate.select_left();
+        assert_eq!(delegate.selected_index(), Some(start + 5));
+        delegate.select_right();
+        delegate.select_right();
+        assert_eq!(delegate.selected_index(), Some(start));
+    }
+
+    #[test]
+    fn test_parse_command_query() {
+        assert_eq!(parse_command_query("> htop").as_deref(), Some("htop"));
+        assert_eq!(
+            parse_command_query(">ls -la | less\n").as_deref(),
+            Some("ls -la | less")
+        );
+        assert_eq!(parse_command_query(">  "), None);
+        assert_eq!(parse_command_query("htop"), None);
+    }
+}
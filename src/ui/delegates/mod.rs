@@ -1,11 +1,15 @@
+mod action_delegate;
+mod app_action_delegate;
 mod base;
 mod clipboard_delegate;
 mod emoji_delegate;
 mod item_delegate;
 mod theme_delegate;
 
+pub use action_delegate::ActionListDelegate;
+pub use app_action_delegate::AppActionListDelegate;
 pub use base::BaseDelegate;
-pub use clipboard_delegate::ClipboardListDelegate;
+pub use clipboard_delegate::{ClipboardContentFilter, ClipboardListDelegate};
 pub use emoji_delegate::EmojiGridDelegate;
-pub use item_delegate::ItemListDelegate;
+pub use item_delegate::{CATEGORY_ID_PREFIX, ItemListDelegate};
 pub use theme_delegate::ThemeListDelegate;
@@ -115,6 +115,26 @@ impl ThemeListDelegate {
         self.base.select_up();
     }
 
+    /// Move selection down by a page (clamped)
+    pub fn select_page_down(&mut self, page: usize) {
+        self.base.select_page_down(page);
+    }
+
+    /// Move selection up by a page (clamped)
+    pub fn select_page_up(&mut self, page: usize) {
+        self.base.select_page_up(page);
+    }
+
+    /// Select the first item
+    pub fn select_first(&mut self) {
+        self.base.select_first();
+    }
+
+    /// Select the last item
+    pub fn select_last(&mut self) {
+        self.base.select_last();
+    }
+
     /// Get all items
     pub fn items(&self) -> &[ThemeItem] {
         self.base.items()
@@ -0,0 +1,177 @@
+//! Cache of loaded application and window icons.
+//!
+//! Icons are read and downscaled on a background thread when the launcher
+//! opens, so the first scroll through the list doesn't wait on disk reads and
+//! decoding of large icon files. Entries are keyed by path, so items sharing
+//! an icon share one image, and the oldest entries are dropped once the cache
+//! holds more than `MAX_CACHE_BYTES` of image data.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+/// Length of the longer side of cached raster icons in pixels.
+const ICON_PIXELS: u32 = 64;
+
+/// Upper bound of the image data kept in the cache.
+const MAX_CACHE_BYTES: usize = 16 * 1024 * 1024;
+
+static CACHE: LazyLock<Mutex<IconCache>> = LazyLock::new(|| Mutex::new(IconCache::default()));
+
+/// Whether a prewarm thread is running.
+static PREWARMING: AtomicBool = AtomicBool::new(false);
+
+/// An icon in the cache.
+#[derive(Clone)]
+pub enum CachedIcon {
+    /// The icon, ready to render
+    Loaded(Arc<gpui::Image>),
+    /// The file couldn't be read or decoded
+    Failed,
+}
+
+impl CachedIcon {
+    /// Size of the image data held by the entry.
+    fn size(&self) -> usize {
+        match self {
+            Self::Loaded(image) => image.bytes.len(),
+            Self::Failed => 0,
+        }
+    }
+}
+
+/// Icons by path, with their insertion order for eviction.
+#[derive(Default)]
+struct IconCache {
+    entries: HashMap<PathBuf, CachedIcon>,
+    order: VecDeque<PathBuf>,
+    bytes: usize,
+}
+
+impl IconCache {
+    /// Insert an icon, evicting the oldest ones beyond `max_bytes`.
+    fn insert(&mut self, path: PathBuf, icon: CachedIcon, max_bytes: usize) {
+        self.bytes += icon.size();
+        if let Some(old) = self.entries.insert(path.clone(), icon) {
+            self.bytes -= old.size();
+        } else {
+            self.order.push_back(path);
+        }
+
+        while self.bytes > max_bytes
+            && let Some(oldest) = self.order.pop_front()
+        {
+            if let Some(old) = self.entries.remove(&oldest) {
+                self.bytes -= old.size();
+            }
+        }
+    }
+}
+
+/// Get an icon from the cache, or None if it hasn't been loaded yet.
+pub fn cached_icon(path: &Path) -> Option<CachedIcon> {
+    let cache = CACHE.lock().unwrap();
+    cache.entries.get(path).cloned()
+}
+
+/// Load the given icons into the cache on a background thread.
+///
+/// Icons already in the cache are skipped, and each path is loaded once.
+/// Nothing happens while an earlier prewarm is still running.
+pub fn prewarm_icons(paths: Vec<PathBuf>) {
+    if PREWARMING.swap(true, Ordering::AcqRel) {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        for path in paths {
+            if cached_icon(&path).is_some() {
+                continue;
+            }
+            let icon = load_icon(&path);
+            if matches!(icon, CachedIcon::Failed) {
+                tracing::debug!(?path, "Failed to load icon");
+            }
+            CACHE.lock().unwrap().insert(path, icon, MAX_CACHE_BYTES);
+        }
+        PREWARMING.store(false, Ordering::Release);
+    });
+}
+
+/// Read an icon file, downscaling raster images to `ICON_PIXELS`.
+fn load_icon(path: &Path) -> CachedIcon {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    let image = match ext.as_str() {
+        "svg" => std::fs::read(path)
+            .ok()
+            .map(|bytes| gpui::Image::from_bytes(gpui::ImageFormat::Svg, bytes)),
+        "png" | "jpg" | "jpeg" => load_raster(path),
+        _ => None,
+    };
+
+    match image {
+        Some(image) => CachedIcon::Loaded(Arc::new(image)),
+        None => CachedIcon::Failed,
+    }
+}
+
+/// Decode a raster icon and re-encode it as a PNG of at most `ICON_PIXELS`.
+fn load_raster(path: &Path) -> Option<gpui::Image> {
+    let decoded = image::open(path).ok()?;
+    let decoded = if decoded.width().max(decoded.height()) > ICON_PIXELS {
+        decoded.thumbnail(ICON_PIXELS, ICON_PIXELS)
+    } else {
+        decoded
+    };
+
+    let mut png_bytes = Vec::new();
+    decoded
+        .write_to(
+            &mut std::io::Cursor::new(&mut png_bytes),
+            image::ImageFormat::Png,
+        )
+        .ok()?;
+    Some(gpui::Image::from_bytes(gpui::ImageFormat::Png, png_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icon(size: usize) -> CachedIcon {
+        CachedIcon::Loaded(Arc::new(gpui::Image::from_bytes(
+            gpui::ImageFormat::Png,
+            vec![0; size],
+        )))
+    }
+
+    #[test]
+    fn test_evicts_oldest_beyond_bound() {
+        let mut cache = IconCache::default();
+        cache.insert("a.png".into(), icon(40), 100);
+        cache.insert("b.png".into(), icon(40), 100);
+        cache.insert("c.svg".into(), CachedIcon::Failed, 100);
+        assert_eq!(cache.bytes, 80);
+
+        cache.insert("d.png".into(), icon(40), 100);
+        assert!(!cache.entries.contains_key(Path::new("a.png")));
+        assert!(cache.entries.contains_key(Path::new("b.png")));
+        assert_eq!(cache.bytes, 80);
+        assert_eq!(cache.order.len(), 3);
+    }
+
+    #[test]
+    fn test_replacing_an_entry_keeps_size() {
+        let mut cache = IconCache::default();
+        cache.insert("a.png".into(), icon(40), 100);
+        cache.insert("a.png".into(), icon(10), 100);
+        assert_eq!(cache.bytes, 10);
+        assert_eq!(cache.order.len(), 1);
+    }
+}
@@ -1,20 +1,37 @@
-use crate::clipboard::copy_to_clipboard;
+use crate::clipboard::file_preview::{FileReadState, MAX_FILE_PREVIEW_BYTES, read_file};
+use crate::clipboard::ocr::{OcrState, recognize_text};
+use crate::clipboard::{ClipboardContent, ClipboardItem, copy_to_clipboard, copy_to_selection};
 use crate::compositor::Compositor;
+use crate::config::{ConfigModule, WindowAnchor};
 use crate::desktop::launch_application;
-use crate::items::{Executable, ListItem};
-use crate::ui::delegates::ItemListDelegate;
+use crate::ipc::LaunchMode;
+use crate::items::{ActionItem, ApplicationItem, Executable, ListItem, SubmenuItem};
+use crate::search::{
+    FileSearchProvider, StreamingProvider, open_url, reveal_paths, spawn_providers,
+};
+use crate::ui::delegates::{CATEGORY_ID_PREFIX, ClipboardContentFilter, ItemListDelegate};
 use crate::ui::modes::{
-    AiModeAccess, AiModeHandler, ClipboardModeHandler, EmojiModeHandler, ThemeModeHandler,
+    ActionMenuModeHandler, AiModeAccess, AiModeHandler, AppActionsModeHandler,
+    ClipboardModeHandler, EmojiModeHandler, ThemeModeHandler,
 };
 use crate::ui::theme::LauncherTheme;
+use crate::ui::utils::parse_url;
+use crate::ui::views::clipboard_rendering::{preview_file_path, read_text_file};
+use crate::ui::views::render_inspector;
 use gpui::{
-    App, Context, Entity, FocusHandle, Focusable, KeyBinding, Length, ScrollStrategy, Window,
-    actions, div, image_cache, prelude::*, px, retain_all,
+    Action, App, AsyncApp, AsyncWindowContext, Context, DummyKeyboardMapper, Entity, FocusHandle,
+    Focusable, Hsla, KeyBinding, KeyBindingContextPredicate, KeyContext, KeybindingKeystroke,
+    Length, Pixels, ScrollStrategy, SharedString, Task, WeakEntity, Window, actions, div,
+    image_cache, prelude::*, px, retain_all,
 };
 use gpui_component::input::InputState;
 use gpui_component::list::{List, ListState};
 use gpui_component::{ActiveTheme, Icon, IconName, IndexPath};
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 actions!(
     launcher,
@@ -24,11 +41,82 @@ actions!(
         SelectTab,
         SelectTabPrev,
         Confirm,
+        PasteFormatted,
         Cancel,
-        GoBack
+        GoBack,
+        CycleClipboardSource,
+        TogglePin,
+        EditTags,
+        DeleteItem,
+        ClearClipboard,
+        OpenUrl,
+        RevealInFolder,
+        ToggleQrCode,
+        CopyFileContents,
+        SaveImage,
+        RecognizeText,
+        ScrollPreviewUp,
+        ScrollPreviewDown,
+        FilterAll,
+        FilterText,
+        FilterImages,
+        FilterFiles,
+        FilterColors,
+        PageUp,
+        PageDown,
+        SelectFirst,
+        SelectLast,
+        CloseWindow,
+        ToggleWindowScope,
+        ToggleSection,
+        ExpandSections,
+        ExpandResults,
+        SelectLeft,
+        SelectRight,
+        CycleCalculatorFormat,
+        ToggleSticky,
+        ShowAppActions,
+        CycleSkinTone,
+        ToggleInspector
     ]
 );
 
+/// Number of list items skipped by Page Up / Page Down.
+const PAGE_SIZE: usize = 8;
+
+/// Number of emoji grid rows skipped by Page Up / Page Down.
+const EMOJI_PAGE_ROWS: usize = 4;
+
+/// Distance Alt+Up / Alt+Down scroll the clipboard preview.
+const PREVIEW_SCROLL_STEP: Pixels = px(60.0);
+
+/// How long the "Copied!" toast shows before the launcher hides.
+const TOAST_DURATION: Duration = Duration::from_millis(600);
+
+/// How long status messages (e.g. the path of a saved image) are shown.
+const STATUS_DURATION: Duration = Duration::from_secs(4);
+
+/// Typing pause before the streaming providers search the query.
+const STREAM_DEBOUNCE: Duration = Duration::from_millis(80);
+
+/// Last query typed in the main view.
+///
+/// The launcher view is recreated every time the window is shown, so the
+/// query outlives it here when `remember_last_query` is enabled.
+static LAST_QUERY: Mutex<String> = Mutex::new(String::new());
+
+/// Whether sticky mode is on, kept across launcher openings like the query.
+static STICKY: AtomicBool = AtomicBool::new(false);
+
+/// A selection jump that skips over several items at once.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SelectionJump {
+    PageUp,
+    PageDown,
+    First,
+    Last,
+}
+
 /// The current view mode of the launcher.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum ViewMode {
@@ -43,18 +131,157 @@ pub enum ViewMode {
     AiResponse,
     /// Theme picker view.
     ThemePicker,
+    /// Desktop actions of an application.
+    AppActions,
+    /// Actions grouped under a submenu (e.g. the power menu).
+    ActionMenu,
+}
+
+/// Get the short label of a key for the footer hints, e.g. "↵" or "ctrl+p".
+fn key_label(keystroke: &KeybindingKeystroke) -> String {
+    let modifiers = keystroke.modifiers();
+    let mut label = String::new();
+    for (pressed, name) in [
+        (modifiers.control, "ctrl+"),
+        (modifiers.alt, "alt+"),
+        (modifiers.shift, "shift+"),
+    ] {
+        if pressed {
+            label.push_str(name);
+        }
+    }
+    label.push_str(match keystroke.key() {
+        "enter" => "↵",
+        "backspace" => "⌫",
+        "escape" => "esc",
+        "delete" => "del",
+        "up" => "↑",
+        "down" => "↓",
+        key => key,
+    });
+    label
+}
+
+/// Get the streaming providers of the enabled modules.
+fn default_streaming_providers() -> Vec<Arc<dyn StreamingProvider>> {
+    let disabled_modules = crate::config::config().disabled_modules.unwrap_or_default();
+    let mut providers: Vec<Arc<dyn StreamingProvider>> = Vec::new();
+    if !disabled_modules.contains(&ConfigModule::Files) {
+        providers.push(Arc::new(FileSearchProvider::new()));
+    }
+    providers
 }
 
 pub fn init(cx: &mut App) {
-    cx.bind_keys([
+    let mut bindings = vec![
         KeyBinding::new("up", SelectPrev, Some("LauncherView")),
         KeyBinding::new("down", SelectNext, Some("LauncherView")),
+        KeyBinding::new("pageup", PageUp, Some("LauncherView")),
+        KeyBinding::new("pagedown", PageDown, Some("LauncherView")),
+        KeyBinding::new("home", SelectFirst, Some("LauncherView")),
+        KeyBinding::new("end", SelectLast, Some("LauncherView")),
         KeyBinding::new("tab", SelectTab, Some("LauncherView")),
         KeyBinding::new("shift-tab", SelectTabPrev, Some("LauncherView")),
         KeyBinding::new("enter", Confirm, Some("LauncherView")),
+        KeyBinding::new("ctrl-enter", PasteFormatted, Some("LauncherView")),
         KeyBinding::new("escape", Cancel, Some("LauncherView")),
         KeyBinding::new("backspace", GoBack, Some("LauncherView")),
-    ]);
+        KeyBinding::new("alt-s", CycleClipboardSource, Some("LauncherView")),
+        KeyBinding::new("ctrl-p", TogglePin, Some("LauncherView")),
+        KeyBinding::new("alt-g", EditTags, Some("LauncherView")),
+        KeyBinding::new("delete", DeleteItem, Some("LauncherView")),
+        KeyBinding::new("ctrl-shift-delete", ClearClipboard, Some("LauncherView")),
+        KeyBinding::new("ctrl-o", OpenUrl, Some("LauncherView")),
+        KeyBinding::new("alt-o", RevealInFolder, Some("LauncherView")),
+        KeyBinding::new("alt-q", ToggleQrCode, Some("LauncherView")),
+        KeyBinding::new("alt-c", CopyFileContents, Some("LauncherView")),
+        KeyBinding::new("ctrl-shift-s", SaveImage, Some("LauncherView")),
+        KeyBinding::new("alt-t", RecognizeText, Some("LauncherView")),
+        KeyBinding::new("alt-up", ScrollPreviewUp, Some("LauncherView")),
+        KeyBinding::new("alt-down", ScrollPreviewDown, Some("LauncherView")),
+        KeyBinding::new("ctrl-w", CloseWindow, Some("LauncherView")),
+        KeyBinding::new("alt-w", ToggleWindowScope, Some("LauncherView")),
+        KeyBinding::new("alt-h", ToggleSection, Some("LauncherView")),
+        KeyBinding::new("alt-shift-h", ExpandSections, Some("LauncherView")),
+        // Bound in the search input so they're tried before moving the cursor
+        // (the handlers pass the key on when they don't apply)
+        KeyBinding::new("left", SelectLeft, Some("LauncherView > Input")),
+        KeyBinding::new("right", SelectRight, Some("LauncherView > Input")),
+        KeyBinding::new("right", ExpandResults, Some("LauncherView > Input")),
+        KeyBinding::new("alt-f", CycleCalculatorFormat, Some("LauncherView")),
+        KeyBinding::new("ctrl-s", ToggleSticky, Some("LauncherView")),
+        KeyBinding::new("alt-enter", ShowAppActions, Some("LauncherView")),
+        KeyBinding::new("ctrl-t", CycleSkinTone, Some("LauncherView")),
+        KeyBinding::new("ctrl-shift-i", ToggleInspector, Some("LauncherView")),
+        KeyBinding::new("alt-1", FilterAll, Some("LauncherView")),
+        KeyBinding::new("alt-2", FilterText, Some("LauncherView")),
+        KeyBinding::new("alt-3", FilterImages, Some("LauncherView")),
+        KeyBinding::new("alt-4", FilterFiles, Some("LauncherView")),
+        KeyBinding::new("alt-5", FilterColors, Some("LauncherView")),
+    ];
+
+    if crate::config::config().vim_navigation {
+        bindings.extend([
+            KeyBinding::new("ctrl-j", SelectNext, Some("LauncherView")),
+            KeyBinding::new("ctrl-k", SelectPrev, Some("LauncherView")),
+        ]);
+    }
+
+    let custom = crate::config::config().keybindings.unwrap_or_default();
+    if !custom.is_empty() {
+        bindings = apply_custom_bindings(bindings, &custom, cx);
+    }
+    cx.bind_keys(bindings);
+}
+
+/// Replace the default bindings of the actions set in the `keybindings`
+/// config with the configured key chords.
+///
+/// Unknown action names and invalid chords are logged and skipped.
+fn apply_custom_bindings(
+    defaults: Vec<KeyBinding>,
+    custom: &HashMap<String, Vec<String>>,
+    cx: &App,
+) -> Vec<KeyBinding> {
+    let is_custom = |binding: &KeyBinding| {
+        binding
+            .action()
+            .name()
+            .strip_prefix("launcher::")
+            .is_some_and(|name| custom.contains_key(name))
+    };
+    let mut bindings: Vec<KeyBinding> = defaults.into_iter().filter(|b| !is_custom(b)).collect();
+
+    let context = KeyBindingContextPredicate::parse("LauncherView")
+        .ok()
+        .map(Rc::new);
+    for (name, chords) in custom {
+        let action = match cx.build_action(&format!("launcher::{}", name), None) {
+            Ok(action) => action,
+            Err(e) => {
+                tracing::warn!(%e, "Unknown action {:?} in keybindings", name);
+                continue;
+            }
+        };
+        for chord in chords {
+            if chord.trim().is_empty() {
+                tracing::warn!("Ignoring empty key binding for {}", name);
+                continue;
+            }
+            match KeyBinding::load(
+                chord,
+                action.boxed_clone(),
+                context.clone(),
+                false,
+                None,
+                &DummyKeyboardMapper,
+            ) {
+                Ok(binding) => bindings.push(binding),
+                Err(e) => tracing::warn!(%e, "Ignoring key binding {:?} for {}", chord, name),
+            }
+        }
+    }
+    bindings
 }
 
 /// The main launcher view.
@@ -71,6 +298,37 @@ pub struct LauncherView {
     ai_mode_handler: Option<AiModeHandler>,
     /// Theme mode handler (created on demand)
     theme_mode_handler: Option<ThemeModeHandler>,
+    /// Desktop actions mode handler (created on demand)
+    app_actions_mode_handler: Option<AppActionsModeHandler>,
+    /// Action menu mode handler (created on demand)
+    action_menu_mode_handler: Option<ActionMenuModeHandler>,
+    /// Action waiting for a second Enter before it runs
+    pending_action: Option<ActionItem>,
+    /// Notified by the copy callbacks, which may run outside of the view
+    /// (e.g. on a click in a list)
+    copied_tx: flume::Sender<()>,
+    /// Task showing the toast when something was copied
+    _copied_task: Task<()>,
+    /// Notified by the confirm callbacks with actions that need confirmation
+    confirm_tx: flume::Sender<ActionItem>,
+    /// Task asking for the confirmation of those actions
+    _confirm_task: Task<()>,
+    /// Whether the "Copied!" toast is shown
+    copied_toast_visible: bool,
+    /// Task hiding the toast (and the launcher) after `TOAST_DURATION`
+    toast_task: Option<Task<()>>,
+    /// Status message shown at the bottom of the panel, and whether it's an error
+    status_message: Option<(SharedString, bool)>,
+    /// Task hiding the status message after `STATUS_DURATION`
+    status_task: Option<Task<()>>,
+    /// Whether the clipboard preview shows the QR code of the selected item
+    show_qr_code: bool,
+    /// Show the raw metadata of the selected item next to the main list
+    show_inspector: bool,
+    /// Text recognition of a clipboard image, with the id of the item
+    ocr: Option<(u64, OcrState)>,
+    /// Text file shown in the clipboard preview, with the id of the item
+    file_preview: Option<(u64, FileReadState)>,
     /// Current theme (for live preview)
     current_theme: LauncherTheme,
     /// Theme preview subscription
@@ -81,6 +339,15 @@ pub struct LauncherView {
     focus_handle: FocusHandle,
     /// Callback to hide the launcher
     on_hide: Arc<dyn Fn() + Send + Sync>,
+    /// Providers whose results stream into the main list
+    streaming_providers: Vec<Arc<dyn StreamingProvider>>,
+    /// Task merging streamed batches into the list
+    /// (replaced on every query change, which drops the previous one)
+    stream_task: Option<Task<()>>,
+    /// Remember the main query across launcher openings
+    remember_query: bool,
+    /// Compositor used to close windows from the list
+    compositor: Arc<dyn Compositor>,
 }
 
 impl LauncherView {
@@ -94,14 +361,40 @@ impl LauncherView {
     ) -> Self {
         let on_hide = Arc::new(on_hide);
 
+        // Load the icons in the background before the list is first scrolled
+        crate::ui::icon_cache::prewarm_icons(
+            items
+                .iter()
+                .filter_map(|item| match item {
+                    ListItem::Application(app) => app.icon_path.clone(),
+                    ListItem::Window(win) => win.icon_path.clone(),
+                    _ => None,
+                })
+                .collect(),
+        );
+
         // Create main delegate with callbacks
         let mut delegate = ItemListDelegate::new(items);
         let on_hide_for_confirm = on_hide.clone();
         let compositor_for_confirm = compositor.clone();
+        let (copied_tx, copied_rx) = flume::unbounded::<()>();
+        let copied_tx_for_confirm = copied_tx.clone();
+        let (confirm_tx, confirm_rx) = flume::unbounded::<ActionItem>();
+        let confirm_tx_for_confirm = confirm_tx.clone();
 
         delegate.set_on_confirm(move |item| {
+            if let ListItem::Action(action) = item
+                && Self::defer_for_confirmation(action, &confirm_tx_for_confirm)
+            {
+                return;
+            }
             Self::handle_item_confirm(item, &compositor_for_confirm);
-            on_hide_for_confirm();
+            // Copies hide after the toast instead
+            if matches!(item, ListItem::Calculator(_) | ListItem::Snippet(_)) {
+                let _ = copied_tx_for_confirm.send(());
+            } else {
+                on_hide_for_confirm();
+            }
         });
 
         let on_hide_for_cancel = on_hide.clone();
@@ -115,18 +408,50 @@ impl LauncherView {
 
         // Subscribe to input changes
         let list_state_for_subscribe = list_state.clone();
-        cx.subscribe(&input_state, move |_this, input, event, cx| {
+        cx.subscribe(&input_state, move |this, input, event, cx| {
             if let gpui_component::input::InputEvent::Change = event {
                 let text = input.read(cx).value().to_string();
                 // Update the delegate's query directly (synchronous filtering)
                 list_state_for_subscribe.update(cx, |state, cx| {
-                    state.delegate_mut().set_query(text);
+                    state.delegate_mut().set_query(text.clone());
                     cx.notify();
                 });
+                // Slow providers stream their results in afterwards
+                if this.view_mode == ViewMode::Main {
+                    if this.remember_query {
+                        *LAST_QUERY.lock().unwrap() = text.clone();
+                    }
+                    this.start_streaming(&text, cx);
+                }
             }
         })
         .detach();
 
+        let copied_task = cx.spawn_in(
+            window,
+            async move |this: WeakEntity<Self>, cx: &mut AsyncWindowContext| {
+                while copied_rx.recv_async().await.is_ok() {
+                    if this
+                        .update_in(cx, |this, window, cx| this.show_copied_toast(window, cx))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            },
+        );
+
+        let confirm_task = cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            while let Ok(action) = confirm_rx.recv_async().await {
+                if this
+                    .update(cx, |this, cx| this.request_confirmation(action, cx))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+
         let focus_handle = cx.focus_handle();
 
         // Hide when the view loses focus
@@ -143,11 +468,114 @@ impl LauncherView {
             clipboard_mode_handler: None,
             ai_mode_handler: None,
             theme_mode_handler: None,
+            app_actions_mode_handler: None,
+            action_menu_mode_handler: None,
+            pending_action: None,
+            copied_tx,
+            _copied_task: copied_task,
+            confirm_tx,
+            _confirm_task: confirm_task,
+            copied_toast_visible: false,
+            toast_task: None,
+            status_message: None,
+            status_task: None,
+            show_qr_code: false,
+            show_inspector: false,
+            ocr: None,
+            file_preview: None,
             current_theme: crate::config::load_configured_theme(),
             _theme_preview_subscription: None,
             input_state,
             focus_handle,
             on_hide,
+            streaming_providers: default_streaming_providers(),
+            stream_task: None,
+            remember_query: crate::config::config().remember_last_query,
+            compositor,
+        }
+    }
+
+    /// Register a provider whose results stream into the main list.
+    pub fn add_streaming_provider(&mut self, provider: Arc<dyn StreamingProvider>) {
+        self.streaming_providers.push(provider);
+    }
+
+    /// Start the streaming providers for a query once typing pauses.
+    ///
+    /// Any in-flight stream is dropped, including one still waiting out the
+    /// debounce, so only the latest query reaches the providers. Batches that
+    /// still arrive for an older query are discarded by the delegate's
+    /// generation check. The regular items are filtered synchronously on every
+    /// keystroke and aren't delayed.
+    fn start_streaming(&mut self, query: &str, cx: &mut Context<Self>) {
+        self.stream_task = None;
+        // Category filters (`@dev`) only list applications
+        if self.list_state.read(cx).delegate().filters_by_category()
+            || !self
+                .streaming_providers
+                .iter()
+                .any(|provider| provider.handles(query))
+        {
+            return;
+        }
+
+        let (generation, generation_handle) = {
+            let delegate = self.list_state.read(cx).delegate();
+            (delegate.generation(), delegate.generation_handle())
+        };
+        let providers = self.streaming_providers.clone();
+        let query = query.to_string();
+        let list_state = self.list_state.clone();
+        self.stream_task = Some(cx.spawn(
+            async move |_this: WeakEntity<Self>, cx: &mut AsyncApp| {
+                cx.background_executor().timer(STREAM_DEBOUNCE).await;
+                let Some(rx) = spawn_providers(&providers, &query, generation, generation_handle)
+                else {
+                    return;
+                };
+
+                while let Ok(batch) = rx.recv_async().await {
+                    let result = cx.update(|cx| {
+                        list_state.update(cx, |state, cx| {
+                            if state.delegate_mut().push_batch(batch) {
+                                cx.notify();
+                            }
+                        });
+                    });
+                    if result.is_err() {
+                        break;
+                    }
+                }
+            },
+        ));
+    }
+
+    /// Open the view requested on the command line.
+    ///
+    /// Only switches from the main view, and not to modules disabled in the
+    /// config.
+    pub fn open_mode(&mut self, mode: LaunchMode, window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::Main {
+            return;
+        }
+        self.pending_action = None;
+        let module = match mode {
+            LaunchMode::Launcher => return,
+            LaunchMode::Clipboard => ConfigModule::Clipboard,
+            LaunchMode::Emoji => ConfigModule::Emojis,
+        };
+        if crate::config::config()
+            .disabled_modules
+            .is_some_and(|disabled| disabled.contains(&module))
+        {
+            tracing::warn!(?mode, "Can't open a disabled module");
+            return;
+        }
+
+        match mode {
+            LaunchMode::Launcher => {}
+            LaunchMode::Clipboard => self.enter_clipboard_mode(window, cx),
+            LaunchMode::Emoji => self.enter_emoji_mode(window, cx),
         }
     }
 
@@ -155,27 +583,34 @@ impl LauncherView {
     /// Called when the theme is changed via IPC while the window is open.
     pub fn refresh_theme(&mut self, cx: &mut Context<Self>) {
         self.current_theme = crate::ui::theme::theme();
+        crate::ui::theme::apply_component_theme(cx);
         cx.notify();
     }
 
+    /// Launch an application item, counting the launch for `frecency_id`.
+    fn launch_app(app: &ApplicationItem, frecency_id: &str) {
+        // Convert to DesktopEntry and launch
+        let entry = crate::desktop::DesktopEntry::new(
+            app.id.clone(),
+            app.name.clone(),
+            app.exec.clone(),
+            None,
+            app.icon_path.clone(),
+            app.description.clone(),
+            vec![],
+            app.terminal,
+            app.desktop_path.clone(),
+        );
+        match launch_application(&entry) {
+            Ok(()) => crate::desktop::frecency::record_launch(frecency_id),
+            Err(e) => tracing::warn!(%e, "Failed to launch application"),
+        }
+    }
+
     /// Handle confirming an item.
     fn handle_item_confirm(item: &ListItem, compositor: &Arc<dyn Compositor>) {
         match item {
-            ListItem::Application(app) => {
-                // Convert to DesktopEntry and launch
-                let entry = crate::desktop::DesktopEntry::new(
-                    app.id.clone(),
-                    app.name.clone(),
-                    app.exec.clone(),
-                    None,
-                    app.icon_path.clone(),
-                    app.description.clone(),
-                    vec![],
-                    app.terminal,
-                    app.desktop_path.clone(),
-                );
-                let _ = launch_application(&entry);
-            }
+            ListItem::Application(app) => Self::launch_app(app, &app.id),
             ListItem::Window(win) => {
                 if let Err(e) = compositor.focus_window(&win.address) {
                     tracing::warn!(%e, "Failed to focus window");
@@ -185,6 +620,7 @@ impl LauncherView {
                 if let Err(e) = copy_to_clipboard(calc.text_for_clipboard()) {
                     tracing::warn!(%e, "Failed to copy to clipboard");
                 }
+                crate::calculator::remember_answer(calc);
             }
             ListItem::Action(act) => {
                 if let Err(e) = act.execute() {
@@ -196,6 +632,16 @@ impl LauncherView {
                     tracing::warn!(%e, "Failed to open search URL");
                 }
             }
+            ListItem::Snippet(snippet) => {
+                if let Err(e) = snippet.execute() {
+                    tracing::warn!(%e, "Failed to copy snippet");
+                }
+            }
+            ListItem::File(file) => {
+                if let Err(e) = file.execute() {
+                    tracing::warn!(%e, "Failed to open file");
+                }
+            }
             ListItem::Submenu(submenu) => {
                 // Submenu items are handled separately (e.g., enter_emoji_mode)
                 tracing::debug!(id = %submenu.id, "Submenu selected");
@@ -212,14 +658,38 @@ impl LauncherView {
     }
 
     /// Focus the launcher input.
-    pub fn focus(&self, window: &mut Window, cx: &mut Context<Self>) {
+    ///
+    /// When the last query is remembered it is restored here, selected so
+    /// that typing replaces it.
+    pub fn focus(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         self.input_state.update(cx, |input: &mut InputState, cx| {
             input.focus(window, cx);
         });
+
+        let last_query = LAST_QUERY.lock().unwrap().clone();
+        if self.remember_query && !last_query.is_empty() {
+            self.input_state.update(cx, |input, cx| {
+                input.set_value(last_query.clone(), window, cx);
+            });
+            self.list_state.update(cx, |state, cx| {
+                state.delegate_mut().set_query(last_query.clone());
+                cx.notify();
+            });
+            self.start_streaming(&last_query, cx);
+            window.dispatch_action(Box::new(gpui_component::input::SelectAll), cx);
+        }
     }
 
     /// Reset search to empty state.
+    ///
+    /// Used when returning from a sub-view, so the remembered query is
+    /// dropped as well.
     pub fn reset_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.stream_task = None;
+        self.pending_action = None;
+        if self.remember_query {
+            LAST_QUERY.lock().unwrap().clear();
+        }
         self.list_state.update(cx, |list_state, _cx| {
             list_state.delegate_mut().clear_query();
         });
@@ -231,7 +701,7 @@ impl LauncherView {
     /// Enter emoji picker mode.
     fn enter_emoji_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         // Create emoji mode handler
-        let handler = EmojiModeHandler::new(&self.input_state, self.on_hide.clone(), window, cx);
+        let handler = EmojiModeHandler::new(&self.input_state, self.on_copied(), window, cx);
 
         // Update input
         self.input_state.update(cx, |input, cx| {
@@ -258,8 +728,7 @@ impl LauncherView {
     /// Enter clipboard history mode.
     fn enter_clipboard_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         // Create clipboard mode handler
-        let handler =
-            ClipboardModeHandler::new(&self.input_state, self.on_hide.clone(), window, cx);
+        let handler = ClipboardModeHandler::new(&self.input_state, self.on_copied(), window, cx);
 
         // Update input
         self.input_state.update(cx, |input, cx| {
@@ -268,6 +737,8 @@ impl LauncherView {
 
         self.clipboard_mode_handler = Some(handler);
         self.view_mode = ViewMode::ClipboardHistory;
+        self.show_qr_code = false;
+        self.file_preview = None;
         cx.notify();
     }
 
@@ -275,6 +746,7 @@ impl LauncherView {
     fn exit_clipboard_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         self.view_mode = ViewMode::Main;
         self.clipboard_mode_handler = None;
+        crate::ui::views::clipboard_rendering::release_image_previews(cx);
 
         self.reset_search(window, cx);
         self.input_state.update(cx, |input, cx| {
@@ -386,12 +858,16 @@ impl LauncherView {
             // Update current_theme when selection changes
             list_state.update(cx, |state, _cx| {
                 if let Some(selected_item) = state.delegate().selected_item() {
-                    let new_theme = selected_item.theme.clone();
+                    let new_theme = selected_item
+                        .theme
+                        .clone()
+                        .scaled(crate::config::font_scale());
                     launcher.current_theme = new_theme.clone();
                     // Update global theme for live preview
                     crate::ui::theme::set_theme(new_theme);
                 }
             });
+            crate::ui::theme::apply_component_theme(cx);
             cx.notify();
         });
 
@@ -414,6 +890,7 @@ impl LauncherView {
         // Reload the configured theme and update the global cache
         crate::ui::theme::sync_theme_from_config();
         self.current_theme = crate::ui::theme::theme();
+        crate::ui::theme::apply_component_theme(cx);
 
         self.reset_search(window, cx);
         self.input_state.update(cx, |input, cx| {
@@ -422,12 +899,342 @@ impl LauncherView {
         cx.notify();
     }
 
+    /// Show the desktop actions of the selected application.
+    fn show_app_actions(
+        &mut self,
+        _: &ShowAppActions,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let selected = if self.view_mode == ViewMode::Main {
+            let delegate = self.list_state.read(cx).delegate();
+            delegate
+                .selected_index()
+                .and_then(|idx| delegate.get_item_at(idx))
+        } else {
+            None
+        };
+        let Some(ListItem::Application(app)) = selected else {
+            cx.propagate();
+            return;
+        };
+        if app.actions.is_empty() {
+            return;
+        }
+
+        let on_hide = self.on_hide.clone();
+        let app_id = app.id.clone();
+        let on_confirm = Arc::new(move |action: &ApplicationItem| {
+            // Launches through an action still count for the application
+            Self::launch_app(action, &app_id);
+            on_hide();
+        });
+        let handler = AppActionsModeHandler::new(&self.input_state, &app, on_confirm, window, cx);
+
+        self.input_state.update(cx, |input, cx| {
+            AppActionsModeHandler::setup_input(input, &app.name, window, cx);
+        });
+
+        self.app_actions_mode_handler = Some(handler);
+        self.view_mode = ViewMode::AppActions;
+        cx.notify();
+    }
+
+    /// Exit desktop actions mode.
+    fn exit_app_actions_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.view_mode = ViewMode::Main;
+        self.app_actions_mode_handler = None;
+
+        self.reset_search(window, cx);
+        self.input_state.update(cx, |input, cx| {
+            AppActionsModeHandler::restore_input(input, window, cx);
+        });
+        cx.notify();
+    }
+
+    /// Enter a submenu listing the given actions.
+    fn enter_action_menu_mode(
+        &mut self,
+        submenu: &SubmenuItem,
+        actions: Vec<ActionItem>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let on_hide = self.on_hide.clone();
+        let confirm_tx = self.confirm_tx.clone();
+        let on_confirm = Arc::new(move |action: &ActionItem| {
+            if Self::defer_for_confirmation(action, &confirm_tx) {
+                return;
+            }
+            if let Err(e) = action.execute() {
+                tracing::warn!(%e, "Failed to execute action");
+            }
+            on_hide();
+        });
+        let handler =
+            ActionMenuModeHandler::new(&self.input_state, actions, on_confirm, window, cx);
+
+        self.input_state.update(cx, |input, cx| {
+            ActionMenuModeHandler::setup_input(input, submenu, window, cx);
+        });
+
+        self.action_menu_mode_handler = Some(handler);
+        self.view_mode = ViewMode::ActionMenu;
+        cx.notify();
+    }
+
+    /// Exit action menu mode.
+    fn exit_action_menu_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.view_mode = ViewMode::Main;
+        self.action_menu_mode_handler = None;
+
+        self.reset_search(window, cx);
+        self.input_state.update(cx, |input, cx| {
+            ActionMenuModeHandler::restore_input(input, window, cx);
+        });
+        cx.notify();
+    }
+
+    /// Hand an action that needs confirmation to the view instead of running
+    /// it, returning whether it was handed over.
+    ///
+    /// Every confirm callback checks this first, so clicking an item can't
+    /// skip the confirmation either.
+    fn defer_for_confirmation(action: &ActionItem, confirm_tx: &flume::Sender<ActionItem>) -> bool {
+        if !action.requires_confirmation {
+            return false;
+        }
+        let _ = confirm_tx.send(action.clone());
+        true
+    }
+
+    /// Ask for confirmation before running an action.
+    fn request_confirmation(&mut self, action: ActionItem, cx: &mut Context<Self>) {
+        self.pending_action = Some(action);
+        cx.notify();
+    }
+
+    /// Run the action waiting for confirmation, if any.
+    ///
+    /// Returns whether there was one.
+    fn run_pending_action(&mut self, cx: &mut Context<Self>) -> bool {
+        let Some(action) = self.pending_action.take() else {
+            return false;
+        };
+        if let Err(e) = action.execute() {
+            tracing::warn!(%e, "Failed to execute action");
+        }
+        (self.on_hide)();
+        cx.notify();
+        true
+    }
+
+    /// Callback for the copy paths, showing the toast.
+    fn on_copied(&self) -> Arc<dyn Fn() + Send + Sync> {
+        let copied_tx = self.copied_tx.clone();
+        Arc::new(move || {
+            let _ = copied_tx.send(());
+        })
+    }
+
+    /// Show the "Copied!" toast.
+    ///
+    /// The launcher hides once the toast is gone, unless
+    /// `stay_open_after_copy` is set or sticky mode is on. Sticky mode clears
+    /// the query for the next pick.
+    fn show_copied_toast(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.copied_toast_visible = true;
+        let sticky = self.is_sticky();
+        if sticky {
+            self.input_state.update(cx, |input, cx| {
+                input.set_value("", window, cx);
+            });
+        }
+        let stay_open = sticky || crate::config::config().stay_open_after_copy;
+        let on_hide = self.on_hide.clone();
+        self.toast_task = Some(
+            cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+                cx.background_executor().timer(TOAST_DURATION).await;
+                let _ = this.update(cx, |this, cx| {
+                    this.copied_toast_visible = false;
+                    cx.notify();
+                });
+                if !stay_open {
+                    on_hide();
+                }
+            }),
+        );
+        cx.notify();
+    }
+
+    /// Show a message at the bottom of the panel for `STATUS_DURATION`.
+    ///
+    /// Unlike the "Copied!" toast this keeps the launcher open.
+    fn show_status(&mut self, message: String, is_error: bool, cx: &mut Context<Self>) {
+        self.status_message = Some((message.into(), is_error));
+        self.status_task = Some(cx.spawn(
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+                cx.background_executor().timer(STATUS_DURATION).await;
+                let _ = this.update(cx, |this, cx| {
+                    this.status_message = None;
+                    cx.notify();
+                });
+            },
+        ));
+        cx.notify();
+    }
+
+    /// Whether copying keeps the launcher open in the current view.
+    ///
+    /// Sticky mode applies to the emoji picker and clipboard history only.
+    fn is_sticky(&self) -> bool {
+        crate::config::config().sticky_mode
+            && STICKY.load(Ordering::Relaxed)
+            && matches!(
+                self.view_mode,
+                ViewMode::EmojiPicker | ViewMode::ClipboardHistory
+            )
+    }
+
+    /// Turn sticky mode on or off in the emoji picker and clipboard history.
+    fn toggle_sticky(&mut self, _: &ToggleSticky, _window: &mut Window, cx: &mut Context<Self>) {
+        if !crate::config::config().sticky_mode
+            || !matches!(
+                self.view_mode,
+                ViewMode::EmojiPicker | ViewMode::ClipboardHistory
+            )
+        {
+            cx.propagate();
+            return;
+        }
+        STICKY.fetch_xor(true, Ordering::Relaxed);
+        cx.notify();
+    }
+
+    /// Render a toast at the bottom of the panel.
+    fn render_toast(&self, message: SharedString, color: Hsla) -> impl IntoElement {
+        let theme = &self.current_theme;
+        div()
+            .absolute()
+            .bottom_4()
+            .left_0()
+            .right_0()
+            .flex()
+            .justify_center()
+            .child(
+                div()
+                    .px_3()
+                    .py_1()
+                    .rounded(theme.item_border_radius)
+                    .bg(theme.window_background.alpha(1.0))
+                    .border_1()
+                    .border_color(theme.window_border)
+                    .text_sm()
+                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                    .text_color(color)
+                    .child(message),
+            )
+    }
+
+    /// Render the inline prompt confirming the pending action.
+    fn render_confirmation(&self, action: &ActionItem) -> impl IntoElement {
+        let theme = &self.current_theme;
+        div()
+            .flex_1()
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .gap_1()
+            .child(
+                div()
+                    .text_size(theme.item_title_font_size)
+                    .text_color(theme.item_title_color)
+                    .child(action.confirmation_prompt()),
+            )
+            .child(
+                div()
+                    .text_size(theme.item_description_font_size)
+                    .text_color(theme.item_description_color)
+                    .child("Enter to confirm, Esc to cancel"),
+            )
+    }
+
     /// Render clipboard preview panel.
     fn render_clipboard_preview(
         &self,
         item: Option<&crate::clipboard::ClipboardItem>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
     ) -> impl IntoElement {
-        crate::ui::views::clipboard_rendering::render_preview_panel(item)
+        let line_range = self
+            .clipboard_mode_handler
+            .as_ref()
+            .and_then(|h| h.list_state().read(cx).delegate().line_range());
+        let ocr = self
+            .ocr
+            .as_ref()
+            .filter(|(id, _)| item.is_some_and(|item| item.id == *id))
+            .map(|(_, state)| state);
+        let file = self
+            .file_preview
+            .as_ref()
+            .filter(|(id, _)| item.is_some_and(|item| item.id == *id))
+            .map(|(_, state)| state);
+        crate::ui::views::clipboard_rendering::render_preview_panel(
+            item,
+            self.show_qr_code,
+            line_range,
+            ocr,
+            file,
+            window,
+            cx,
+        )
+    }
+
+    /// Start reading the text file shown in the preview of the selected
+    /// clipboard item, unless it's already read.
+    ///
+    /// The file is read in the background so a slow disk or network
+    /// filesystem doesn't stall navigating the history.
+    fn load_file_preview(&mut self, cx: &mut Context<Self>) {
+        let selected = self.clipboard_mode_handler.as_ref().and_then(|handler| {
+            let item = handler.list_state().read(cx).delegate().selected_item()?;
+            Some((item.id, preview_file_path(item)?))
+        });
+        let Some((id, path)) = selected else {
+            self.file_preview = None;
+            return;
+        };
+        if self
+            .file_preview
+            .as_ref()
+            .is_some_and(|(preview_id, _)| *preview_id == id)
+        {
+            return;
+        }
+        self.file_preview = Some((id, FileReadState::Loading));
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { read_file(&path, MAX_FILE_PREVIEW_BYTES) })
+                .await;
+            let _ = this.update(cx, |this, cx| {
+                // Dropped if another item was selected meanwhile
+                if let Some((preview_id, state)) = &mut this.file_preview
+                    && *preview_id == id
+                    && *state == FileReadState::Loading
+                {
+                    *state = match result {
+                        Ok(content) => FileReadState::Done(content),
+                        Err(e) => FileReadState::Failed(e),
+                    };
+                    cx.notify();
+                }
+            });
+        })
+        .detach();
     }
 
     /// Simplified navigation - delegates handle their own logic.
@@ -451,13 +1258,8 @@ impl LauncherView {
                 {
                     emoji_state.update(cx, |state, cx| {
                         state.delegate_mut().select_down();
-                        if let Some(row) = state.delegate().selected_row() {
-                            state.scroll_to_item(
-                                IndexPath::new(row),
-                                ScrollStrategy::Top,
-                                window,
-                                cx,
-                            );
+                        if let Some(ix) = state.delegate().selected_index_path() {
+                            state.scroll_to_item(ix, ScrollStrategy::Top, window, cx);
                         }
                         cx.notify();
                     });
@@ -471,7 +1273,7 @@ impl LauncherView {
                         state.delegate_mut().select_down();
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
-                                IndexPath::new(idx),
+                                state.delegate().index_path(idx),
                                 ScrollStrategy::Top,
                                 window,
                                 cx,
@@ -498,6 +1300,46 @@ impl LauncherView {
                     });
                 }
             }
+            ViewMode::AppActions => {
+                if let Some(actions_state) = self
+                    .app_actions_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_down();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                ScrollStrategy::Top,
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::ActionMenu => {
+                if let Some(actions_state) = self
+                    .action_menu_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_down();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                ScrollStrategy::Top,
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
             ViewMode::AiResponse => {
                 // No navigation in AI response mode
             }
@@ -524,13 +1366,8 @@ impl LauncherView {
                 {
                     emoji_state.update(cx, |state, cx| {
                         state.delegate_mut().select_up();
-                        if let Some(row) = state.delegate().selected_row() {
-                            state.scroll_to_item(
-                                IndexPath::new(row),
-                                ScrollStrategy::Top,
-                                window,
-                                cx,
-                            );
+                        if let Some(ix) = state.delegate().selected_index_path() {
+                            state.scroll_to_item(ix, ScrollStrategy::Top, window, cx);
                         }
                         cx.notify();
                     });
@@ -544,7 +1381,7 @@ impl LauncherView {
                         state.delegate_mut().select_up();
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
-                                IndexPath::new(idx),
+                                state.delegate().index_path(idx),
                                 ScrollStrategy::Top,
                                 window,
                                 cx,
@@ -571,27 +1408,84 @@ impl LauncherView {
                     });
                 }
             }
-            ViewMode::AiResponse => {
-                // No navigation in AI response mode
-            }
-        }
+            ViewMode::AppActions => {
+                if let Some(actions_state) = self
+                    .app_actions_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_up();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                ScrollStrategy::Top,
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::ActionMenu => {
+                if let Some(actions_state) = self
+                    .action_menu_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_up();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                ScrollStrategy::Top,
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::AiResponse => {
+                // No navigation in AI response mode
+            }
+        }
     }
 
-    /// Tab moves to next item linearly with wrapping.
-    fn select_tab(&mut self, _: &SelectTab, window: &mut Window, cx: &mut Context<Self>) {
+    fn page_up(&mut self, _: &PageUp, window: &mut Window, cx: &mut Context<Self>) {
+        self.jump_selection(SelectionJump::PageUp, window, cx);
+    }
+
+    fn page_down(&mut self, _: &PageDown, window: &mut Window, cx: &mut Context<Self>) {
+        self.jump_selection(SelectionJump::PageDown, window, cx);
+    }
+
+    fn select_first(&mut self, _: &SelectFirst, window: &mut Window, cx: &mut Context<Self>) {
+        self.jump_selection(SelectionJump::First, window, cx);
+    }
+
+    fn select_last(&mut self, _: &SelectLast, window: &mut Window, cx: &mut Context<Self>) {
+        self.jump_selection(SelectionJump::Last, window, cx);
+    }
+
+    /// Move the selection of the active list by more than one item and
+    /// scroll it into view.
+    fn jump_selection(&mut self, jump: SelectionJump, window: &mut Window, cx: &mut Context<Self>) {
         match self.view_mode {
             ViewMode::Main => {
                 self.list_state.update(cx, |state, cx| {
                     let delegate = state.delegate_mut();
-                    let count = delegate.filtered_count();
-                    if count == 0 {
-                        return;
+                    match jump {
+                        SelectionJump::PageUp => delegate.select_page_up(PAGE_SIZE),
+                        SelectionJump::PageDown => delegate.select_page_down(PAGE_SIZE),
+                        SelectionJump::First => delegate.select_first(),
+                        SelectionJump::Last => delegate.select_last(),
                     }
-                    let current = delegate.selected_index().unwrap_or(0);
-                    let next = if current + 1 >= count { 0 } else { current + 1 };
-                    delegate.set_selected(next);
-
-                    if let Some(index_path) = delegate.global_to_index_path(next) {
+                    if let Some(idx) = state.delegate().selected_index()
+                        && let Some(index_path) = state.delegate().global_to_index_path(idx)
+                    {
                         state.set_selected_index(Some(index_path), window, cx);
                         state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
                     }
@@ -602,10 +1496,35 @@ impl LauncherView {
                 if let Some(emoji_state) = self.emoji_mode_handler.as_ref().map(|h| h.list_state())
                 {
                     emoji_state.update(cx, |state, cx| {
-                        state.delegate_mut().select_right();
-                        if let Some(row) = state.delegate().selected_row() {
+                        let delegate = state.delegate_mut();
+                        match jump {
+                            SelectionJump::PageUp => delegate.select_page_up(EMOJI_PAGE_ROWS),
+                            SelectionJump::PageDown => delegate.select_page_down(EMOJI_PAGE_ROWS),
+                            SelectionJump::First => delegate.select_first(),
+                            SelectionJump::Last => delegate.select_last(),
+                        }
+                        if let Some(ix) = state.delegate().selected_index_path() {
+                            state.scroll_to_item(ix, ScrollStrategy::Top, window, cx);
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::ClipboardHistory => {
+                if let Some(clipboard_state) =
+                    self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    clipboard_state.update(cx, |state, cx| {
+                        let delegate = state.delegate_mut();
+                        match jump {
+                            SelectionJump::PageUp => delegate.select_page_up(PAGE_SIZE),
+                            SelectionJump::PageDown => delegate.select_page_down(PAGE_SIZE),
+                            SelectionJump::First => delegate.select_first(),
+                            SelectionJump::Last => delegate.select_last(),
+                        }
+                        if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
-                                IndexPath::new(row),
+                                state.delegate().index_path(idx),
                                 ScrollStrategy::Top,
                                 window,
                                 cx,
@@ -614,6 +1533,116 @@ impl LauncherView {
                         cx.notify();
                     });
                 }
+                // Re-render so the preview panel follows the new selection
+                cx.notify();
+            }
+            ViewMode::ThemePicker => {
+                if let Some(theme_state) = self.theme_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    theme_state.update(cx, |state, cx| {
+                        let delegate = state.delegate_mut();
+                        match jump {
+                            SelectionJump::PageUp => delegate.select_page_up(PAGE_SIZE),
+                            SelectionJump::PageDown => delegate.select_page_down(PAGE_SIZE),
+                            SelectionJump::First => delegate.select_first(),
+                            SelectionJump::Last => delegate.select_last(),
+                        }
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                ScrollStrategy::Top,
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::AppActions => {
+                if let Some(actions_state) = self
+                    .app_actions_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, cx| {
+                        let delegate = state.delegate_mut();
+                        match jump {
+                            SelectionJump::PageUp => delegate.select_page_up(PAGE_SIZE),
+                            SelectionJump::PageDown => delegate.select_page_down(PAGE_SIZE),
+                            SelectionJump::First => delegate.select_first(),
+                            SelectionJump::Last => delegate.select_last(),
+                        }
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                ScrollStrategy::Top,
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::ActionMenu => {
+                if let Some(actions_state) = self
+                    .action_menu_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, cx| {
+                        let delegate = state.delegate_mut();
+                        match jump {
+                            SelectionJump::PageUp => delegate.select_page_up(PAGE_SIZE),
+                            SelectionJump::PageDown => delegate.select_page_down(PAGE_SIZE),
+                            SelectionJump::First => delegate.select_first(),
+                            SelectionJump::Last => delegate.select_last(),
+                        }
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                ScrollStrategy::Top,
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::AiResponse => {
+                // No navigation in AI response mode
+            }
+        }
+    }
+
+    /// Tab moves to next item linearly with wrapping.
+    fn select_tab(&mut self, _: &SelectTab, window: &mut Window, cx: &mut Context<Self>) {
+        match self.view_mode {
+            ViewMode::Main => {
+                self.list_state.update(cx, |state, cx| {
+                    state.delegate_mut().select_right();
+                    if let Some(idx) = state.delegate().selected_index()
+                        && let Some(index_path) = state.delegate().global_to_index_path(idx)
+                    {
+                        state.set_selected_index(Some(index_path), window, cx);
+                        state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
+                    }
+                    cx.notify();
+                });
+            }
+            ViewMode::EmojiPicker => {
+                if let Some(emoji_state) = self.emoji_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    emoji_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_right();
+                        if let Some(ix) = state.delegate().selected_index_path() {
+                            state.scroll_to_item(ix, ScrollStrategy::Top, window, cx);
+                        }
+                        cx.notify();
+                    });
+                }
             }
             ViewMode::ClipboardHistory => {
                 if let Some(clipboard_state) =
@@ -623,7 +1652,7 @@ impl LauncherView {
                         state.delegate_mut().select_down();
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
-                                IndexPath::new(idx),
+                                state.delegate().index_path(idx),
                                 ScrollStrategy::Top,
                                 window,
                                 cx,
@@ -650,6 +1679,46 @@ impl LauncherView {
                     });
                 }
             }
+            ViewMode::AppActions => {
+                if let Some(actions_state) = self
+                    .app_actions_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_down();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                ScrollStrategy::Top,
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::ActionMenu => {
+                if let Some(actions_state) = self
+                    .action_menu_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_down();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                ScrollStrategy::Top,
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
             ViewMode::AiResponse => {
                 // No navigation in AI response mode
             }
@@ -661,16 +1730,10 @@ impl LauncherView {
         match self.view_mode {
             ViewMode::Main => {
                 self.list_state.update(cx, |state, cx| {
-                    let delegate = state.delegate_mut();
-                    let count = delegate.filtered_count();
-                    if count == 0 {
-                        return;
-                    }
-                    let current = delegate.selected_index().unwrap_or(0);
-                    let prev = if current == 0 { count - 1 } else { current - 1 };
-                    delegate.set_selected(prev);
-
-                    if let Some(index_path) = delegate.global_to_index_path(prev) {
+                    state.delegate_mut().select_left();
+                    if let Some(idx) = state.delegate().selected_index()
+                        && let Some(index_path) = state.delegate().global_to_index_path(idx)
+                    {
                         state.set_selected_index(Some(index_path), window, cx);
                         state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
                     }
@@ -682,9 +1745,22 @@ impl LauncherView {
                 {
                     emoji_state.update(cx, |state, cx| {
                         state.delegate_mut().select_left();
-                        if let Some(row) = state.delegate().selected_row() {
+                        if let Some(ix) = state.delegate().selected_index_path() {
+                            state.scroll_to_item(ix, ScrollStrategy::Top, window, cx);
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::ClipboardHistory => {
+                if let Some(clipboard_state) =
+                    self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    clipboard_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_up();
+                        if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
-                                IndexPath::new(row),
+                                state.delegate().index_path(idx),
                                 ScrollStrategy::Top,
                                 window,
                                 cx,
@@ -694,11 +1770,50 @@ impl LauncherView {
                     });
                 }
             }
-            ViewMode::ClipboardHistory => {
-                if let Some(clipboard_state) =
-                    self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+            ViewMode::ThemePicker => {
+                if let Some(theme_state) = self.theme_mode_handler.as_ref().map(|h| h.list_state())
                 {
-                    clipboard_state.update(cx, |state, cx| {
+                    theme_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_up();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                ScrollStrategy::Top,
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::AppActions => {
+                if let Some(actions_state) = self
+                    .app_actions_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_up();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                ScrollStrategy::Top,
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::ActionMenu => {
+                if let Some(actions_state) = self
+                    .action_menu_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, cx| {
                         state.delegate_mut().select_up();
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
@@ -712,103 +1827,944 @@ impl LauncherView {
                     });
                 }
             }
-            ViewMode::ThemePicker => {
-                if let Some(theme_state) = self.theme_mode_handler.as_ref().map(|h| h.list_state())
+            ViewMode::AiResponse => {
+                // No navigation in AI response mode
+            }
+        }
+    }
+
+    fn confirm(&mut self, _: &Confirm, window: &mut Window, cx: &mut Context<Self>) {
+        if self.run_pending_action(cx) {
+            return;
+        }
+        match self.view_mode {
+            ViewMode::Main => {
+                // Check if a submenu or AI item is selected
+                if let Some(item) = self.list_state.read(cx).delegate().get_item_at(
+                    self.list_state
+                        .read(cx)
+                        .delegate()
+                        .selected_index()
+                        .unwrap_or(0),
+                ) {
+                    match item {
+                        ListItem::Submenu(submenu) => match submenu.id.as_str() {
+                            "submenu-emojis" => {
+                                self.enter_emoji_mode(window, cx);
+                                return;
+                            }
+                            "submenu-clipboard" => {
+                                self.enter_clipboard_mode(window, cx);
+                                return;
+                            }
+                            "submenu-themes" => {
+                                self.enter_theme_mode(window, cx);
+                                return;
+                            }
+                            "submenu-power" => {
+                                self.enter_action_menu_mode(
+                                    &submenu,
+                                    ActionItem::power_actions(),
+                                    window,
+                                    cx,
+                                );
+                                return;
+                            }
+                            id if id.starts_with(CATEGORY_ID_PREFIX) => {
+                                self.filter_by_category(&submenu.name, window, cx);
+                                return;
+                            }
+                            _ => {}
+                        },
+                        ListItem::Ai(_) => {
+                            self.enter_ai_mode(window, cx);
+                            return;
+                        }
+                        _ => {}
+                    }
+                }
+                // Regular item confirmation
+                self.list_state.update(cx, |state, _cx| {
+                    state.delegate().do_confirm();
+                });
+            }
+            ViewMode::EmojiPicker => {
+                if let Some(emoji_state) = self.emoji_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    emoji_state.update(cx, |state, _cx| {
+                        state.delegate().do_confirm();
+                    });
+                }
+            }
+            ViewMode::ClipboardHistory => {
+                if self.finish_tag_edit(window, cx) {
+                    return;
+                }
+                if let Some(clipboard_state) =
+                    self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    // Copy the text recognized in an image instead of the image
+                    let delegate = clipboard_state.read(cx).delegate();
+                    if let Some((id, OcrState::Done(text))) = &self.ocr
+                        && delegate.selected_item().is_some_and(|item| item.id == *id)
+                    {
+                        if let Err(e) = copy_to_selection(text, delegate.copy_target()) {
+                            tracing::warn!(%e, "Failed to copy recognized text to clipboard");
+                            return;
+                        }
+                        (self.on_copied())();
+                        return;
+                    }
+                    clipboard_state.update(cx, |state, _cx| {
+                        state.delegate().do_confirm();
+                    });
+                }
+            }
+            ViewMode::ThemePicker => {
+                if let Some(theme_state) = self.theme_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    theme_state.update(cx, |state, _cx| {
+                        state.delegate().do_confirm();
+                    });
+                }
+                // Exit theme mode after confirming
+                self.exit_theme_mode(window, cx);
+            }
+            ViewMode::AppActions => {
+                if let Some(actions_state) = self
+                    .app_actions_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, _cx| {
+                        state.delegate().do_confirm();
+                    });
+                }
+            }
+            ViewMode::ActionMenu => {
+                if let Some(actions_state) = self
+                    .action_menu_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    actions_state.update(cx, |state, _cx| {
+                        state.delegate().do_confirm();
+                    });
+                }
+            }
+            ViewMode::AiResponse => {
+                // If already in AI mode, then send a new prompt
+                self.update_ai_mode(window, cx);
+            }
+        }
+    }
+
+    /// Copy the selected clipboard item, keeping the formatting of rich text.
+    fn paste_formatted(
+        &mut self,
+        _: &PasteFormatted,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            cx.propagate();
+            return;
+        }
+        if let Some(clipboard_state) = self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+        {
+            clipboard_state.update(cx, |state, _cx| {
+                state.delegate().do_confirm_formatted();
+            });
+        }
+    }
+
+    /// Cycle the clipboard history between all, clipboard-only and primary-only items.
+    fn cycle_clipboard_source(
+        &mut self,
+        _: &CycleClipboardSource,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            return;
+        }
+        if let Some(handler) = &self.clipboard_mode_handler {
+            handler.cycle_source_filter(&self.input_state, window, cx);
+        }
+    }
+
+    /// Switch the skin tone of the emoji picker.
+    fn cycle_skin_tone(&mut self, _: &CycleSkinTone, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::EmojiPicker {
+            cx.propagate();
+            return;
+        }
+        if let Some(emoji_state) = self.emoji_mode_handler.as_ref().map(|h| h.list_state()) {
+            emoji_state.update(cx, |state, cx| {
+                state.delegate_mut().cycle_skin_tone();
+                cx.notify();
+            });
+        }
+    }
+
+    /// Pin or unpin the selected clipboard history item.
+    fn toggle_pin(&mut self, _: &TogglePin, window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            return;
+        }
+        if let Some(clipboard_state) = self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+        {
+            clipboard_state.update(cx, |state, cx| {
+                state.delegate_mut().toggle_pin_selected();
+                if let Some(idx) = state.delegate().selected_index() {
+                    state.scroll_to_item(
+                        state.delegate().index_path(idx),
+                        ScrollStrategy::Top,
+                        window,
+                        cx,
+                    );
+                }
+                cx.notify();
+            });
+        }
+        cx.notify();
+    }
+
+    /// Edit the tags of the selected clipboard history item in the search
+    /// input.
+    fn edit_tags(&mut self, _: &EditTags, window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::ClipboardHistory || self.is_editing_tags(cx) {
+            return;
+        }
+        if let Some(handler) = &self.clipboard_mode_handler
+            && handler.start_tag_edit(&self.input_state, window, cx)
+        {
+            cx.notify();
+        }
+    }
+
+    /// Whether the search input is editing the tags of a clipboard item.
+    fn is_editing_tags(&self, cx: &App) -> bool {
+        self.clipboard_mode_handler
+            .as_ref()
+            .is_some_and(|handler| handler.is_editing_tags(cx))
+    }
+
+    /// Save the tags typed for a clipboard item and report them.
+    ///
+    /// Returns false if no tags were being edited.
+    fn finish_tag_edit(&mut self, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        let Some(tags) = self
+            .clipboard_mode_handler
+            .as_ref()
+            .and_then(|handler| handler.finish_tag_edit(&self.input_state, window, cx))
+        else {
+            return false;
+        };
+        let message = if tags.is_empty() {
+            "Tags removed".to_string()
+        } else {
+            let tags: Vec<String> = tags.iter().map(|tag| format!("#{}", tag)).collect();
+            format!("Tagged {}", tags.join(" "))
+        };
+        self.show_status(message, false, cx);
+        true
+    }
+
+    /// Delete the selected clipboard history item.
+    fn delete_item(&mut self, _: &DeleteItem, window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            // Let the input handle forward delete
+            cx.propagate();
+            return;
+        }
+        if let Some(clipboard_state) = self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+        {
+            clipboard_state.update(cx, |state, cx| {
+                state.delegate_mut().remove_selected();
+                if let Some(idx) = state.delegate().selected_index() {
+                    let index_path = state.delegate().index_path(idx);
+                    state.set_selected_index(Some(index_path), window, cx);
+                    state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
+                }
+                cx.notify();
+            });
+        }
+        // Re-render so the preview panel follows the new selection
+        cx.notify();
+    }
+
+    /// Close the selected window instead of focusing it.
+    fn close_window(&mut self, _: &CloseWindow, window: &mut Window, cx: &mut Context<Self>) {
+        let selected = if self.view_mode == ViewMode::Main {
+            let delegate = self.list_state.read(cx).delegate();
+            delegate
+                .selected_index()
+                .and_then(|idx| delegate.get_item_at(idx))
+        } else {
+            None
+        };
+        let Some(ListItem::Window(win)) = selected else {
+            cx.propagate();
+            return;
+        };
+
+        if let Err(e) = self.compositor.close_window(&win.address) {
+            tracing::warn!(%e, "Failed to close window");
+            return;
+        }
+
+        self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().remove_window(&win.address);
+            if let Some(idx) = state.delegate().selected_index()
+                && let Some(index_path) = state.delegate().global_to_index_path(idx)
+            {
+                state.set_selected_index(Some(index_path), window, cx);
+                state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
+            }
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    /// Switch between the windows of the focused workspace and all windows.
+    fn toggle_window_scope(
+        &mut self,
+        _: &ToggleWindowScope,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::Main {
+            cx.propagate();
+            return;
+        }
+        self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().toggle_window_scope();
+            if let Some(index_path) = state.delegate().global_to_index_path(0) {
+                state.set_selected_index(Some(index_path), window, cx);
+                state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
+            }
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    /// Collapse the section of the selected item (or expand all sections
+    /// when everything is collapsed).
+    fn toggle_section(&mut self, _: &ToggleSection, window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::Main {
+            cx.propagate();
+            return;
+        }
+        self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().toggle_selected_section();
+            if let Some(idx) = state.delegate().selected_index()
+                && let Some(index_path) = state.delegate().global_to_index_path(idx)
+            {
+                state.set_selected_index(Some(index_path), window, cx);
+                state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
+            }
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    /// Expand all collapsed sections.
+    fn expand_sections(&mut self, _: &ExpandSections, window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::Main {
+            cx.propagate();
+            return;
+        }
+        self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().expand_sections();
+            if let Some(idx) = state.delegate().selected_index()
+                && let Some(index_path) = state.delegate().global_to_index_path(idx)
+            {
+                state.set_selected_index(Some(index_path), window, cx);
+                state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
+            }
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    /// Move to the item on the left in the grid layout of the main list.
+    ///
+    /// Moves the cursor of the search input otherwise.
+    fn select_left(&mut self, _: &SelectLeft, window: &mut Window, cx: &mut Context<Self>) {
+        self.move_in_grid(false, window, cx);
+    }
+
+    /// Move to the item on the right in the grid layout of the main list.
+    ///
+    /// Moves the cursor of the search input otherwise.
+    fn select_right(&mut self, _: &SelectRight, window: &mut Window, cx: &mut Context<Self>) {
+        self.move_in_grid(true, window, cx);
+    }
+
+    /// Move the selection of the main list grid one item to the right or left.
+    fn move_in_grid(&mut self, right: bool, window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::Main || !self.list_state.read(cx).delegate().is_grid() {
+            cx.propagate();
+            return;
+        }
+        self.list_state.update(cx, |state, cx| {
+            if right {
+                state.delegate_mut().select_right();
+            } else {
+                state.delegate_mut().select_left();
+            }
+            if let Some(idx) = state.delegate().selected_index()
+                && let Some(index_path) = state.delegate().global_to_index_path(idx)
+            {
+                state.set_selected_index(Some(index_path), window, cx);
+                state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
+            }
+            cx.notify();
+        });
+    }
+
+    /// Show all results of the selected section, when the last shown result
+    /// of a section with more results is selected.
+    fn expand_results(&mut self, _: &ExpandResults, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::Main {
+            cx.propagate();
+            return;
+        }
+        let expanded = self.list_state.update(cx, |state, cx| {
+            let expanded = state.delegate_mut().expand_selected_section();
+            cx.notify();
+            expanded
+        });
+        if !expanded {
+            cx.propagate();
+        }
+    }
+
+    /// Switch which representation of the calculator result is copied.
+    fn cycle_calculator_format(
+        &mut self,
+        _: &CycleCalculatorFormat,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::Main {
+            cx.propagate();
+            return;
+        }
+        let cycled = self.list_state.update(cx, |state, cx| {
+            let cycled = state.delegate_mut().cycle_calculator_format();
+            cx.notify();
+            cycled
+        });
+        if !cycled {
+            cx.propagate();
+        }
+    }
+
+    /// Wipe the whole clipboard history.
+    fn clear_clipboard(
+        &mut self,
+        _: &ClearClipboard,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            return;
+        }
+        if let Some(clipboard_state) = self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+        {
+            clipboard_state.update(cx, |state, cx| {
+                state.delegate_mut().clear_all();
+                cx.notify();
+            });
+        }
+        cx.notify();
+    }
+
+    /// Open the selected clipboard item in the browser if it is a URL.
+    fn open_url(&mut self, _: &OpenUrl, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            return;
+        }
+        let Some(clipboard_state) = self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+        else {
+            return;
+        };
+
+        let url = match clipboard_state.read(cx).delegate().selected_item() {
+            Some(ClipboardItem {
+                content: ClipboardContent::Text(text),
+                ..
+            }) => parse_url(text).map(str::to_string),
+            _ => None,
+        };
+        let Some(url) = url else {
+            return;
+        };
+
+        if let Err(e) = open_url(&url) {
+            tracing::warn!(%e, "Failed to open URL");
+            return;
+        }
+        (self.on_hide)();
+    }
+
+    /// Show the files of the selected clipboard item in the file manager.
+    fn reveal_in_folder(
+        &mut self,
+        _: &RevealInFolder,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            return;
+        }
+        let Some(clipboard_state) = self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+        else {
+            return;
+        };
+
+        let paths = match clipboard_state.read(cx).delegate().selected_item() {
+            Some(item) => item.file_paths(),
+            None => return,
+        };
+        if paths.is_empty() {
+            return;
+        }
+
+        if let Err(e) = reveal_paths(&paths) {
+            tracing::warn!(%e, "Failed to open containing folder");
+            return;
+        }
+        (self.on_hide)();
+    }
+
+    /// Copy the contents of the selected text file, or the lines picked with
+    /// a `:10-20` query.
+    fn copy_file_contents(
+        &mut self,
+        _: &CopyFileContents,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            cx.propagate();
+            return;
+        }
+        let Some(clipboard_state) = self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+        else {
+            return;
+        };
+
+        let delegate = clipboard_state.read(cx).delegate();
+        let paths = match delegate.selected_item() {
+            Some(item) => item.file_paths(),
+            None => return,
+        };
+        let [path] = paths.as_slice() else {
+            return;
+        };
+        let Some(content) = read_text_file(path) else {
+            return;
+        };
+        let text = match delegate.line_range() {
+            Some(range) => match range.select(&content) {
+                Some(lines) => lines,
+                None => return,
+            },
+            None => content.as_str(),
+        };
+
+        if let Err(e) = copy_to_selection(text, delegate.copy_target()) {
+            tracing::warn!(%e, "Failed to copy file contents to clipboard");
+            return;
+        }
+        (self.on_copied())();
+    }
+
+    /// Save the selected clipboard image as a PNG in the screenshots
+    /// directory, showing the path of the file (or why it failed).
+    fn save_image(&mut self, _: &SaveImage, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            cx.propagate();
+            return;
+        }
+        let Some(clipboard_state) = self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+        else {
+            return;
+        };
+
+        let (width, height, rgba_bytes) = match clipboard_state
+            .read(cx)
+            .delegate()
+            .selected_item()
+            .map(|item| &item.content)
+        {
+            Some(ClipboardContent::Image {
+                width,
+                height,
+                rgba_bytes,
+            }) => (*width, *height, rgba_bytes.clone()),
+            Some(_) => {
+                self.show_status("Only images can be saved".to_string(), true, cx);
+                return;
+            }
+            None => return,
+        };
+        let Some(dir) = crate::clipboard::export::images_dir() else {
+            self.show_status("No directory to save images to".to_string(), true, cx);
+            return;
+        };
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    crate::clipboard::export::save_image(width, height, &rgba_bytes, &dir)
+                })
+                .await;
+            let _ = this.update(cx, |this, cx| match result {
+                Ok(path) => this.show_status(format!("Saved to {}", path.display()), false, cx),
+                Err(e) => {
+                    tracing::warn!(%e, "Failed to save clipboard image");
+                    this.show_status(e, true, cx);
+                }
+            });
+        })
+        .detach();
+    }
+
+    /// Show the text recognized in the selected clipboard image, or the
+    /// image again if the text is already shown.
+    ///
+    /// Recognition runs in the background, Enter then copies the text.
+    fn recognize_text(&mut self, _: &RecognizeText, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            cx.propagate();
+            return;
+        }
+        let Some(clipboard_state) = self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
+        else {
+            return;
+        };
+        let Some(item) = clipboard_state.read(cx).delegate().selected_item() else {
+            return;
+        };
+        let ClipboardContent::Image {
+            width,
+            height,
+            rgba_bytes,
+        } = &item.content
+        else {
+            self.show_status(
+                "Text can only be recognized in images".to_string(),
+                true,
+                cx,
+            );
+            return;
+        };
+
+        let (id, width, height, rgba_bytes) = (item.id, *width, *height, rgba_bytes.clone());
+        if self.ocr.as_ref().is_some_and(|(ocr_id, _)| *ocr_id == id) {
+            self.ocr = None;
+            cx.notify();
+            return;
+        }
+        self.ocr = Some((id, OcrState::Running));
+        self.show_qr_code = false;
+        cx.notify();
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { recognize_text(width, height, &rgba_bytes) })
+                .await;
+            let _ = this.update(cx, |this, cx| {
+                // Dropped if the text was hidden or another image picked meanwhile
+                if let Some((ocr_id, state)) = &mut this.ocr
+                    && *ocr_id == id
+                    && *state == OcrState::Running
                 {
-                    theme_state.update(cx, |state, cx| {
-                        state.delegate_mut().select_up();
-                        if let Some(idx) = state.delegate().selected_index() {
-                            state.scroll_to_item(
-                                IndexPath::new(idx),
-                                ScrollStrategy::Top,
-                                window,
-                                cx,
-                            );
+                    *state = match result {
+                        Ok(text) => OcrState::Done(text),
+                        Err(e) => {
+                            tracing::warn!(%e, "Failed to recognize text in clipboard image");
+                            OcrState::Failed(e)
                         }
-                        cx.notify();
-                    });
+                    };
+                    cx.notify();
                 }
-            }
-            ViewMode::AiResponse => {
-                // No navigation in AI response mode
-            }
+            });
+        })
+        .detach();
+    }
+
+    /// Switch the clipboard preview between the content and its QR code.
+    fn toggle_qr_code(&mut self, _: &ToggleQrCode, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            cx.propagate();
+            return;
         }
+        self.show_qr_code = !self.show_qr_code;
+        cx.notify();
     }
 
-    fn confirm(&mut self, _: &Confirm, window: &mut Window, cx: &mut Context<Self>) {
+    /// Put an `@category` filter into the search input.
+    fn filter_by_category(&mut self, category: &str, window: &mut Window, cx: &mut Context<Self>) {
+        let query = format!("@{} ", category.to_lowercase());
+        self.input_state.update(cx, |input, cx| {
+            input.set_value(query.clone(), window, cx);
+        });
+        self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().set_query(query);
+            cx.notify();
+        });
+    }
+
+    /// Show or hide the metadata inspector next to the main list.
+    fn toggle_inspector(
+        &mut self,
+        _: &ToggleInspector,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::Main {
+            cx.propagate();
+            return;
+        }
+        self.show_inspector = !self.show_inspector;
+        cx.notify();
+    }
+
+    fn scroll_preview_up(
+        &mut self,
+        _: &ScrollPreviewUp,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.scroll_preview(-PREVIEW_SCROLL_STEP, cx);
+    }
+
+    fn scroll_preview_down(
+        &mut self,
+        _: &ScrollPreviewDown,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.scroll_preview(PREVIEW_SCROLL_STEP, cx);
+    }
+
+    /// Scroll the clipboard preview text by `delta`.
+    fn scroll_preview(&mut self, delta: Pixels, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            cx.propagate();
+            return;
+        }
+        crate::ui::views::clipboard_rendering::scroll_preview(delta);
+        cx.notify();
+    }
+
+    fn filter_all(&mut self, _: &FilterAll, _window: &mut Window, cx: &mut Context<Self>) {
+        self.set_clipboard_filter(ClipboardContentFilter::All, cx);
+    }
+
+    fn filter_text(&mut self, _: &FilterText, _window: &mut Window, cx: &mut Context<Self>) {
+        self.set_clipboard_filter(ClipboardContentFilter::Text, cx);
+    }
+
+    fn filter_images(&mut self, _: &FilterImages, _window: &mut Window, cx: &mut Context<Self>) {
+        self.set_clipboard_filter(ClipboardContentFilter::Images, cx);
+    }
+
+    fn filter_files(&mut self, _: &FilterFiles, _window: &mut Window, cx: &mut Context<Self>) {
+        self.set_clipboard_filter(ClipboardContentFilter::Files, cx);
+    }
+
+    fn filter_colors(&mut self, _: &FilterColors, _window: &mut Window, cx: &mut Context<Self>) {
+        self.set_clipboard_filter(ClipboardContentFilter::Colors, cx);
+    }
+
+    /// Switch the clipboard history content filter.
+    fn set_clipboard_filter(&mut self, filter: ClipboardContentFilter, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::ClipboardHistory || self.is_editing_tags(cx) {
+            cx.propagate();
+            return;
+        }
+        if let Some(handler) = &self.clipboard_mode_handler {
+            handler.set_content_filter(filter, cx);
+        }
+        // Re-render so the filter bar and preview follow
+        cx.notify();
+    }
+
+    /// Render the clipboard content filter bar.
+    fn render_clipboard_filter_bar(
+        &self,
+        active: ClipboardContentFilter,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let theme = &self.current_theme;
+        div()
+            .flex()
+            .flex_row()
+            .gap_1()
+            .px(theme.item_padding_x)
+            .pt_2()
+            .children(
+                ClipboardContentFilter::ALL
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, filter)| {
+                        let is_active = filter == active;
+                        div()
+                            .id(("clipboard-filter", ix))
+                            .px_2()
+                            .py_0p5()
+                            .rounded(theme.item_border_radius)
+                            .text_xs()
+                            .cursor_pointer()
+                            .text_color(if is_active {
+                                theme.item_title_color
+                            } else {
+                                theme.item_description_color
+                            })
+                            .when(is_active, |el| el.bg(theme.item_background_selected))
+                            .when(!is_active, |el| el.hover(|el| el.bg(theme.item_background)))
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                if let Some(handler) = &this.clipboard_mode_handler {
+                                    handler.set_content_filter(filter, cx);
+                                }
+                                cx.notify();
+                            }))
+                            .child(format!("Alt+{} {}", ix + 1, filter.label()))
+                    }),
+            )
+    }
+
+    /// Get the result count shown in the footer for the current mode.
+    fn result_count_label(&self, cx: &Context<Self>) -> Option<String> {
+        let (count, singular, plural) = match self.view_mode {
+            ViewMode::Main => (
+                self.list_state.read(cx).delegate().filtered_count(),
+                "result",
+                "results",
+            ),
+            ViewMode::EmojiPicker => (
+                self.emoji_mode_handler
+                    .as_ref()?
+                    .list_state()
+                    .read(cx)
+                    .delegate()
+                    .filtered_count(),
+                "emoji",
+                "emoji",
+            ),
+            ViewMode::ClipboardHistory => (
+                self.clipboard_mode_handler
+                    .as_ref()?
+                    .list_state()
+                    .read(cx)
+                    .delegate()
+                    .filtered_count(),
+                "entry",
+                "entries",
+            ),
+            ViewMode::ThemePicker => (
+                self.theme_mode_handler
+                    .as_ref()?
+                    .list_state()
+                    .read(cx)
+                    .delegate()
+                    .filtered_count(),
+                "theme",
+                "themes",
+            ),
+            ViewMode::AppActions => (
+                self.app_actions_mode_handler
+                    .as_ref()?
+                    .list_state()
+                    .read(cx)
+                    .delegate()
+                    .filtered_count(),
+                "action",
+                "actions",
+            ),
+            ViewMode::ActionMenu => (
+                self.action_menu_mode_handler
+                    .as_ref()?
+                    .list_state()
+                    .read(cx)
+                    .delegate()
+                    .filtered_count(),
+                "action",
+                "actions",
+            ),
+            ViewMode::AiResponse => return None,
+        };
+
+        Some(match count {
+            0 => "No matches".to_string(),
+            1 => format!("1 {}", singular),
+            n => format!("{} {}", n, plural),
+        })
+    }
+
+    /// Main actions of the current view with what they do, for the key hints.
+    fn key_hint_actions(&self) -> Vec<(Box<dyn Action>, &'static str)> {
         match self.view_mode {
-            ViewMode::Main => {
-                // Check if a submenu or AI item is selected
-                if let Some(item) = self.list_state.read(cx).delegate().get_item_at(
-                    self.list_state
-                        .read(cx)
-                        .delegate()
-                        .selected_index()
-                        .unwrap_or(0),
-                ) {
-                    match item {
-                        ListItem::Submenu(submenu) => match submenu.id.as_str() {
-                            "submenu-emojis" => {
-                                self.enter_emoji_mode(window, cx);
-                                return;
-                            }
-                            "submenu-clipboard" => {
-                                self.enter_clipboard_mode(window, cx);
-                                return;
-                            }
-                            "submenu-themes" => {
-                                self.enter_theme_mode(window, cx);
-                                return;
-                            }
-                            _ => {}
-                        },
-                        ListItem::Ai(_) => {
-                            self.enter_ai_mode(window, cx);
-                            return;
-                        }
-                        _ => {}
-                    }
-                }
-                // Regular item confirmation
-                self.list_state.update(cx, |state, _cx| {
-                    state.delegate().do_confirm();
-                });
-            }
-            ViewMode::EmojiPicker => {
-                if let Some(emoji_state) = self.emoji_mode_handler.as_ref().map(|h| h.list_state())
-                {
-                    emoji_state.update(cx, |state, _cx| {
-                        state.delegate().do_confirm();
-                    });
-                }
-            }
-            ViewMode::ClipboardHistory => {
-                if let Some(clipboard_state) =
-                    self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
-                {
-                    clipboard_state.update(cx, |state, _cx| {
-                        state.delegate().do_confirm();
-                    });
-                }
-            }
-            ViewMode::ThemePicker => {
-                if let Some(theme_state) = self.theme_mode_handler.as_ref().map(|h| h.list_state())
-                {
-                    theme_state.update(cx, |state, _cx| {
-                        state.delegate().do_confirm();
-                    });
-                }
-                // Exit theme mode after confirming
-                self.exit_theme_mode(window, cx);
-            }
-            ViewMode::AiResponse => {
-                // If already in AI mode, then send a new prompt
-                self.update_ai_mode(window, cx);
+            ViewMode::Main => vec![
+                (Box::new(Confirm), "open"),
+                (Box::new(SelectTab), "next"),
+                (Box::new(Cancel), "close"),
+            ],
+            ViewMode::ClipboardHistory => vec![
+                (Box::new(Confirm), "copy"),
+                (Box::new(GoBack), "back"),
+                (Box::new(DeleteItem), "remove"),
+                (Box::new(TogglePin), "pin"),
+            ],
+            ViewMode::EmojiPicker => vec![
+                (Box::new(Confirm), "copy"),
+                (Box::new(GoBack), "back"),
+                (Box::new(CycleSkinTone), "skin tone"),
+            ],
+            ViewMode::ThemePicker => vec![(Box::new(Confirm), "apply"), (Box::new(GoBack), "back")],
+            ViewMode::AppActions | ViewMode::ActionMenu => {
+                vec![(Box::new(Confirm), "run"), (Box::new(GoBack), "back")]
             }
+            ViewMode::AiResponse => vec![(Box::new(Cancel), "back")],
         }
     }
 
+    /// Hints for the main keys of the current view, e.g. "↵ open · tab next".
+    ///
+    /// The keys are looked up in the key bindings, so the hints follow them.
+    fn key_hints(&self, window: &Window) -> Option<String> {
+        let context = KeyContext::parse("LauncherView").ok()?;
+        let hints: Vec<String> = self
+            .key_hint_actions()
+            .iter()
+            .filter_map(|(action, label)| {
+                let bindings =
+                    window.bindings_for_action_in_context(action.as_ref(), context.clone());
+                let keys: Vec<String> = bindings
+                    .first()?
+                    .keystrokes()
+                    .iter()
+                    .map(key_label)
+                    .collect();
+                Some(format!("{} {}", keys.join(" "), label))
+            })
+            .collect();
+        (!hints.is_empty()).then(|| hints.join(" · "))
+    }
+
     fn cancel(&mut self, _: &Cancel, window: &mut Window, cx: &mut Context<Self>) {
+        if self.pending_action.take().is_some() {
+            cx.notify();
+            return;
+        }
         match self.view_mode {
             ViewMode::Main => {
                 self.list_state.update(cx, |state, _cx| {
@@ -823,6 +2779,16 @@ impl LauncherView {
     }
 
     fn go_back(&mut self, _: &GoBack, window: &mut Window, cx: &mut Context<Self>) {
+        if self.pending_action.take().is_some() {
+            cx.notify();
+            return;
+        }
+        if let Some(handler) = &self.clipboard_mode_handler
+            && handler.cancel_tag_edit(&self.input_state, window, cx)
+        {
+            cx.notify();
+            return;
+        }
         match self.view_mode {
             ViewMode::Main => {
                 // Already at main, do nothing
@@ -836,6 +2802,12 @@ impl LauncherView {
             ViewMode::ThemePicker => {
                 self.exit_theme_mode(window, cx);
             }
+            ViewMode::AppActions => {
+                self.exit_app_actions_mode(window, cx);
+            }
+            ViewMode::ActionMenu => {
+                self.exit_action_menu_mode(window, cx);
+            }
             ViewMode::AiResponse => {
                 self.exit_ai_mode(window, cx);
             }
@@ -851,6 +2823,10 @@ impl Focusable for LauncherView {
 
 impl gpui::Render for LauncherView {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Read the previewed text file when the selected clipboard item changed
+        if self.view_mode == ViewMode::ClipboardHistory {
+            self.load_file_preview(cx);
+        }
         let theme = &self.current_theme;
         let config = crate::config::config();
 
@@ -887,6 +2863,24 @@ impl gpui::Render for LauncherView {
                 }))
                 .child(Icon::new(IconName::ArrowLeft).text_color(cx.theme().muted_foreground))
                 .into_any_element(),
+            ViewMode::AppActions => div()
+                .id("back-app-actions")
+                .cursor_pointer()
+                .mr_2()
+                .on_click(cx.listener(|this, _, window, cx| {
+                    this.exit_app_actions_mode(window, cx);
+                }))
+                .child(Icon::new(IconName::ArrowLeft).text_color(cx.theme().muted_foreground))
+                .into_any_element(),
+            ViewMode::ActionMenu => div()
+                .id("back-action-menu")
+                .cursor_pointer()
+                .mr_2()
+                .on_click(cx.listener(|this, _, window, cx| {
+                    this.exit_action_menu_mode(window, cx);
+                }))
+                .child(Icon::new(IconName::ArrowLeft).text_color(cx.theme().muted_foreground))
+                .into_any_element(),
             ViewMode::AiResponse => div()
                 .id("back-ai")
                 .cursor_pointer()
@@ -900,6 +2894,41 @@ impl gpui::Render for LauncherView {
 
         // List content based on mode
         let list_content = match self.view_mode {
+            ViewMode::Main if self.show_inspector => {
+                let delegate = self.list_state.read(cx).delegate();
+                let selected_item = delegate
+                    .selected_index()
+                    .and_then(|idx| delegate.get_item_at(idx));
+
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .flex()
+                    .flex_row()
+                    .child(
+                        image_cache(retain_all("app-icons"))
+                            .w(Length::Definite(gpui::DefiniteLength::Fraction(0.5)))
+                            .h_full()
+                            .py_2()
+                            .child(List::new(&self.list_state)),
+                    )
+                    .child(
+                        div()
+                            .w(theme.layout.separator_width)
+                            .h_full()
+                            .bg(theme.window_border),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .h_full()
+                            .bg(theme.item_background)
+                            .rounded(theme.item_border_radius)
+                            .overflow_hidden()
+                            .child(render_inspector(selected_item.as_ref())),
+                    )
+                    .into_any_element()
+            }
             ViewMode::Main => image_cache(retain_all("app-icons"))
                 .flex_1()
                 .overflow_hidden()
@@ -925,6 +2954,7 @@ impl gpui::Render for LauncherView {
                 {
                     let selected_item =
                         clipboard_state.read(cx).delegate().selected_item().cloned();
+                    let content_filter = clipboard_state.read(cx).delegate().content_filter();
 
                     div()
                         .flex_1()
@@ -936,7 +2966,10 @@ impl gpui::Render for LauncherView {
                             div()
                                 .w(Length::Definite(gpui::DefiniteLength::Fraction(0.5)))
                                 .h_full()
-                                .child(List::new(clipboard_state)),
+                                .flex()
+                                .flex_col()
+                                .child(self.render_clipboard_filter_bar(content_filter, cx))
+                                .child(div().flex_1().min_h_0().child(List::new(clipboard_state))),
                         )
                         // Separator
                         .child(
@@ -953,7 +2986,11 @@ impl gpui::Render for LauncherView {
                                 .bg(theme.item_background)
                                 .rounded(theme.item_border_radius)
                                 .overflow_hidden()
-                                .child(self.render_clipboard_preview(selected_item.as_ref())),
+                                .child(self.render_clipboard_preview(
+                                    selected_item.as_ref(),
+                                    window,
+                                    cx,
+                                )),
                         )
                         .into_any_element()
                 } else {
@@ -973,6 +3010,38 @@ impl gpui::Render for LauncherView {
                     div().flex_1().into_any_element()
                 }
             }
+            ViewMode::AppActions => {
+                if let Some(actions_state) = self
+                    .app_actions_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    image_cache(retain_all("app-icons"))
+                        .flex_1()
+                        .overflow_hidden()
+                        .py_2()
+                        .child(List::new(actions_state))
+                        .into_any_element()
+                } else {
+                    div().flex_1().into_any_element()
+                }
+            }
+            ViewMode::ActionMenu => {
+                if let Some(actions_state) = self
+                    .action_menu_mode_handler
+                    .as_ref()
+                    .map(|h| h.list_state())
+                {
+                    image_cache(retain_all("app-icons"))
+                        .flex_1()
+                        .overflow_hidden()
+                        .py_2()
+                        .child(List::new(actions_state))
+                        .into_any_element()
+                } else {
+                    div().flex_1().into_any_element()
+                }
+            }
             ViewMode::AiResponse => {
                 if let Some(ref handler) = self.ai_mode_handler {
                     div()
@@ -986,6 +3055,49 @@ impl gpui::Render for LauncherView {
             }
         };
 
+        // A pending confirmation replaces the list
+        let list_content = match &self.pending_action {
+            Some(action) => self.render_confirmation(action).into_any_element(),
+            None => list_content,
+        };
+
+        // Footer with the live result count, and whether sticky mode is on
+        let sticky_label = self.is_sticky().then(|| {
+            div()
+                .font_weight(gpui::FontWeight::SEMIBOLD)
+                .text_color(theme.item_title_color)
+                .child("Sticky (Ctrl+S)")
+        });
+        let key_hints = (config.show_key_hints && self.pending_action.is_none())
+            .then(|| self.key_hints(window))
+            .flatten();
+        let count_label = self.result_count_label(cx);
+        let footer = (count_label.is_some() || key_hints.is_some()).then(|| {
+            div()
+                .w_full()
+                .px(theme.item_padding_x)
+                .py_1()
+                .border_t_1()
+                .border_color(cx.theme().border)
+                .flex()
+                .flex_row()
+                .justify_between()
+                .gap_4()
+                .text_xs()
+                .text_color(theme.item_description_color)
+                .child(div().children(count_label))
+                .child(
+                    div()
+                        .flex()
+                        .flex_row()
+                        .gap_4()
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .children(key_hints)
+                        .children(sticky_label),
+                )
+        });
+
         // Outer container - fullscreen with centered content
         let on_hide = self.on_hide.clone();
         div()
@@ -993,14 +3105,52 @@ impl gpui::Render for LauncherView {
             .key_context("LauncherView")
             .on_action(cx.listener(Self::select_next))
             .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::page_up))
+            .on_action(cx.listener(Self::page_down))
+            .on_action(cx.listener(Self::select_first))
+            .on_action(cx.listener(Self::select_last))
             .on_action(cx.listener(Self::select_tab))
             .on_action(cx.listener(Self::select_tab_prev))
             .on_action(cx.listener(Self::confirm))
             .on_action(cx.listener(Self::cancel))
             .on_action(cx.listener(Self::go_back))
+            .on_action(cx.listener(Self::paste_formatted))
+            .on_action(cx.listener(Self::cycle_clipboard_source))
+            .on_action(cx.listener(Self::toggle_pin))
+            .on_action(cx.listener(Self::edit_tags))
+            .on_action(cx.listener(Self::delete_item))
+            .on_action(cx.listener(Self::clear_clipboard))
+            .on_action(cx.listener(Self::open_url))
+            .on_action(cx.listener(Self::reveal_in_folder))
+            .on_action(cx.listener(Self::toggle_qr_code))
+            .on_action(cx.listener(Self::toggle_inspector))
+            .on_action(cx.listener(Self::copy_file_contents))
+            .on_action(cx.listener(Self::save_image))
+            .on_action(cx.listener(Self::recognize_text))
+            .on_action(cx.listener(Self::scroll_preview_up))
+            .on_action(cx.listener(Self::scroll_preview_down))
+            .on_action(cx.listener(Self::close_window))
+            .on_action(cx.listener(Self::toggle_window_scope))
+            .on_action(cx.listener(Self::toggle_section))
+            .on_action(cx.listener(Self::expand_sections))
+            .on_action(cx.listener(Self::expand_results))
+            .on_action(cx.listener(Self::select_left))
+            .on_action(cx.listener(Self::select_right))
+            .on_action(cx.listener(Self::cycle_calculator_format))
+            .on_action(cx.listener(Self::toggle_sticky))
+            .on_action(cx.listener(Self::show_app_actions))
+            .on_action(cx.listener(Self::cycle_skin_tone))
+            .on_action(cx.listener(Self::filter_all))
+            .on_action(cx.listener(Self::filter_text))
+            .on_action(cx.listener(Self::filter_images))
+            .on_action(cx.listener(Self::filter_files))
+            .on_action(cx.listener(Self::filter_colors))
             .size_full()
             .flex()
-            .items_center()
+            .map(|this| match config.window_anchor {
+                WindowAnchor::Center => this.items_center(),
+                WindowAnchor::TopCenter => this.items_start().pt(px(config.window_margin_top)),
+            })
             .justify_center()
             // Click on backdrop to close
             .on_mouse_down(gpui::MouseButton::Left, move |_event, _window, _cx| {
@@ -1023,6 +3173,7 @@ impl gpui::Render for LauncherView {
                     .border_color(theme.window_border)
                     .rounded(theme.window_border_radius)
                     .overflow_hidden()
+                    .relative()
                     // Stop click propagation to backdrop
                     .on_mouse_down(gpui::MouseButton::Left, |_event, _window, cx| {
                         cx.stop_propagation();
@@ -1043,7 +3194,30 @@ impl gpui::Render for LauncherView {
                             ),
                     )
                     // List content
-                    .child(list_content),
+                    .child(list_content)
+                    // Result count
+                    .children(footer)
+                    .when(self.copied_toast_visible, |this| {
+                        this.child(
+                            self.render_toast(
+                                "Copied!".into(),
+                                self.current_theme.item_title_color,
+                            ),
+                        )
+                    })
+                    .when_some(
+                        self.status_message
+                            .clone()
+                            .filter(|_| !self.copied_toast_visible),
+                        |this, (message, is_error)| {
+                            let color = if is_error {
+                                self.current_theme.ai.error_title_color
+                            } else {
+                                self.current_theme.item_title_color
+                            };
+                            this.child(self.render_toast(message, color))
+                        },
+                    ),
             )
     }
 }
@@ -14,7 +14,7 @@ use gpui::{App, IntoElement, SharedString, StyleRefinement, Window, div, prelude
 use gpui_component::highlighter::HighlightTheme;
 use gpui_component::text::{TextView, TextViewStyle};
 
-use crate::ui::theme::theme;
+use crate::ui::theme::{ThemeVariant, theme};
 
 /// Render markdown text using gpui-component's TextView.
 ///
@@ -38,13 +38,9 @@ pub fn render_markdown_with_id(
 ) -> impl IntoElement {
     let t = theme();
 
-    // Determine if dark theme based on background lightness
-    let is_dark = t.window_background.l < 0.5;
-
-    let highlight_theme = if is_dark {
-        HighlightTheme::default_dark()
-    } else {
-        HighlightTheme::default_light()
+    let highlight_theme = match t.variant() {
+        ThemeVariant::Dark => HighlightTheme::default_dark(),
+        ThemeVariant::Light => HighlightTheme::default_light(),
     };
 
     let code_block_bg = t.item_background_selected;
@@ -2,6 +2,7 @@ pub mod components;
 pub mod core;
 pub mod delegates;
 pub mod icon;
+pub mod icon_cache;
 pub mod launcher;
 pub mod markdown;
 pub mod modes;
@@ -0,0 +1,85 @@
+//! Action menu mode handler.
+//!
+//! Lists the actions grouped under a submenu (e.g. the power menu's
+//! Shutdown, Reboot, ...) and runs the selected one.
+
+use crate::items::{ActionItem, SubmenuItem};
+use crate::ui::delegates::ActionListDelegate;
+use gpui::{AppContext, Context, Entity, Subscription, Window};
+use gpui_component::input::{InputEvent, InputState};
+use gpui_component::list::ListState;
+use std::sync::Arc;
+
+/// Handler for a submenu of actions.
+pub struct ActionMenuModeHandler {
+    /// The action list state
+    list_state: Entity<ListState<ActionListDelegate>>,
+    /// Subscription to input changes (for filtering)
+    _input_subscription: Subscription,
+}
+
+impl ActionMenuModeHandler {
+    /// Create a new action menu mode handler.
+    ///
+    /// # Parameters
+    /// - `input_state`: The input field state
+    /// - `actions`: The actions listed in the menu
+    /// - `on_confirm`: Callback running the selected action (Enter)
+    /// - `window`: The window context
+    /// - `cx`: The GPUI context
+    pub fn new<T: 'static>(
+        input_state: &Entity<InputState>,
+        actions: Vec<ActionItem>,
+        on_confirm: Arc<dyn Fn(&ActionItem) + Send + Sync>,
+        window: &mut Window,
+        cx: &mut Context<T>,
+    ) -> Self {
+        let mut delegate = ActionListDelegate::new(actions);
+        delegate.set_on_confirm(move |action| on_confirm(action));
+
+        let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+
+        // Subscribe to input for filtering
+        let list_state_for_search = list_state.clone();
+        let input_subscription = cx.subscribe(input_state, move |_this, input, event, cx| {
+            if let InputEvent::Change = event {
+                let query = input.read(cx).value().to_string();
+                list_state_for_search.update(cx, |state, cx| {
+                    state.delegate_mut().set_query(query);
+                    cx.notify();
+                });
+            }
+        });
+
+        Self {
+            list_state,
+            _input_subscription: input_subscription,
+        }
+    }
+
+    /// Get the list state for rendering.
+    pub fn list_state(&self) -> &Entity<ListState<ActionListDelegate>> {
+        &self.list_state
+    }
+
+    /// Update input placeholder when entering the action menu.
+    pub fn setup_input(
+        input_state: &mut InputState,
+        submenu: &SubmenuItem,
+        window: &mut Window,
+        cx: &mut Context<InputState>,
+    ) {
+        input_state.set_value("", window, cx);
+        input_state.set_placeholder(format!("{} actions...", submenu.name), window, cx);
+    }
+
+    /// Restore input placeholder when exiting the action menu.
+    pub fn restore_input(
+        input_state: &mut InputState,
+        window: &mut Window,
+        cx: &mut Context<InputState>,
+    ) {
+        input_state.set_value("", window, cx);
+        input_state.set_placeholder("Search applications...", window, cx);
+    }
+}
@@ -0,0 +1,85 @@
+//! Desktop actions mode handler.
+//!
+//! Lists the additional actions of an application's desktop entry
+//! (e.g. Firefox's "New Private Window") and launches the selected one.
+
+use crate::items::ApplicationItem;
+use crate::ui::delegates::AppActionListDelegate;
+use gpui::{AppContext, Context, Entity, Subscription, Window};
+use gpui_component::input::{InputEvent, InputState};
+use gpui_component::list::ListState;
+use std::sync::Arc;
+
+/// Handler for the desktop actions submenu.
+pub struct AppActionsModeHandler {
+    /// The action list state
+    list_state: Entity<ListState<AppActionListDelegate>>,
+    /// Subscription to input changes (for filtering)
+    _input_subscription: Subscription,
+}
+
+impl AppActionsModeHandler {
+    /// Create a new actions mode handler for an application.
+    ///
+    /// # Parameters
+    /// - `input_state`: The input field state
+    /// - `app`: The application whose actions are listed
+    /// - `on_confirm`: Callback launching the selected action (Enter)
+    /// - `window`: The window context
+    /// - `cx`: The GPUI context
+    pub fn new<T: 'static>(
+        input_state: &Entity<InputState>,
+        app: &ApplicationItem,
+        on_confirm: Arc<dyn Fn(&ApplicationItem) + Send + Sync>,
+        window: &mut Window,
+        cx: &mut Context<T>,
+    ) -> Self {
+        let mut delegate = AppActionListDelegate::new(app.action_items());
+        delegate.set_on_confirm(move |action| on_confirm(action));
+
+        let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+
+        // Subscribe to input for filtering
+        let list_state_for_search = list_state.clone();
+        let input_subscription = cx.subscribe(input_state, move |_this, input, event, cx| {
+            if let InputEvent::Change = event {
+                let query = input.read(cx).value().to_string();
+                list_state_for_search.update(cx, |state, cx| {
+                    state.delegate_mut().set_query(query);
+                    cx.notify();
+                });
+            }
+        });
+
+        Self {
+            list_state,
+            _input_subscription: input_subscription,
+        }
+    }
+
+    /// Get the list state for rendering.
+    pub fn list_state(&self) -> &Entity<ListState<AppActionListDelegate>> {
+        &self.list_state
+    }
+
+    /// Update input placeholder when entering actions mode.
+    pub fn setup_input(
+        input_state: &mut InputState,
+        app_name: &str,
+        window: &mut Window,
+        cx: &mut Context<InputState>,
+    ) {
+        input_state.set_value("", window, cx);
+        input_state.set_placeholder(format!("{} actions...", app_name), window, cx);
+    }
+
+    /// Restore input placeholder when exiting actions mode.
+    pub fn restore_input(
+        input_state: &mut InputState,
+        window: &mut Window,
+        cx: &mut Context<InputState>,
+    ) {
+        input_state.set_value("", window, cx);
+        input_state.set_placeholder("Search applications...", window, cx);
+    }
+}
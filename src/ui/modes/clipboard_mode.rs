@@ -5,14 +5,17 @@
 //! - Setting up input filtering
 //! - Handling clipboard item selection and pasting
 
-use crate::clipboard::{
-    ClipboardContent, copy_image_to_clipboard, copy_to_clipboard, data::search_items,
-};
-use crate::ui::delegates::ClipboardListDelegate;
-use gpui::{AppContext, Context, Entity, Subscription, Window};
+use crate::clipboard::data::{eviction_count, search_items};
+use crate::clipboard::{ClipboardSelection, copy_item};
+use crate::ui::delegates::{ClipboardContentFilter, ClipboardListDelegate};
+use gpui::{App, AppContext, AsyncApp, Context, Entity, Subscription, Task, WeakEntity, Window};
 use gpui_component::input::{InputEvent, InputState};
 use gpui_component::list::ListState;
 use std::sync::Arc;
+use std::time::Duration;
+
+/// How often the open history checks for evicted items.
+const EVICTION_POLL_INTERVAL: Duration = Duration::from_millis(500);
 
 /// Handler for clipboard history mode.
 pub struct ClipboardModeHandler {
@@ -20,53 +23,29 @@ pub struct ClipboardModeHandler {
     list_state: Entity<ListState<ClipboardListDelegate>>,
     /// Subscription to input changes (for filtering)
     _input_subscription: Subscription,
+    /// Task dropping items evicted from the history while the list is open
+    _eviction_task: Task<()>,
 }
 
 impl ClipboardModeHandler {
     /// Create a new clipboard mode handler.
+    ///
+    /// `on_copied` runs after an item was copied.
     pub fn new<T: 'static>(
         input_state: &Entity<InputState>,
-        on_hide: Arc<dyn Fn() + Send + Sync>,
+        on_copied: Arc<dyn Fn() + Send + Sync>,
         window: &mut Window,
         cx: &mut Context<T>,
     ) -> Self {
         // Create delegate with initial empty search
         let mut delegate = ClipboardListDelegate::new(search_items(""));
 
-        // Set up confirm callback (copy item and hide)
-        delegate.set_on_confirm(move |item| {
-            match &item.content {
-                ClipboardContent::Text(t) => {
-                    if let Err(e) = copy_to_clipboard(t) {
-                        tracing::warn!(%e, "Failed to copy text to clipboard");
-                    }
-                }
-                ClipboardContent::Image {
-                    width,
-                    height,
-                    rgba_bytes,
-                } => {
-                    if let Err(e) = copy_image_to_clipboard(*width, *height, rgba_bytes) {
-                        tracing::warn!(%e, "Failed to copy image to clipboard");
-                    }
-                }
-                ClipboardContent::FilePaths(paths) => {
-                    let text = paths
-                        .iter()
-                        .filter_map(|p| p.to_str())
-                        .collect::<Vec<_>>()
-                        .join("\n");
-                    if let Err(e) = copy_to_clipboard(&text) {
-                        tracing::warn!(%e, "Failed to copy file paths to clipboard");
-                    }
-                }
-                ClipboardContent::RichText { plain, .. } => {
-                    if let Err(e) = copy_to_clipboard(plain) {
-                        tracing::warn!(%e, "Failed to copy rich text to clipboard");
-                    }
-                }
+        // Set up confirm callback (copy item into the target selection and report it)
+        delegate.set_on_confirm(move |item, selection, formatted| {
+            if let Err(e) = copy_item(item, selection, formatted) {
+                tracing::warn!(%e, "Failed to copy clipboard item");
             }
-            on_hide();
+            on_copied();
         });
 
         // Create list state
@@ -84,9 +63,33 @@ impl ClipboardModeHandler {
             }
         });
 
+        // New copies may evict the oldest items while the list is open
+        let list_state_for_eviction = list_state.clone();
+        let eviction_task = cx.spawn(async move |_this: WeakEntity<T>, cx: &mut AsyncApp| {
+            let mut seen = eviction_count();
+            loop {
+                cx.background_executor().timer(EVICTION_POLL_INTERVAL).await;
+                let evictions = eviction_count();
+                if evictions == seen {
+                    continue;
+                }
+                seen = evictions;
+                let result = cx.update(|cx| {
+                    list_state_for_eviction.update(cx, |state, cx| {
+                        state.delegate_mut().drop_evicted();
+                        cx.notify();
+                    });
+                });
+                if result.is_err() {
+                    break;
+                }
+            }
+        });
+
         Self {
             list_state,
             _input_subscription: subscription,
+            _eviction_task: eviction_task,
         }
     }
 
@@ -95,6 +98,116 @@ impl ClipboardModeHandler {
         &self.list_state
     }
 
+    /// Cycle which selection (clipboard/primary) the history is filtered by.
+    pub fn cycle_source_filter<T: 'static>(
+        &self,
+        input_state: &Entity<InputState>,
+        window: &mut Window,
+        cx: &mut Context<T>,
+    ) {
+        let source_filter = self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().cycle_source_filter();
+            cx.notify();
+            state.delegate().source_filter()
+        });
+
+        input_state.update(cx, |input, cx| {
+            input.set_placeholder(search_placeholder(source_filter), window, cx);
+        });
+    }
+
+    /// Start editing the tags of the selected item in the search input.
+    ///
+    /// Returns false if no item is selected.
+    pub fn start_tag_edit<T: 'static>(
+        &self,
+        input_state: &Entity<InputState>,
+        window: &mut Window,
+        cx: &mut Context<T>,
+    ) -> bool {
+        let Some(tags) = self
+            .list_state
+            .update(cx, |state, _cx| state.delegate_mut().start_tag_edit())
+        else {
+            return false;
+        };
+        input_state.update(cx, |input, cx| {
+            input.set_value(tags, window, cx);
+            input.set_placeholder("Tags, separated by spaces...", window, cx);
+        });
+        true
+    }
+
+    /// Save the tags typed into the search input and restore the search.
+    ///
+    /// Returns the new tags, or None if no tags were being edited.
+    pub fn finish_tag_edit<T: 'static>(
+        &self,
+        input_state: &Entity<InputState>,
+        window: &mut Window,
+        cx: &mut Context<T>,
+    ) -> Option<Vec<String>> {
+        let (tags, query, source_filter) = self.list_state.update(cx, |state, cx| {
+            let delegate = state.delegate_mut();
+            let (tags, query) = delegate.finish_tag_edit()?;
+            let source_filter = delegate.source_filter();
+            cx.notify();
+            Some((tags, query, source_filter))
+        })?;
+        Self::restore_search(input_state, query, source_filter, window, cx);
+        Some(tags)
+    }
+
+    /// Discard the tags typed into the search input and restore the search.
+    ///
+    /// Returns false if no tags were being edited.
+    pub fn cancel_tag_edit<T: 'static>(
+        &self,
+        input_state: &Entity<InputState>,
+        window: &mut Window,
+        cx: &mut Context<T>,
+    ) -> bool {
+        let Some((query, source_filter)) = self.list_state.update(cx, |state, _cx| {
+            let delegate = state.delegate_mut();
+            Some((delegate.cancel_tag_edit()?, delegate.source_filter()))
+        }) else {
+            return false;
+        };
+        Self::restore_search(input_state, query, source_filter, window, cx);
+        true
+    }
+
+    /// Whether the search input is editing the tags of an item.
+    pub fn is_editing_tags(&self, cx: &App) -> bool {
+        self.list_state.read(cx).delegate().is_editing_tags()
+    }
+
+    /// Put the search query and its placeholder back into the input.
+    fn restore_search<T: 'static>(
+        input_state: &Entity<InputState>,
+        query: String,
+        source_filter: Option<ClipboardSelection>,
+        window: &mut Window,
+        cx: &mut Context<T>,
+    ) {
+        input_state.update(cx, |input, cx| {
+            input.set_value(query, window, cx);
+            input.set_placeholder(search_placeholder(source_filter), window, cx);
+        });
+    }
+
+    /// Switch the content type filter of the history list.
+    pub fn set_content_filter<T: 'static>(
+        &self,
+        filter: ClipboardContentFilter,
+        cx: &mut Context<T>,
+    ) {
+        self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().set_content_filter(filter);
+            cx.notify();
+        });
+    }
+
     /// Update input placeholder when entering clipboard mode.
     pub fn setup_input(
         input_state: &mut InputState,
@@ -115,3 +228,11 @@ impl ClipboardModeHandler {
         input_state.set_placeholder("Search applications...", window, cx);
     }
 }
+
+/// Get the search placeholder for the given source filter.
+fn search_placeholder(source_filter: Option<ClipboardSelection>) -> String {
+    match source_filter {
+        Some(source) => format!("Search {} history...", source.label().to_lowercase()),
+        None => "Search clipboard history...".to_string(),
+    }
+}
@@ -6,9 +6,9 @@
 //! - Handling emoji selection and copying
 
 use crate::clipboard::copy_to_clipboard;
-use crate::emoji::all_emojis;
+use crate::emoji::{all_emojis, recent_emojis};
 use crate::ui::delegates::EmojiGridDelegate;
-use gpui::{AppContext, Context, Entity, Subscription, Window};
+use gpui::{AppContext, Context, Entity, Subscription, Window, px};
 use gpui_component::input::{InputEvent, InputState};
 use gpui_component::list::ListState;
 use std::sync::Arc;
@@ -23,24 +23,29 @@ pub struct EmojiModeHandler {
 
 impl EmojiModeHandler {
     /// Create a new emoji mode handler.
+    ///
+    /// `on_copied` runs after an emoji was copied.
     pub fn new<T: 'static>(
         input_state: &Entity<InputState>,
-        on_hide: Arc<dyn Fn() + Send + Sync>,
+        on_copied: Arc<dyn Fn() + Send + Sync>,
         window: &mut Window,
         cx: &mut Context<T>,
     ) -> Self {
-        // Create delegate with theme-based column count
+        // Create delegate with as many columns as fit the window
+        let theme = crate::ui::theme::theme();
+        let grid_width = px(crate::config::window_width()) - theme.item_margin_x * 2.0;
         let mut delegate = EmojiGridDelegate::new(
             all_emojis().to_vec(),
-            crate::ui::theme::theme().emoji.columns,
+            recent_emojis(),
+            theme.emoji.columns_for_width(grid_width),
         );
 
-        // Set up confirm callback (copy emoji and hide)
+        // Set up confirm callback (copy emoji and report it)
         delegate.set_on_confirm(move |emoji| {
             if let Err(e) = copy_to_clipboard(&emoji.emoji) {
                 tracing::warn!(%e, "Failed to copy emoji to clipboard");
             }
-            on_hide();
+            on_copied();
         });
 
         // Create list state
@@ -4,12 +4,16 @@
 //! the mode-specific logic, state, and UI coordination. This keeps the main
 //! launcher clean and focused on routing/coordination.
 
+pub mod action_menu_mode;
 pub mod ai_mode;
+pub mod app_actions_mode;
 pub mod clipboard_mode;
 pub mod emoji_mode;
 pub mod theme_mode;
 
+pub use action_menu_mode::ActionMenuModeHandler;
 pub use ai_mode::{AiModeAccess, AiModeHandler};
+pub use app_actions_mode::AppActionsModeHandler;
 pub use clipboard_mode::ClipboardModeHandler;
 pub use emoji_mode::EmojiModeHandler;
 pub use theme_mode::ThemeModeHandler;
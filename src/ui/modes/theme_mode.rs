@@ -6,10 +6,10 @@
 //! - Reverting to previous theme on cancel
 //! - Persisting theme selection on confirm
 
-use crate::config::{list_all_themes_with_source, load_theme, update_config};
+use crate::config::{list_all_themes_with_source, load_theme};
 use crate::items::ThemeItem;
 use crate::ui::delegates::ThemeListDelegate;
-use crate::ui::theme::LauncherTheme;
+use crate::ui::theme::{LauncherTheme, save_theme_name};
 use gpui::{AppContext, Context, Entity, Subscription, Window};
 use gpui_component::input::{InputEvent, InputState};
 use gpui_component::list::ListState;
@@ -72,11 +72,9 @@ impl ThemeModeHandler {
         // Set up confirm callback (save theme and confirm)
         let on_confirm_clone = on_confirm.clone();
         delegate.set_on_confirm(move |theme_item| {
-            // Update config (persists to disk only if config file exists)
-            let theme_name = theme_item.name.clone();
-            update_config(|config| {
-                config.theme = theme_name;
-            });
+            // Update config (persists to disk only if config file exists),
+            // as the theme of the active light or dark variant
+            save_theme_name(theme_item.name.clone());
             // Call confirm callback
             on_confirm_clone(theme_item.name.clone());
         });
@@ -1,4 +1,6 @@
-use gpui::{Hsla, Pixels, hsla, px};
+use crate::config::Appearance;
+use gpui::{App, Hsla, Pixels, hsla, px};
+use gpui_component::theme::{Theme, ThemeMode};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Custom serde module for Hsla colors
@@ -252,7 +254,8 @@ pub struct ActionIndicatorTheme {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct EmojiTheme {
-    /// Number of columns in the emoji grid
+    /// Maximum number of columns in the emoji grid (fewer are used when
+    /// they don't fit the window width)
     pub columns: usize,
     /// Size of each emoji cell (width and height)
     #[serde(with = "pixels_serde")]
@@ -417,6 +420,12 @@ pub struct LauncherTheme {
     pub item_title_color: Hsla,
     #[serde(with = "hsla_serde")]
     pub item_description_color: Hsla,
+    /// Font size of item titles and preview text
+    #[serde(with = "pixels_serde")]
+    pub item_title_font_size: Pixels,
+    /// Font size of item descriptions, timestamps and captions
+    #[serde(with = "pixels_serde")]
+    pub item_description_font_size: Pixels,
     #[serde(with = "pixels_serde")]
     pub item_title_line_height: Pixels,
     #[serde(with = "pixels_serde")]
@@ -489,6 +498,14 @@ impl Default for EmojiTheme {
     }
 }
 
+impl EmojiTheme {
+    /// Number of grid columns fitting in the given width, at most `columns`.
+    pub fn columns_for_width(&self, width: Pixels) -> usize {
+        let fitting = ((width + self.cell_gap) / (self.cell_size + self.cell_gap)).floor();
+        (fitting.max(1.0) as usize).min(self.columns.max(1))
+    }
+}
+
 impl Default for AiTheme {
     fn default() -> Self {
         Self {
@@ -574,6 +591,8 @@ impl Default for LauncherTheme {
             // Item content
             item_title_color: hsla(0.0, 0.0, 1.0, 0.9), // 90% white
             item_description_color: hsla(0.0, 0.0, 1.0, 0.4), // 40% white
+            item_title_font_size: px(14.0),
+            item_description_font_size: px(12.0),
             item_title_line_height: px(16.0),
             item_content_height: px(34.0),
 
@@ -600,6 +619,58 @@ impl Default for LauncherTheme {
 }
 
 impl LauncherTheme {
+    /// The built-in light theme ("default-light").
+    pub fn light() -> Self {
+        // Dark text and overlays on a light background
+        let ink = |alpha| hsla(0.0, 0.0, 0.0, alpha);
+
+        let mut theme = Self {
+            name: "default-light".to_string(),
+            window_background: hsla(0.0, 0.0, 0.97, 0.85),
+            window_border: ink(0.12),
+            item_background_selected: ink(0.06),
+            item_title_color: ink(0.87),
+            item_description_color: ink(0.5),
+            icon_placeholder_background: ink(0.05),
+            icon_placeholder_color: ink(0.35),
+            empty_state_color: ink(0.35),
+            ..Self::default()
+        };
+
+        theme.calculator.icon_color = hsla(210.0 / 360.0, 0.7, 0.4, 1.0);
+        theme.calculator.error_color = hsla(15.0 / 360.0, 0.7, 0.45, 1.0);
+        theme.action_indicator.label_color = ink(0.45);
+        theme.action_indicator.key_background = ink(0.05);
+        theme.action_indicator.key_border = ink(0.15);
+        theme.action_indicator.key_color = ink(0.6);
+        theme.emoji.cell_selected_bg = ink(0.08);
+        theme.ai.error_background = hsla(0.0, 0.7, 0.92, 1.0);
+        theme.ai.error_title_color = hsla(0.0, 0.7, 0.4, 1.0);
+        theme.ai.error_message_color = hsla(0.0, 0.6, 0.3, 1.0);
+        theme.ai.user_bubble_text_color = ink(0.85);
+        theme.section_header.color = ink(0.45);
+        theme
+    }
+
+    /// Whether this is a light or dark theme, judged by its background.
+    pub fn variant(&self) -> ThemeVariant {
+        if self.window_background.l < 0.5 {
+            ThemeVariant::Dark
+        } else {
+            ThemeVariant::Light
+        }
+    }
+
+    /// Scale the item text sizes (and the heights fitting them) by `scale`.
+    pub fn scaled(mut self, scale: f32) -> Self {
+        self.item_title_font_size *= scale;
+        self.item_description_font_size *= scale;
+        self.item_title_line_height *= scale;
+        self.item_content_height *= scale;
+        self.layout.item_description_height *= scale;
+        self
+    }
+
     /// Calculate the maximum text width for item content.
     /// Accounts for window width, margins, padding, icon, and optionally action indicator.
     pub fn max_text_width(&self, window_width: Pixels, with_action_indicator: bool) -> Pixels {
@@ -618,6 +689,46 @@ impl LauncherTheme {
     }
 }
 
+/// Light or dark variant of the launcher theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeVariant {
+    Light,
+    Dark,
+}
+
+/// Resolve the configured `appearance` to the variant to use now.
+///
+/// In auto mode this asks the desktop portal for the system preference,
+/// using the dark variant if there is none.
+pub fn current_variant() -> ThemeVariant {
+    match crate::config::config().appearance {
+        Appearance::Light => ThemeVariant::Light,
+        Appearance::Dark => ThemeVariant::Dark,
+        Appearance::Auto => {
+            crate::desktop::color_scheme::system_color_scheme().unwrap_or(ThemeVariant::Dark)
+        }
+    }
+}
+
+/// Switch to the other variant and persist it as the `appearance`.
+pub fn toggle_variant() {
+    let appearance = match current_variant() {
+        ThemeVariant::Light => Appearance::Dark,
+        ThemeVariant::Dark => Appearance::Light,
+    };
+    crate::config::update_config(|config| config.appearance = appearance);
+    sync_theme_from_config();
+}
+
+/// Persist a theme name as the theme of the active variant.
+pub fn save_theme_name(name: String) {
+    let variant = current_variant();
+    crate::config::update_config(|config| match variant {
+        ThemeVariant::Light => config.light_theme = name,
+        ThemeVariant::Dark => config.theme = name,
+    });
+}
+
 /// Global theme instance (cached for performance, synced from config).
 static THEME: std::sync::RwLock<Option<LauncherTheme>> = std::sync::RwLock::new(None);
 
@@ -651,3 +762,26 @@ pub fn sync_theme_from_config() {
     let mut write_lock = THEME.write().unwrap();
     *write_lock = Some(loaded_theme);
 }
+
+/// Apply the light or dark mode of the current theme to gpui-component, whose
+/// widgets (like the search input) draw with their own theme.
+pub fn apply_component_theme(cx: &mut App) {
+    let (mode, separator) = match theme().variant() {
+        ThemeVariant::Light => (ThemeMode::Light, hsla(0.0, 0.0, 0.0, 0.1)),
+        ThemeVariant::Dark => (ThemeMode::Dark, hsla(0.0, 0.0, 1.0, 0.1)),
+    };
+    Theme::change(mode, None, cx);
+
+    // Customize theme for transparent background and no borders
+    let theme = Theme::global_mut(cx);
+    theme.background = hsla(0.0, 0.0, 0.0, 0.0); // Fully transparent
+    theme.window_border = hsla(0.0, 0.0, 0.0, 0.0); // No window border
+    theme.border = separator; // Subtle separator between search and list
+    theme.list_active_border = hsla(0.0, 0.0, 0.0, 0.0); // No selection border
+    theme.list_active = hsla(0.0, 0.0, 0.0, 0.0); // Fully transparent - we handle selection ourselves
+    theme.list_hover = hsla(0.0, 0.0, 0.0, 0.0); // Fully transparent - we handle hover ourselves
+    theme.mono_font_family = "Mononoki Nerd Font Mono".into(); // Monospace font for code blocks
+    if let Some(family) = crate::config::config().font_family {
+        theme.font_family = family.into();
+    }
+}
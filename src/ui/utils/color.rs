@@ -1,3 +1,6 @@
+/// Minimum contrast ratio of normal text for WCAG AA.
+pub const WCAG_AA_NORMAL_TEXT: f32 = 4.5;
+
 /// Represents a color with RGBA components
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Color {
@@ -18,6 +21,35 @@ impl Color {
         Self { r, g, b, a }
     }
 
+    /// Create a color from HSL components (hue in degrees, saturation and
+    /// lightness in 0.0-1.0) and an alpha in 0.0-1.0
+    pub fn from_hsla(h: f32, s: f32, l: f32, a: f32) -> Self {
+        let h = h.rem_euclid(360.0);
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let m = l - c / 2.0;
+
+        let (r, g, b) = match h as u16 {
+            0..60 => (c, x, 0.0),
+            60..120 => (x, c, 0.0),
+            120..180 => (0.0, c, x),
+            180..240 => (0.0, x, c),
+            240..300 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        let channel = |v: f32| ((v + m) * 255.0).round() as u8;
+        Self {
+            r: channel(r),
+            g: channel(g),
+            b: channel(b),
+            a: (a.clamp(0.0, 1.0) * 255.0).round() as u8,
+        }
+    }
+
     /// Convert to HSL format (hue: 0-360, saturation: 0-100, lightness: 0-100)
     pub fn to_hsl(&self) -> (u16, u8, u8) {
         let r = self.r as f32 / 255.0;
@@ -53,6 +85,27 @@ impl Color {
         (h as u16, (s * 100.0) as u8, (l * 100.0) as u8)
     }
 
+    /// Relative luminance as defined by WCAG 2, from 0.0 (black) to 1.0
+    /// (white). Alpha is ignored.
+    pub fn relative_luminance(&self) -> f32 {
+        let linear = |channel: u8| {
+            let c = channel as f32 / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// WCAG 2 contrast ratio against another color, from 1.0 (no contrast)
+    /// to 21.0 (black on white)
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
     /// Convert to RGB format string
     pub fn to_rgb_string(&self) -> String {
         format!("rgb({}, {}, {})", self.r, self.g, self.b)
@@ -85,7 +138,7 @@ impl Color {
     }
 }
 
-/// Try to parse a color string (hex, rgb, rgba, hsl, etc.)
+/// Try to parse a color string (hex, rgb, rgba, hsl, hsla or a CSS color name)
 pub fn parse_color(text: &str) -> Option<Color> {
     let text = text.trim();
 
@@ -133,9 +186,265 @@ pub fn parse_color(text: &str) -> Option<Color> {
         }
     }
 
-    None
+    // Try hsl/hsla format: hsl(h, s%, l%), hsla(h, s%, l%, a) or hsl(h s% l% / a)
+    if text.starts_with("hsl(") || text.starts_with("hsla(") {
+        return parse_hsl(text);
+    }
+
+    // Try named CSS colors
+    let lower = text.to_ascii_lowercase();
+    NAMED_COLORS
+        .iter()
+        .find(|(name, _)| *name == lower)
+        .map(|&(_, rgba)| {
+            Color::from_rgba(
+                (rgba >> 24) as u8,
+                (rgba >> 16) as u8,
+                (rgba >> 8) as u8,
+                rgba as u8,
+            )
+        })
+}
+
+/// Find the color in a text: the whole text if it's a color, otherwise the
+/// first hex, rgb or hsl value in a short single line such as the CSS
+/// declaration `color: #3366ff;`.
+///
+/// Color names only count as the whole text, so that ordinary words aren't
+/// taken for colors.
+pub fn find_color(text: &str) -> Option<Color> {
+    parse_color(text).or_else(|| find_embedded_color(text.trim()))
+}
+
+/// Find the first well-formed hex, rgb or hsl value in a short line.
+fn find_embedded_color(text: &str) -> Option<Color> {
+    if text.len() > MAX_EMBEDDED_COLOR_TEXT_LEN || text.contains('\n') {
+        return None;
+    }
+
+    text.char_indices().find_map(|(idx, c)| {
+        // Values start a word, e.g. not the `#` of an HTML entity like `&#123;`
+        let before = text[..idx].chars().next_back();
+        if before.is_some_and(|b| b.is_alphanumeric() || b == '&') {
+            return None;
+        }
+
+        let rest = &text[idx..];
+        if c == '#' {
+            let hex = &rest[1..];
+            let hex = &hex[..hex
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(hex.len())];
+            // Three digits are more likely an issue number ("fixes #123")
+            if hex.len() == 3 && hex.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            return parse_color(&rest[..=hex.len()]);
+        }
+
+        if ["rgb(", "rgba(", "hsl(", "hsla("]
+            .iter()
+            .any(|function| rest.starts_with(function))
+        {
+            return parse_color(&rest[..=rest.find(')')?]);
+        }
+        None
+    })
+}
+
+/// Parse the hsl()/hsla() functional syntax.
+///
+/// Accepts both comma and space separated components. Saturation and
+/// lightness may be given with or without a percent sign, the hue with an
+/// optional `deg` unit. Out-of-range values are clamped.
+fn parse_hsl(text: &str) -> Option<Color> {
+    let start = text.find('(')? + 1;
+    let end = text.rfind(')')?;
+    let values = text.get(start..end)?.replace('/', " ");
+
+    let parts: Vec<&str> = if values.contains(',') {
+        values.split(',').map(|s| s.trim()).collect()
+    } else {
+        values.split_whitespace().collect()
+    };
+    if parts.len() != 3 && parts.len() != 4 {
+        return None;
+    }
+
+    let h = parts[0].strip_suffix("deg").unwrap_or(parts[0]).trim();
+    let h = h.parse::<f32>().ok()?;
+    let percent = |part: &str| -> Option<f32> {
+        let value = part.strip_suffix('%').unwrap_or(part).trim();
+        Some(value.parse::<f32>().ok()? / 100.0)
+    };
+    let s = percent(parts[1])?;
+    let l = percent(parts[2])?;
+    let a = match parts.get(3) {
+        Some(alpha) if alpha.ends_with('%') => percent(alpha)?,
+        Some(alpha) => alpha.parse::<f32>().ok()?,
+        None => 1.0,
+    };
+
+    Some(Color::from_hsla(h, s, l, a))
 }
 
+/// Longest text searched for a color value within it. Anything longer is
+/// content of its own rather than a copied color.
+const MAX_EMBEDDED_COLOR_TEXT_LEN: usize = 120;
+
+/// Standard CSS named colors as 0xRRGGBBAA.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xF0F8FFFF),
+    ("antiquewhite", 0xFAEBD7FF),
+    ("aqua", 0x00FFFFFF),
+    ("aquamarine", 0x7FFFD4FF),
+    ("azure", 0xF0FFFFFF),
+    ("beige", 0xF5F5DCFF),
+    ("bisque", 0xFFE4C4FF),
+    ("black", 0x000000FF),
+    ("blanchedalmond", 0xFFEBCDFF),
+    ("blue", 0x0000FFFF),
+    ("blueviolet", 0x8A2BE2FF),
+    ("brown", 0xA52A2AFF),
+    ("burlywood", 0xDEB887FF),
+    ("cadetblue", 0x5F9EA0FF),
+    ("chartreuse", 0x7FFF00FF),
+    ("chocolate", 0xD2691EFF),
+    ("coral", 0xFF7F50FF),
+    ("cornflowerblue", 0x6495EDFF),
+    ("cornsilk", 0xFFF8DCFF),
+    ("crimson", 0xDC143CFF),
+    ("cyan", 0x00FFFFFF),
+    ("darkblue", 0x00008BFF),
+    ("darkcyan", 0x008B8BFF),
+    ("darkgoldenrod", 0xB8860BFF),
+    ("darkgray", 0xA9A9A9FF),
+    ("darkgreen", 0x006400FF),
+    ("darkgrey", 0xA9A9A9FF),
+    ("darkkhaki", 0xBDB76BFF),
+    ("darkmagenta", 0x8B008BFF),
+    ("darkolivegreen", 0x556B2FFF),
+    ("darkorange", 0xFF8C00FF),
+    ("darkorchid", 0x9932CCFF),
+    ("darkred", 0x8B0000FF),
+    ("darksalmon", 0xE9967AFF),
+    ("darkseagreen", 0x8FBC8FFF),
+    ("darkslateblue", 0x483D8BFF),
+    ("darkslategray", 0x2F4F4FFF),
+    ("darkslategrey", 0x2F4F4FFF),
+    ("darkturquoise", 0x00CED1FF),
+    ("darkviolet", 0x9400D3FF),
+    ("deeppink", 0xFF1493FF),
+    ("deepskyblue", 0x00BFFFFF),
+    ("dimgray", 0x696969FF),
+    ("dimgrey", 0x696969FF),
+    ("dodgerblue", 0x1E90FFFF),
+    ("firebrick", 0xB22222FF),
+    ("floralwhite", 0xFFFAF0FF),
+    ("forestgreen", 0x228B22FF),
+    ("fuchsia", 0xFF00FFFF),
+    ("gainsboro", 0xDCDCDCFF),
+    ("ghostwhite", 0xF8F8FFFF),
+    ("gold", 0xFFD700FF),
+    ("goldenrod", 0xDAA520FF),
+    ("gray", 0x808080FF),
+    ("green", 0x008000FF),
+    ("greenyellow", 0xADFF2FFF),
+    ("grey", 0x808080FF),
+    ("honeydew", 0xF0FFF0FF),
+    ("hotpink", 0xFF69B4FF),
+    ("indianred", 0xCD5C5CFF),
+    ("indigo", 0x4B0082FF),
+    ("ivory", 0xFFFFF0FF),
+    ("khaki", 0xF0E68CFF),
+    ("lavender", 0xE6E6FAFF),
+    ("lavenderblush", 0xFFF0F5FF),
+    ("lawngreen", 0x7CFC00FF),
+    ("lemonchiffon", 0xFFFACDFF),
+    ("lightblue", 0xADD8E6FF),
+    ("lightcoral", 0xF08080FF),
+    ("lightcyan", 0xE0FFFFFF),
+    ("lightgoldenrodyellow", 0xFAFAD2FF),
+    ("lightgray", 0xD3D3D3FF),
+    ("lightgreen", 0x90EE90FF),
+    ("lightgrey", 0xD3D3D3FF),
+    ("lightpink", 0xFFB6C1FF),
+    ("lightsalmon", 0xFFA07AFF),
+    ("lightseagreen", 0x20B2AAFF),
+    ("lightskyblue", 0x87CEFAFF),
+    ("lightslategray", 0x778899FF),
+    ("lightslategrey", 0x778899FF),
+    ("lightsteelblue", 0xB0C4DEFF),
+    ("lightyellow", 0xFFFFE0FF),
+    ("lime", 0x00FF00FF),
+    ("limegreen", 0x32CD32FF),
+    ("linen", 0xFAF0E6FF),
+    ("magenta", 0xFF00FFFF),
+    ("maroon", 0x800000FF),
+    ("mediumaquamarine", 0x66CDAAFF),
+    ("mediumblue", 0x0000CDFF),
+    ("mediumorchid", 0xBA55D3FF),
+    ("mediumpurple", 0x9370DBFF),
+    ("mediumseagreen", 0x3CB371FF),
+    ("mediumslateblue", 0x7B68EEFF),
+    ("mediumspringgreen", 0x00FA9AFF),
+    ("mediumturquoise", 0x48D1CCFF),
+    ("mediumvioletred", 0xC71585FF),
+    ("midnightblue", 0x191970FF),
+    ("mintcream", 0xF5FFFAFF),
+    ("mistyrose", 0xFFE4E1FF),
+    ("moccasin", 0xFFE4B5FF),
+    ("navajowhite", 0xFFDEADFF),
+    ("navy", 0x000080FF),
+    ("oldlace", 0xFDF5E6FF),
+    ("olive", 0x808000FF),
+    ("olivedrab", 0x6B8E23FF),
+    ("orange", 0xFFA500FF),
+    ("orangered", 0xFF4500FF),
+    ("orchid", 0xDA70D6FF),
+    ("palegoldenrod", 0xEEE8AAFF),
+    ("palegreen", 0x98FB98FF),
+    ("paleturquoise", 0xAFEEEEFF),
+    ("palevioletred", 0xDB7093FF),
+    ("papayawhip", 0xFFEFD5FF),
+    ("peachpuff", 0xFFDAB9FF),
+    ("peru", 0xCD853FFF),
+    ("pink", 0xFFC0CBFF),
+    ("plum", 0xDDA0DDFF),
+    ("powderblue", 0xB0E0E6FF),
+    ("purple", 0x800080FF),
+    ("rebeccapurple", 0x663399FF),
+    ("red", 0xFF0000FF),
+    ("rosybrown", 0xBC8F8FFF),
+    ("royalblue", 0x4169E1FF),
+    ("saddlebrown", 0x8B4513FF),
+    ("salmon", 0xFA8072FF),
+    ("sandybrown", 0xF4A460FF),
+    ("seagreen", 0x2E8B57FF),
+    ("seashell", 0xFFF5EEFF),
+    ("sienna", 0xA0522DFF),
+    ("silver", 0xC0C0C0FF),
+    ("skyblue", 0x87CEEBFF),
+    ("slateblue", 0x6A5ACDFF),
+    ("slategray", 0x708090FF),
+    ("slategrey", 0x708090FF),
+    ("snow", 0xFFFAFAFF),
+    ("springgreen", 0x00FF7FFF),
+    ("steelblue", 0x4682B4FF),
+    ("tan", 0xD2B48CFF),
+    ("teal", 0x008080FF),
+    ("thistle", 0xD8BFD8FF),
+    ("tomato", 0xFF6347FF),
+    ("transparent", 0x00000000),
+    ("turquoise", 0x40E0D0FF),
+    ("violet", 0xEE82EEFF),
+    ("wheat", 0xF5DEB3FF),
+    ("white", 0xFFFFFFFF),
+    ("whitesmoke", 0xF5F5F5FF),
+    ("yellow", 0xFFFF00FF),
+    ("yellowgreen", 0x9ACD32FF),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,4 +481,104 @@ mod tests {
         let color = Color::from_rgb(255, 128, 64);
         assert_eq!(color.to_hex(), "#FF8040");
     }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let black = Color::from_rgb(0, 0, 0);
+        let white = Color::from_rgb(255, 255, 255);
+        assert_eq!(black.relative_luminance(), 0.0);
+        assert!((white.relative_luminance() - 1.0).abs() < 1e-4);
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-3);
+        assert_eq!(white.contrast_ratio(&black), black.contrast_ratio(&white));
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+
+        // The lightest gray that passes AA on white, and the next one up
+        let pass = Color::from_rgb(0x76, 0x76, 0x76).contrast_ratio(&white);
+        let fail = Color::from_rgb(0x77, 0x77, 0x77).contrast_ratio(&white);
+        assert!(pass >= WCAG_AA_NORMAL_TEXT, "{pass}");
+        assert!(fail < WCAG_AA_NORMAL_TEXT, "{fail}");
+    }
+
+    #[test]
+    fn test_parse_hsl() {
+        let color = parse_color("hsl(210, 50%, 40%)").unwrap();
+        assert_eq!(color, Color::from_rgb(51, 102, 153));
+
+        // Space separated, unitless and with a deg unit
+        assert_eq!(parse_color("hsl(210deg 50 40)"), Some(color));
+        assert_eq!(
+            parse_color("hsl( 0 , 100% , 50% )"),
+            Some(Color::from_rgb(255, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_hsla() {
+        let color = parse_color("hsla(120, 100%, 25%, 0.5)").unwrap();
+        assert_eq!(color, Color::from_rgba(0, 128, 0, 128));
+        assert_eq!(parse_color("hsl(120 100% 25% / 50%)"), Some(color));
+    }
+
+    #[test]
+    fn test_parse_hsl_clamps() {
+        let color = parse_color("hsl(-120, 150%, 120%, 2)").unwrap();
+        assert_eq!(color, Color::from_rgb(255, 255, 255));
+        assert!(parse_color("hsl(10, 20%)").is_none());
+    }
+
+    #[test]
+    fn test_parse_named() {
+        assert_eq!(
+            parse_color("rebeccapurple"),
+            Some(Color::from_rgb(102, 51, 153))
+        );
+        assert_eq!(parse_color(" Red "), Some(Color::from_rgb(255, 0, 0)));
+        assert_eq!(parse_color("transparent").map(|c| c.a), Some(0));
+        assert!(parse_color("notacolor").is_none());
+    }
+
+    #[test]
+    fn test_find_embedded_color() {
+        assert_eq!(
+            find_color("color: #3366ff;"),
+            Some(Color::from_rgb(0x33, 0x66, 0xff))
+        );
+        assert_eq!(
+            find_color("  background-color: rgba(255, 0, 0, 0.5) !important;"),
+            Some(Color::from_rgba(255, 0, 0, 127))
+        );
+        assert_eq!(
+            find_color("--accent: hsl(210, 50%, 40%)"),
+            Some(Color::from_rgb(51, 102, 153))
+        );
+        assert_eq!(
+            find_color("border: 1px solid #fff"),
+            Some(Color::from_rgb(255, 255, 255))
+        );
+        // The first value wins
+        assert_eq!(
+            find_color("#3366ff on #fff"),
+            Some(Color::from_rgb(0x33, 0x66, 0xff))
+        );
+        // The whole text still parses as before
+        assert_eq!(find_color("red"), Some(Color::from_rgb(255, 0, 0)));
+    }
+
+    #[test]
+    fn test_find_embedded_color_rejects_non_colors() {
+        // Names only count on their own
+        assert!(find_color("a red car").is_none());
+        // Issue numbers, HTML entities and anchors
+        assert!(find_color("fixes #123").is_none());
+        assert!(find_color("&#123; and &#x41;").is_none());
+        assert!(find_color("see page#abc").is_none());
+        assert!(find_color("jump to #section-2").is_none());
+        // Malformed values
+        assert!(find_color("color: #3366fg;").is_none());
+        assert!(find_color("color: rgb(300, 0, 0);").is_none());
+        assert!(find_color("color: rgb(1, 2").is_none());
+        // Longer texts are content of their own
+        assert!(find_color(&format!("{} #3366ff", "word ".repeat(30))).is_none());
+        assert!(find_color("body {\n  color: #3366ff;\n}").is_none());
+    }
 }
@@ -67,6 +67,34 @@ pub fn is_text_ext(ext: &str) -> bool {
     )
 }
 
+/// Get the syntax highlighting language for a text file extension.
+///
+/// Returns `None` for plain formats (txt, log, ...) and languages without a
+/// known grammar, which are previewed as plain text.
+pub fn code_language(ext: &str) -> Option<&'static str> {
+    let language = match ext.to_lowercase().as_str() {
+        "md" => "markdown",
+        "rs" => "rust",
+        "py" => "python",
+        "js" | "jsx" => "javascript",
+        "ts" => "typescript",
+        "tsx" => "tsx",
+        "json" => "json",
+        "yaml" | "yml" => "yaml",
+        "toml" => "toml",
+        "html" => "html",
+        "css" => "css",
+        "sh" | "bash" => "bash",
+        "c" | "h" => "c",
+        "cpp" | "hpp" => "cpp",
+        "go" => "go",
+        "java" => "java",
+        "swift" => "swift",
+        _ => return None,
+    };
+    Some(language)
+}
+
 /// Check if a file should be previewed as an image
 pub fn should_preview_as_image(path: &Path) -> bool {
     classify_file(path) == FileType::Image
@@ -96,6 +124,16 @@ mod tests {
         assert_eq!(classify_file(&PathBuf::from("test.rs")), FileType::Text);
     }
 
+    #[test]
+    fn test_code_language() {
+        assert_eq!(code_language("rs"), Some("rust"));
+        assert_eq!(code_language("PY"), Some("python"));
+        assert_eq!(code_language("yml"), Some("yaml"));
+        assert_eq!(code_language("md"), Some("markdown"));
+        assert_eq!(code_language("txt"), None);
+        assert_eq!(code_language("log"), None);
+    }
+
     #[test]
     fn test_classify_other() {
         assert_eq!(classify_file(&PathBuf::from("test.pdf")), FileType::Other);
@@ -0,0 +1,358 @@
+//! Conversion of copied HTML to markdown for previewing.
+//!
+//! Only the formatting the markdown renderer can show is kept: emphasis,
+//! links, inline code, code blocks, headings, paragraphs and lists. Other
+//! elements are reduced to their text.
+
+/// Elements whose content is never shown.
+const SKIPPED_ELEMENTS: &[&str] = &["head", "script", "style", "template", "title"];
+
+/// Elements separated from their surroundings by a blank line.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "p",
+    "div",
+    "section",
+    "article",
+    "header",
+    "footer",
+    "blockquote",
+    "table",
+    "tr",
+];
+
+/// Convert an HTML fragment to markdown.
+///
+/// Returns None if the input has no markup or no text, so callers can fall
+/// back to the plain text version.
+pub fn html_to_markdown(html: &str) -> Option<String> {
+    let mut converter = Converter::default();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if rest.starts_with('<')
+            && let Some(end) = rest.find('>')
+        {
+            converter.tag(&rest[1..end]);
+            rest = &rest[end + 1..];
+        } else {
+            // A stray '<' is text
+            let skip = rest.chars().next().map_or(0, char::len_utf8);
+            let end = rest[skip..].find('<').map_or(rest.len(), |pos| pos + skip);
+            converter.text(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+
+    let markdown = converter.out.trim().to_string();
+    (converter.saw_tag && !markdown.is_empty()).then_some(markdown)
+}
+
+/// State of the HTML to markdown conversion.
+#[derive(Default)]
+struct Converter {
+    out: String,
+    /// Open lists, with the next number of ordered ones
+    lists: Vec<Option<usize>>,
+    /// Targets of the open links (None for anchors without one)
+    links: Vec<Option<String>>,
+    /// Depth of open elements whose content is skipped
+    skip_depth: usize,
+    /// Depth of open `<pre>` elements, whose whitespace is kept
+    pre_depth: usize,
+    saw_tag: bool,
+}
+
+impl Converter {
+    /// Handle the inside of a tag, e.g. `a href="..."` or `/b`.
+    fn tag(&mut self, tag: &str) {
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name_end = tag
+            .find(|c: char| c.is_whitespace() || c == '/')
+            .unwrap_or(tag.len());
+        let name = tag[..name_end].to_lowercase();
+        let attrs = &tag[name_end..];
+        if name.is_empty() || name.starts_with('!') || name.starts_with('?') {
+            return;
+        }
+        self.saw_tag = true;
+
+        if SKIPPED_ELEMENTS.contains(&name.as_str()) {
+            if closing {
+                self.skip_depth = self.skip_depth.saturating_sub(1);
+            } else if !attrs.trim_end().ends_with('/') {
+                self.skip_depth += 1;
+            }
+            return;
+        }
+        if self.skip_depth > 0 {
+            return;
+        }
+
+        match (name.as_str(), closing) {
+            ("b" | "strong", _) => self.out.push_str("**"),
+            ("i" | "em", _) => self.out.push('*'),
+            ("code", _) if self.pre_depth == 0 => self.out.push('`'),
+            ("a", false) => {
+                let href = attribute(attrs, "href");
+                if href.is_some() {
+                    self.out.push('[');
+                }
+                self.links.push(href);
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = self.links.pop() {
+                    self.out.push_str(&format!("]({})", href));
+                }
+            }
+            ("br", _) => self.out.push_str("  \n"),
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", false) => {
+                self.block_break();
+                let level = name[1..].parse().unwrap_or(1);
+                self.out.push_str(&"#".repeat(level));
+                self.out.push(' ');
+            }
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", true) => self.block_break(),
+            ("ul", false) => self.open_list(None),
+            ("ol", false) => {
+                let start = attribute(attrs, "start").and_then(|s| s.parse().ok());
+                self.open_list(Some(start.unwrap_or(1)));
+            }
+            ("ul" | "ol", true) => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.block_break();
+                }
+            }
+            ("li", false) => {
+                self.line_break();
+                let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                self.out.push_str(&indent);
+                match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        self.out.push_str(&format!("{}. ", number));
+                        *number += 1;
+                    }
+                    _ => self.out.push_str("- "),
+                }
+            }
+            ("pre", false) => {
+                self.block_break();
+                self.out.push_str("```\n");
+                self.pre_depth += 1;
+            }
+            ("pre", true) => {
+                self.line_break();
+                self.out.push_str("```");
+                self.pre_depth = self.pre_depth.saturating_sub(1);
+                self.block_break();
+            }
+            ("td" | "th", false) => self.out.push(' '),
+            (name, _) if BLOCK_ELEMENTS.contains(&name) => self.block_break(),
+            _ => {}
+        }
+    }
+
+    /// Add text, collapsing whitespace and escaping markdown syntax outside
+    /// of `<pre>`.
+    fn text(&mut self, text: &str) {
+        if self.skip_depth > 0 {
+            return;
+        }
+        let text = decode_entities(text);
+        if self.pre_depth > 0 {
+            self.out.push_str(&text);
+            return;
+        }
+
+        for c in text.chars() {
+            if c.is_whitespace() {
+                if !self.out.is_empty() && !self.out.ends_with([' ', '\n']) {
+                    self.out.push(' ');
+                }
+            } else {
+                if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '#') {
+                    self.out.push('\\');
+                }
+                self.out.push(c);
+            }
+        }
+    }
+
+    fn open_list(&mut self, numbering: Option<usize>) {
+        if self.lists.is_empty() {
+            self.block_break();
+        }
+        self.lists.push(numbering);
+    }
+
+    /// End the current line, unless already at the start of one.
+    fn line_break(&mut self) {
+        let trimmed = self.out.trim_end_matches(' ').len();
+        self.out.truncate(trimmed);
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    /// End the current paragraph with a blank line.
+    fn block_break(&mut self) {
+        self.line_break();
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+}
+
+/// Get the value of an attribute from the attribute part of a tag.
+///
+/// Only ASCII is lowercased, so positions in `lower` are also positions in
+/// `attrs` (full lowercasing changes the length of e.g. `İ`).
+fn attribute(attrs: &str, name: &str) -> Option<String> {
+    let lower = attrs.to_ascii_lowercase();
+    let mut search_from = 0;
+    while let Some(pos) = lower[search_from..].find(name) {
+        let start = search_from + pos;
+        search_from = start + name.len();
+        // Must be a whole attribute name followed by '='
+        let preceded_by_space = lower[..start].ends_with(char::is_whitespace);
+        let rest = attrs[search_from..].trim_start();
+        let Some(value) = rest.strip_prefix('=').filter(|_| preceded_by_space) else {
+            continue;
+        };
+        let value = value.trim_start();
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or(""),
+            _ => value
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or(""),
+        };
+        return Some(decode_entities(value));
+    }
+    None
+}
+
+/// Decode the character references of HTML text.
+///
+/// Unknown named references are left as they are.
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                _ => entity.strip_prefix('#').and_then(|code| {
+                    match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                        None => code.parse().ok(),
+                    }
+                    .and_then(char::from_u32)
+                }),
+            };
+            c.map(|c| (c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_formatting() {
+        assert_eq!(
+            html_to_markdown(r#"<b>Bold</b>, <em>italic</em> and <a href="https://x.org/?a=1&amp;b=2">a link</a>"#)
+                .as_deref(),
+            Some("**Bold**, *italic* and [a link](https://x.org/?a=1&b=2)")
+        );
+    }
+
+    #[test]
+    fn test_blocks_and_lists() {
+        let html = "<meta charset='utf-8'><h2>Title</h2><p>First\n   paragraph</p>\
+                    <ul><li>one</li><li>two<ol><li>nested</li></ol></li></ul><p>End</p>";
+        assert_eq!(
+            html_to_markdown(html).as_deref(),
+            Some("## Title\n\nFirst paragraph\n\n- one\n- two\n  1. nested\n\nEnd")
+        );
+    }
+
+    #[test]
+    fn test_skips_styles_and_escapes_text() {
+        let html = "<style>p { color: red; }</style><p>2 * 3 &lt; 7_a</p>";
+        assert_eq!(html_to_markdown(html).as_deref(), Some(r"2 \* 3 < 7\_a"));
+    }
+
+    #[test]
+    fn test_pre_keeps_whitespace() {
+        let html = "<pre><code>fn main() {\n    run();\n}</code></pre>";
+        assert_eq!(
+            html_to_markdown(html).as_deref(),
+            Some("```\nfn main() {\n    run();\n}\n```")
+        );
+    }
+
+    #[test]
+    fn test_no_markup_falls_back() {
+        assert_eq!(html_to_markdown("just text"), None);
+        assert_eq!(html_to_markdown("<div>  </div>"), None);
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &amp; b &#233; &#x1F600; &bogus;"),
+            "a & b é 😀 &bogus;"
+        );
+    }
+
+    #[test]
+    fn test_attribute_after_non_ascii_value() {
+        assert_eq!(attribute(" title=\"İİ\" href", "href"), None);
+        assert_eq!(
+            attribute(" title=\"İ\" HREF=\"x\"", "href"),
+            Some("x".to_string())
+        );
+        assert_eq!(
+            html_to_markdown("<a title=\"ẞİ\" href=\"https://a.b\">link</a>").as_deref(),
+            Some("[link](https://a.b)")
+        );
+    }
+
+    #[test]
+    fn test_non_ascii_text_after_tag() {
+        assert_eq!(html_to_markdown("<p>é</p>").as_deref(), Some("é"));
+        assert_eq!(
+            html_to_markdown("<b>😀</b> ok").as_deref(),
+            Some("**😀** ok")
+        );
+    }
+}
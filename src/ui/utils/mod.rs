@@ -1,8 +1,22 @@
 pub mod color;
 mod file_type;
+mod html;
+mod qr;
+mod structured;
+mod text;
+mod time;
 
-pub use color::{Color, parse_color};
+pub use color::{Color, WCAG_AA_NORMAL_TEXT, find_color, parse_color};
 pub use file_type::{
-    FileType, classify_file, is_image_ext, is_text_ext, should_preview_as_image,
+    FileType, classify_file, code_language, is_image_ext, is_text_ext, should_preview_as_image,
     should_preview_as_text,
 };
+pub use html::html_to_markdown;
+pub use qr::qr_code_image;
+pub use structured::{StructuredText, parse_structured_text};
+pub use text::{
+    DecodedBase64, LineRange, char_indices_to_ranges, decode_base64, fold_diacritics, format_bytes,
+    format_text_stats, is_line_range_query, looks_like_markdown, looks_like_svg, parse_line_range,
+    parse_tags, parse_url, pretty_print_json, split_tag_query,
+};
+pub use time::format_timestamp;
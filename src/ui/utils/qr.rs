@@ -0,0 +1,54 @@
+//! QR codes of clipboard text, e.g. to get a URL onto a phone.
+
+use qrcode::QrCode;
+
+/// Longest text shown as a QR code. Longer text gives codes too dense to
+/// scan off a screen.
+pub const MAX_QR_CODE_BYTES: usize = 500;
+
+/// Size of a QR code module in pixels. Rendering large keeps the modules
+/// sharp when the preview scales the image down.
+const MODULE_PIXELS: u32 = 8;
+
+/// Render text as a black on white QR code, with its quiet zone.
+///
+/// Returns an error message for empty text and text too long to scan.
+pub fn qr_code_image(text: &str) -> Result<image::RgbaImage, String> {
+    if text.trim().is_empty() {
+        return Err("Nothing to encode".to_string());
+    }
+    if text.len() > MAX_QR_CODE_BYTES {
+        return Err(format!(
+            "Too long for a QR code ({} bytes, at most {})",
+            text.len(),
+            MAX_QR_CODE_BYTES
+        ));
+    }
+
+    let code = QrCode::new(text.as_bytes()).map_err(|e| format!("Can't create QR code: {}", e))?;
+    Ok(code
+        .render::<image::Rgba<u8>>()
+        .module_dimensions(MODULE_PIXELS, MODULE_PIXELS)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qr_code_image() {
+        let image = qr_code_image("https://example.com").unwrap();
+        assert_eq!(image.width(), image.height());
+        // Version 2 (25 modules) plus a quiet zone of 4 modules on each side
+        assert_eq!(image.width(), (25 + 8) * MODULE_PIXELS);
+        assert_eq!(image.get_pixel(0, 0), &image::Rgba([255, 255, 255, 255]));
+    }
+
+    #[test]
+    fn test_rejects_unreadable_content() {
+        assert!(qr_code_image("  ").is_err());
+        assert!(qr_code_image(&"a".repeat(MAX_QR_CODE_BYTES + 1)).is_err());
+        assert!(qr_code_image(&"a".repeat(MAX_QR_CODE_BYTES)).is_ok());
+    }
+}
@@ -0,0 +1,364 @@
+//! Recognition of machine-oriented text formats, like those in QR codes.
+//!
+//! Wi-Fi credentials (`WIFI:`), locations (`geo:`) and contact cards
+//! (`MECARD:` and vCard) are broken down into labeled fields for the preview.
+
+/// Text in one of the recognized formats.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StructuredText {
+    /// Wi-Fi network credentials
+    Wifi {
+        ssid: String,
+        password: Option<String>,
+        /// Authentication type, e.g. "WPA"
+        security: Option<String>,
+        hidden: bool,
+    },
+    /// A point on the map
+    Geo {
+        latitude: f64,
+        longitude: f64,
+        /// Place name or search query from `?q=`
+        label: Option<String>,
+    },
+    /// A contact card, as labeled fields in card order
+    Contact(Vec<(&'static str, String)>),
+}
+
+impl StructuredText {
+    /// Get the fields shown in the preview, as label and value.
+    pub fn fields(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Self::Wifi {
+                ssid,
+                password,
+                security,
+                hidden,
+            } => {
+                let mut fields = vec![("Network", ssid.clone())];
+                if let Some(password) = password {
+                    fields.push(("Password", password.clone()));
+                }
+                if let Some(security) = security {
+                    fields.push(("Security", security.clone()));
+                }
+                if *hidden {
+                    fields.push(("Hidden", "Yes".to_string()));
+                }
+                fields
+            }
+            Self::Geo {
+                latitude,
+                longitude,
+                label,
+            } => {
+                let mut fields = Vec::new();
+                if let Some(label) = label {
+                    fields.push(("Place", label.clone()));
+                }
+                fields.push(("Latitude", latitude.to_string()));
+                fields.push(("Longitude", longitude.to_string()));
+                fields
+            }
+            Self::Contact(fields) => fields.clone(),
+        }
+    }
+
+    /// Get the OpenStreetMap link of a location.
+    pub fn map_url(&self) -> Option<String> {
+        match self {
+            Self::Geo {
+                latitude,
+                longitude,
+                ..
+            } => Some(format!(
+                "https://www.openstreetmap.org/?mlat={lat}&mlon={lon}#map=16/{lat}/{lon}",
+                lat = latitude,
+                lon = longitude
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Recognize Wi-Fi, geo and contact text.
+///
+/// Returns None for anything else, including malformed text in one of the
+/// formats, so it can be shown as plain text.
+pub fn parse_structured_text(text: &str) -> Option<StructuredText> {
+    let text = text.trim();
+    let (scheme, rest) = text.split_once(':')?;
+
+    match scheme.to_ascii_uppercase().as_str() {
+        "WIFI" => parse_wifi(rest),
+        "GEO" => parse_geo(rest),
+        "MECARD" => parse_mecard(rest),
+        "BEGIN" if rest.lines().next()?.trim().eq_ignore_ascii_case("VCARD") => parse_vcard(text),
+        _ => None,
+    }
+}
+
+/// Parse `T:WPA;S:name;P:password;H:true;;` (after `WIFI:`).
+fn parse_wifi(fields: &str) -> Option<StructuredText> {
+    let mut ssid = None;
+    let mut password = None;
+    let mut security = None;
+    let mut hidden = false;
+
+    for field in split_escaped(fields, ';') {
+        let Some((key, value)) = field.split_once(':') else {
+            continue;
+        };
+        let value = unescape(value);
+        match key {
+            "S" => ssid = Some(value),
+            "P" if !value.is_empty() => password = Some(value),
+            "T" if !value.is_empty() && value != "nopass" => security = Some(value),
+            "H" => hidden = value.eq_ignore_ascii_case("true"),
+            _ => {}
+        }
+    }
+
+    Some(StructuredText::Wifi {
+        ssid: ssid.filter(|s| !s.is_empty())?,
+        password,
+        security,
+        hidden,
+    })
+}
+
+/// Parse `lat,lon[,alt][;params][?q=label]` (after `geo:`).
+fn parse_geo(uri: &str) -> Option<StructuredText> {
+    let (coordinates, query) = match uri.split_once('?') {
+        Some((coordinates, query)) => (coordinates, Some(query)),
+        None => (uri, None),
+    };
+    let coordinates = coordinates.split(';').next()?;
+    let mut parts = coordinates.split(',');
+    let latitude: f64 = parts.next()?.trim().parse().ok()?;
+    let longitude: f64 = parts.next()?.trim().parse().ok()?;
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+        return None;
+    }
+
+    let label = query
+        .into_iter()
+        .flat_map(|query| query.split('&'))
+        .find_map(|param| param.strip_prefix("q="))
+        .map(|q| {
+            let q = q.replace('+', " ");
+            urlencoding::decode(&q).map_or(q.clone(), |decoded| decoded.into_owned())
+        })
+        .filter(|label| !label.is_empty());
+
+    Some(StructuredText::Geo {
+        latitude,
+        longitude,
+        label,
+    })
+}
+
+/// Parse `N:Doe,John;TEL:123;EMAIL:a@b.c;;` (after `MECARD:`).
+fn parse_mecard(fields: &str) -> Option<StructuredText> {
+    let mut contact = Vec::new();
+    for field in split_escaped(fields, ';') {
+        let Some((key, value)) = field.split_once(':') else {
+            continue;
+        };
+        let value = unescape(value);
+        let value = match key {
+            // Family and given name are separated by a comma
+            "N" => match value.split_once(',') {
+                Some((family, given)) => format!("{} {}", given.trim(), family.trim()),
+                None => value,
+            },
+            _ => value,
+        };
+        if let Some(label) = contact_label(key) {
+            contact.push((label, value.trim().to_string()));
+        }
+    }
+    contact_fields(contact)
+}
+
+/// Parse a vCard (`BEGIN:VCARD` ... `END:VCARD`).
+fn parse_vcard(text: &str) -> Option<StructuredText> {
+    let mut contact = Vec::new();
+    let mut full_name = None;
+    let mut structured_name = None;
+
+    for line in text.lines() {
+        let Some((name, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        // Drop parameters like `TEL;TYPE=cell`
+        let key = name.split(';').next().unwrap_or("").to_ascii_uppercase();
+        // Components of structured values are separated by semicolons
+        let components: Vec<String> = value.split(';').map(unescape).collect();
+        let non_empty = |order: &[usize]| {
+            order
+                .iter()
+                .filter_map(|&i| components.get(i))
+                .filter(|part| !part.is_empty())
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        match key.as_str() {
+            "FN" => full_name = Some(unescape(value)),
+            // Family;given;additional;prefix;suffix, shown in reading order
+            "N" => structured_name = Some(non_empty(&[3, 1, 2, 0, 4]).join(" ")),
+            "ADR" => contact.push(("Address", non_empty(&[0, 1, 2, 3, 4, 5, 6]).join(", "))),
+            "ORG" => contact.push(("Organization", non_empty(&[0, 1, 2]).join(", "))),
+            key => {
+                if let Some(label) = contact_label(key) {
+                    contact.push((label, unescape(value)));
+                }
+            }
+        }
+    }
+
+    if let Some(name) = full_name.or(structured_name) {
+        contact.insert(0, ("Name", name));
+    }
+    contact_fields(contact)
+}
+
+/// Get the label of a MECARD or vCard field, None for fields not shown.
+fn contact_label(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "N" => "Name",
+        "ORG" => "Organization",
+        "TEL" => "Phone",
+        "EMAIL" => "Email",
+        "ADR" => "Address",
+        "URL" => "Website",
+        "BDAY" => "Birthday",
+        "NOTE" => "Note",
+        _ => return None,
+    })
+}
+
+/// Build a contact from its fields, which need at least one value.
+fn contact_fields(fields: Vec<(&'static str, String)>) -> Option<StructuredText> {
+    let fields: Vec<_> = fields
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty())
+        .collect();
+    (!fields.is_empty()).then_some(StructuredText::Contact(fields))
+}
+
+/// Split at unescaped separators, keeping the escapes in the parts.
+fn split_escaped(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == separator {
+            parts.push(&text[start..i]);
+            start = i + 1;
+        }
+    }
+    parts.push(&text[start..]);
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+/// Resolve backslash escapes (`\;`, `\,`, `\:`, `\\` and vCard's `\n`).
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(escaped) => out.push(escaped),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wifi() {
+        assert_eq!(
+            parse_structured_text(r"WIFI:T:WPA;S:my\;net;P:pa\:ss;;"),
+            Some(StructuredText::Wifi {
+                ssid: "my;net".to_string(),
+                password: Some("pa:ss".to_string()),
+                security: Some("WPA".to_string()),
+                hidden: false,
+            })
+        );
+        assert_eq!(
+            parse_structured_text("WIFI:S:open;T:nopass;H:true;;"),
+            Some(StructuredText::Wifi {
+                ssid: "open".to_string(),
+                password: None,
+                security: None,
+                hidden: true,
+            })
+        );
+        // A network name is required
+        assert_eq!(parse_structured_text("WIFI:T:WPA;P:pass;;"), None);
+    }
+
+    #[test]
+    fn test_parse_geo() {
+        let geo = parse_structured_text("geo:37.78,-122.41?q=Ferry+Building").unwrap();
+        assert_eq!(
+            geo,
+            StructuredText::Geo {
+                latitude: 37.78,
+                longitude: -122.41,
+                label: Some("Ferry Building".to_string()),
+            }
+        );
+        assert_eq!(
+            geo.map_url().as_deref(),
+            Some("https://www.openstreetmap.org/?mlat=37.78&mlon=-122.41#map=16/37.78/-122.41")
+        );
+        assert!(parse_structured_text("geo:48.2,16.37,180;u=35").is_some());
+        assert_eq!(parse_structured_text("geo:91,0"), None);
+        assert_eq!(parse_structured_text("geo:here"), None);
+    }
+
+    #[test]
+    fn test_parse_contacts() {
+        assert_eq!(
+            parse_structured_text("MECARD:N:Doe,John;TEL:+123;EMAIL:john@example.com;;"),
+            Some(StructuredText::Contact(vec![
+                ("Name", "John Doe".to_string()),
+                ("Phone", "+123".to_string()),
+                ("Email", "john@example.com".to_string()),
+            ]))
+        );
+
+        let vcard = "BEGIN:VCARD\nVERSION:3.0\nN:Doe;Jane;;;\nFN:Jane Doe\n\
+                     TEL;TYPE=cell:+456\nADR;TYPE=home:;;1 Main St;Springfield;;;\nEND:VCARD";
+        assert_eq!(
+            parse_structured_text(vcard),
+            Some(StructuredText::Contact(vec![
+                ("Name", "Jane Doe".to_string()),
+                ("Phone", "+456".to_string()),
+                ("Address", "1 Main St, Springfield".to_string()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_unknown_schemes() {
+        assert_eq!(parse_structured_text("mailto:someone@example.com"), None);
+        assert_eq!(parse_structured_text("just some text"), None);
+    }
+}
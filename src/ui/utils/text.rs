@@ -0,0 +1,661 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use unicode_normalization::char::{decompose_canonical, is_combining_mark};
+
+/// Check whether plain text looks like markdown.
+///
+/// Requires at least two different kinds of markdown syntax (headings, lists,
+/// code fences, inline emphasis/code/links), so that e.g. a shell script whose
+/// comments look like headings isn't rendered as markdown.
+pub fn looks_like_markdown(text: &str) -> bool {
+    let mut heading = false;
+    let mut list = false;
+    let mut fence = false;
+    let mut inline = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("```") {
+            fence = true;
+        } else if let Some(rest) = trimmed.strip_prefix('#') {
+            let rest = rest.trim_start_matches('#');
+            if trimmed.len() - rest.len() <= 6 && rest.starts_with(' ') {
+                heading = true;
+            }
+        } else if trimmed.starts_with("- ") || trimmed.starts_with("* ") || is_numbered(trimmed) {
+            list = true;
+        }
+
+        if has_inline_markup(trimmed) {
+            inline = true;
+        }
+    }
+
+    [heading, list, fence, inline]
+        .iter()
+        .filter(|&&found| found)
+        .count()
+        >= 2
+}
+
+/// Check if a line starts an ordered list item ("1. item").
+fn is_numbered(line: &str) -> bool {
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    digits > 0 && line[digits..].starts_with(". ")
+}
+
+/// Check if a line contains bold/italic emphasis, inline code or a link.
+fn has_inline_markup(line: &str) -> bool {
+    let paired = |marker: &str| {
+        line.find(marker)
+            .and_then(|start| line[start + marker.len()..].find(marker))
+            .is_some_and(|len| len > 0)
+    };
+
+    paired("**") || paired("__") || paired("`") || (line.contains("](") && line.contains('['))
+}
+
+/// Extract an http(s) URL from text that consists of only a URL.
+///
+/// Surrounding whitespace and trailing punctuation (e.g. from copying the end
+/// of a sentence) are ignored. Closing parentheses are only stripped when they
+/// are unbalanced, so URLs like Wikipedia's `Foo_(bar)` stay intact.
+pub fn parse_url(text: &str) -> Option<&str> {
+    let mut url = text.trim();
+
+    loop {
+        let trimmed = url.trim_end_matches(['.', ',', ';', ':', '!', '?', '\'', '"', ']', '>']);
+        let trimmed = match trimmed.strip_suffix(')') {
+            Some(rest) if rest.matches('(').count() < trimmed.matches(')').count() => rest,
+            _ => trimmed,
+        };
+        if trimmed.len() == url.len() {
+            break;
+        }
+        url = trimmed;
+    }
+
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+
+    if host.is_empty() || url.chars().any(char::is_whitespace) {
+        return None;
+    }
+
+    Some(url)
+}
+
+/// Pretty-print text that is a JSON object or array.
+///
+/// Returns `None` for invalid JSON and for bare scalars like `42` or `true`.
+/// Unlike a round trip through `serde_json::Value`, the original key order
+/// and number formatting are kept.
+pub fn pretty_print_json(text: &str) -> Option<String> {
+    let text = text.trim();
+    if !(text.starts_with('{') || text.starts_with('[')) {
+        return None;
+    }
+    serde_json::from_str::<serde::de::IgnoredAny>(text).ok()?;
+
+    let mut out = String::with_capacity(text.len() * 2);
+    let mut indent = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = text.chars().peekable();
+
+    let newline = |out: &mut String, indent: usize| {
+        out.push('\n');
+        out.push_str(&"  ".repeat(indent));
+    };
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            }
+            '{' | '[' => {
+                out.push(c);
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                // Keep empty containers on one line
+                if let Some(close) = chars.next_if(|&next| matches!(next, '}' | ']')) {
+                    out.push(close);
+                } else {
+                    indent += 1;
+                    newline(&mut out, indent);
+                }
+            }
+            '}' | ']' => {
+                indent = indent.saturating_sub(1);
+                newline(&mut out, indent);
+                out.push(c);
+            }
+            ',' => {
+                out.push(c);
+                newline(&mut out, indent);
+            }
+            ':' => out.push_str(": "),
+            c if c.is_whitespace() => {}
+            c => out.push(c),
+        }
+    }
+
+    Some(out)
+}
+
+/// Minimum length of text considered for base64 decoding, so that ordinary
+/// words aren't mistaken for base64.
+const MIN_BASE64_LEN: usize = 16;
+
+/// Content of a decoded base64 string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodedBase64 {
+    /// UTF-8 text
+    Text(String),
+    /// PNG image bytes
+    Png(Vec<u8>),
+    /// JPEG image bytes
+    Jpeg(Vec<u8>),
+}
+
+/// Decode text that consists of a single base64 string (or a base64 data URI).
+///
+/// Only strings of a reasonable length with correct padding are considered,
+/// and the decoded bytes must be printable UTF-8 text or a PNG/JPEG image.
+/// Anything else (e.g. hex hashes that happen to be valid base64) is rejected.
+pub fn decode_base64(text: &str) -> Option<DecodedBase64> {
+    use base64::Engine;
+
+    let text = text.trim();
+    let encoded = match text.strip_prefix("data:") {
+        Some(uri) => uri.split_once(";base64,")?.1,
+        None => text,
+    };
+
+    if encoded.len() < MIN_BASE64_LEN || !encoded.len().is_multiple_of(4) {
+        return None;
+    }
+
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .ok()?;
+
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        return Some(DecodedBase64::Png(bytes));
+    }
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return Some(DecodedBase64::Jpeg(bytes));
+    }
+
+    let decoded = String::from_utf8(bytes).ok()?;
+    let printable = decoded
+        .chars()
+        .all(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t'));
+    (printable && !decoded.trim().is_empty()).then_some(DecodedBase64::Text(decoded))
+}
+
+/// Check whether text is SVG markup (e.g. copied from a vector editor).
+///
+/// The text has to start with the `<svg` element, optionally after an XML
+/// declaration, doctype or comments, and end with its closing tag (or be a
+/// single self-closing `<svg/>`).
+pub fn looks_like_svg(text: &str) -> bool {
+    let text = text.trim_start_matches('\u{feff}').trim();
+    let mut rest = text;
+    while let Some(prolog) = rest
+        .strip_prefix("<?")
+        .map(|r| r.split_once("?>"))
+        .or_else(|| rest.strip_prefix("<!--").map(|r| r.split_once("-->")))
+        .or_else(|| rest.strip_prefix("<!").map(|r| r.split_once('>')))
+    {
+        let Some((_, after)) = prolog else {
+            return false;
+        };
+        rest = after.trim_start();
+    }
+
+    rest.strip_prefix("<svg")
+        .is_some_and(|r| r.starts_with(|c: char| c.is_whitespace() || c == '>'))
+        && (rest.ends_with("</svg>")
+            || (rest.ends_with("/>") && rest.find('>') == Some(rest.len() - 1)))
+}
+
+/// Format the character and word count of a text, e.g. "42 characters · 7 words".
+pub fn format_text_stats(text: &str) -> String {
+    let chars = text.chars().count();
+    let words = text.split_whitespace().count();
+    format!(
+        "{} character{} · {} word{}",
+        chars,
+        if chars == 1 { "" } else { "s" },
+        words,
+        if words == 1 { "" } else { "s" }
+    )
+}
+
+/// Format a byte count as a human readable size (B, KB, MB, GB).
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if size < 10.0 {
+        format!("{:.1} {}", size, UNITS[unit])
+    } else {
+        format!("{:.0} {}", size, UNITS[unit])
+    }
+}
+
+/// Lines picked with a `:10-20` query, 1-based and inclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineRange {
+    pub start: usize,
+    /// Last line, `usize::MAX` for an open range like `:10-`
+    pub end: usize,
+}
+
+impl LineRange {
+    /// Limit the range to a text of `line_count` lines.
+    ///
+    /// Returns None if the range starts past the last line.
+    pub fn clamp(self, line_count: usize) -> Option<Self> {
+        (self.start <= line_count).then(|| Self {
+            start: self.start,
+            end: self.end.min(line_count),
+        })
+    }
+
+    /// Get the lines of `text` in the range, without the final line break.
+    ///
+    /// Returns None if the range starts past the last line.
+    pub fn select(self, text: &str) -> Option<&str> {
+        let mut start = None;
+        let mut end = text.len();
+        let mut offset = 0;
+        for (i, line) in text.split_inclusive('\n').enumerate() {
+            if i + 1 == self.start {
+                start = Some(offset);
+            }
+            offset += line.len();
+            if i + 1 == self.end {
+                end = offset;
+                break;
+            }
+        }
+
+        let lines = &text[start?..end];
+        let lines = lines.strip_suffix('\n').unwrap_or(lines);
+        Some(lines.strip_suffix('\r').unwrap_or(lines))
+    }
+}
+
+/// Check whether a query picks lines (`:`, `:10`, `:10-20`) rather than
+/// searching, including ranges that are still being typed.
+pub fn is_line_range_query(query: &str) -> bool {
+    query
+        .strip_prefix(':')
+        .is_some_and(|range| range.chars().all(|c| c.is_ascii_digit() || c == '-'))
+}
+
+/// Parse a `:10` (single line), `:10-20` or `:10-` (to the end) query.
+pub fn parse_line_range(query: &str) -> Option<LineRange> {
+    let range = query.strip_prefix(':')?;
+    let (start, end) = match range.split_once('-') {
+        Some((start, "")) => (start.parse().ok()?, usize::MAX),
+        Some((start, end)) => (start.parse().ok()?, end.parse().ok()?),
+        None => {
+            let line = range.parse().ok()?;
+            (line, line)
+        }
+    };
+    (start >= 1 && end >= start).then_some(LineRange { start, end })
+}
+
+/// Parse tags typed by the user.
+///
+/// Tags are separated by whitespace or commas; a leading `#` is dropped and
+/// tags are lowercased, keeping the first occurrence of duplicates.
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input.split(|c: char| c.is_whitespace() || c == ',') {
+        let tag = tag.trim_start_matches('#').to_lowercase();
+        if !tag.is_empty() && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+/// Split `#tag` terms off a search query.
+///
+/// Returns the lowercased tags (without `#`) and the rest of the query. Only
+/// terms accepted by `is_tag` count as tags, so e.g. a `#ff0000` search
+/// stays a text search unless an item is tagged like that. Without any tags
+/// the query is returned unchanged.
+pub fn split_tag_query(query: &str, is_tag: impl Fn(&str) -> bool) -> (Vec<String>, String) {
+    let mut tags = Vec::new();
+    let mut rest = Vec::new();
+    for term in query.split_whitespace() {
+        match term.strip_prefix('#').map(str::to_lowercase) {
+            Some(tag) if !tag.is_empty() && is_tag(&tag) => tags.push(tag),
+            _ => rest.push(term),
+        }
+    }
+    if tags.is_empty() {
+        return (tags, query.to_string());
+    }
+    (tags, rest.join(" "))
+}
+
+/// Convert matched character indices into byte ranges of `text`.
+///
+/// Consecutive characters are merged into a single range, so the result can
+/// be used directly as text highlights. Out of range indices are ignored.
+pub fn char_indices_to_ranges(text: &str, indices: &[usize]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut wanted = indices.iter().copied().peekable();
+
+    for (char_idx, (start, ch)) in text.char_indices().enumerate() {
+        while wanted.next_if(|&idx| idx < char_idx).is_some() {}
+        if wanted.next_if_eq(&char_idx).is_none() {
+            continue;
+        }
+
+        let end = start + ch.len_utf8();
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+
+    ranges
+}
+
+/// Strip diacritics from text for matching, so "cafe" matches "Café".
+///
+/// Every character maps to exactly one character (characters that don't
+/// decompose into a single base letter are kept as is), so match positions
+/// in the folded text are also positions in the original text.
+pub fn fold_diacritics(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(text.chars().map(fold_char).collect())
+}
+
+/// Get the base letter of a character with diacritics.
+fn fold_char(c: char) -> char {
+    let mut base = None;
+    let mut single = true;
+    decompose_canonical(c, |part| {
+        if is_combining_mark(part) {
+            return;
+        }
+        single &= base.is_none();
+        base = Some(part);
+    });
+    match base {
+        Some(base) if single => base,
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold_diacritics() {
+        assert_eq!(fold_diacritics("Café"), "Cafe");
+        assert_eq!(fold_diacritics("Español"), "Espanol");
+        assert_eq!(fold_diacritics("Ångström"), "Angstrom");
+        assert!(matches!(fold_diacritics("plain"), Cow::Borrowed("plain")));
+        // Characters without a single base letter are kept
+        assert_eq!(fold_diacritics("한글 ß"), "한글 ß");
+        assert_eq!(fold_diacritics("한글").chars().count(), 2);
+    }
+
+    #[test]
+    fn test_detects_markdown() {
+        assert!(looks_like_markdown("# Title\n\nSome **bold** text"));
+        assert!(looks_like_markdown(
+            "- one\n- two\n\n```rust\nfn main() {}\n```"
+        ));
+        assert!(looks_like_markdown("## Notes\n1. first\n2. second"));
+    }
+
+    #[test]
+    fn test_parse_line_range() {
+        assert_eq!(
+            parse_line_range(":10-20"),
+            Some(LineRange { start: 10, end: 20 })
+        );
+        assert_eq!(parse_line_range(":7"), Some(LineRange { start: 7, end: 7 }));
+        assert_eq!(
+            parse_line_range(":3-"),
+            Some(LineRange {
+                start: 3,
+                end: usize::MAX
+            })
+        );
+        assert_eq!(parse_line_range(":0"), None);
+        assert_eq!(parse_line_range(":20-10"), None);
+        assert_eq!(parse_line_range(":"), None);
+        assert!(is_line_range_query(":"));
+        assert!(is_line_range_query(":12-"));
+        assert!(!is_line_range_query("a:1"));
+        assert!(!is_line_range_query(":x"));
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        assert_eq!(parse_tags("work  #Snippets, work"), ["work", "snippets"]);
+        assert!(parse_tags(" # , ").is_empty());
+    }
+
+    #[test]
+    fn test_split_tag_query() {
+        let is_tag = |tag: &str| "work".starts_with(tag);
+        assert_eq!(
+            split_tag_query("#Wo deploy  key", is_tag),
+            (vec!["wo".to_string()], "deploy key".to_string())
+        );
+        // Unknown tags and a lone `#` stay part of the text
+        assert_eq!(
+            split_tag_query("#ff0000  # ", is_tag),
+            (vec![], "#ff0000  # ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_select_lines() {
+        let text = "one\r\ntwo\nthree\nfour\n";
+        assert_eq!(
+            LineRange { start: 2, end: 3 }.select(text),
+            Some("two\nthree")
+        );
+        assert_eq!(LineRange { start: 1, end: 1 }.select(text), Some("one"));
+        assert_eq!(
+            LineRange {
+                start: 3,
+                end: usize::MAX
+            }
+            .select(text),
+            Some("three\nfour")
+        );
+        assert_eq!(LineRange { start: 5, end: 6 }.select(text), None);
+        assert_eq!(
+            LineRange { start: 2, end: 9 }.clamp(4),
+            Some(LineRange { start: 2, end: 4 })
+        );
+        assert_eq!(LineRange { start: 5, end: 9 }.clamp(4), None);
+    }
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(
+            parse_url("https://example.com"),
+            Some("https://example.com")
+        );
+        assert_eq!(
+            parse_url("  http://example.com/a?b=c#d \n"),
+            Some("http://example.com/a?b=c#d")
+        );
+        assert_eq!(
+            parse_url("https://example.com/page."),
+            Some("https://example.com/page")
+        );
+        assert_eq!(parse_url("(https://example.com)."), None);
+        assert_eq!(
+            parse_url("https://en.wikipedia.org/wiki/Rust_(language))"),
+            Some("https://en.wikipedia.org/wiki/Rust_(language)")
+        );
+    }
+
+    #[test]
+    fn test_parse_url_rejects_non_urls() {
+        assert!(parse_url("example.com").is_none());
+        assert!(parse_url("https://").is_none());
+        assert!(parse_url("https://example.com and more text").is_none());
+        assert!(parse_url("ftp://example.com").is_none());
+    }
+
+    #[test]
+    fn test_format_text_stats() {
+        assert_eq!(format_text_stats("hello world"), "11 characters · 2 words");
+        assert_eq!(format_text_stats("ä"), "1 character · 1 word");
+        assert_eq!(
+            format_text_stats("  one\n\ttwo  three "),
+            "18 characters · 3 words"
+        );
+        assert_eq!(format_text_stats(""), "0 characters · 0 words");
+    }
+
+    #[test]
+    fn test_looks_like_svg() {
+        assert!(looks_like_svg(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\"><rect/></svg>"
+        ));
+        assert!(looks_like_svg(
+            "<?xml version=\"1.0\"?>\n<!-- Created with Inkscape -->\n\
+             <!DOCTYPE svg>\n<svg width=\"10\">\n</svg>\n"
+        ));
+        assert!(looks_like_svg("<svg viewBox=\"0 0 1 1\"/>"));
+
+        assert!(!looks_like_svg("<svgfoo></svgfoo>"));
+        assert!(!looks_like_svg("<html><svg></svg></html>"));
+        assert!(!looks_like_svg("<svg><rect/>"));
+        assert!(!looks_like_svg("<?xml version=\"1.0\""));
+        assert!(!looks_like_svg("an <svg> tag"));
+    }
+
+    #[test]
+    fn test_decode_base64_text() {
+        assert_eq!(
+            decode_base64("aGVsbG8gYmFzZTY0IHdvcmxk"),
+            Some(DecodedBase64::Text("hello base64 world".to_string()))
+        );
+        assert_eq!(
+            decode_base64(" data:text/plain;base64,aGVsbG8gYmFzZTY0IHdvcmxk\n"),
+            Some(DecodedBase64::Text("hello base64 world".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_decode_base64_image() {
+        // 1x1 PNG header is enough for sniffing
+        assert!(matches!(
+            decode_base64("iVBORw0KGgoAAAANSUhEUgAAAAE="),
+            Some(DecodedBase64::Png(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_base64_rejects_non_base64() {
+        // Too short, even though it decodes
+        assert!(decode_base64("dGVzdA==").is_none());
+        // Ordinary words
+        assert!(decode_base64("configuration").is_none());
+        assert!(decode_base64("internationalization").is_none());
+        // Bad padding
+        assert!(decode_base64("aGVsbG8gYmFzZTY0IHdvcmxk=").is_none());
+        // Decodes, but to binary garbage (a sha256 hex digest)
+        assert!(
+            decode_base64("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_pretty_print_json() {
+        assert_eq!(
+            pretty_print_json(r#"{"b":1,"a":[true, null,{}],"s":"x, {y}: \"z\""}"#).as_deref(),
+            Some(
+                "{\n  \"b\": 1,\n  \"a\": [\n    true,\n    null,\n    {}\n  ],\n  \"s\": \"x, {y}: \\\"z\\\"\"\n}"
+            )
+        );
+        assert_eq!(pretty_print_json("[ ]").as_deref(), Some("[]"));
+    }
+
+    #[test]
+    fn test_pretty_print_json_rejects_invalid() {
+        assert!(pretty_print_json("{not json}").is_none());
+        assert!(pretty_print_json("[1, 2").is_none());
+        assert!(pretty_print_json("42").is_none());
+        assert!(pretty_print_json("\"string\"").is_none());
+    }
+
+    #[test]
+    fn test_char_indices_to_ranges() {
+        assert_eq!(
+            char_indices_to_ranges("Firefox", &[0, 1, 4]),
+            vec![0..2, 4..5]
+        );
+        assert_eq!(char_indices_to_ranges("héllo", &[1, 2]), vec![1..4]);
+        assert!(char_indices_to_ranges("abc", &[5]).is_empty());
+        assert!(char_indices_to_ranges("abc", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(200 * 1024), "200 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 / 2), "2.5 MB");
+    }
+
+    #[test]
+    fn test_rejects_plain_text() {
+        assert!(!looks_like_markdown("just some text"));
+        assert!(!looks_like_markdown(
+            "#!/bin/sh\n# install deps\nmake install"
+        ));
+        assert!(!looks_like_markdown("- a single list item"));
+        assert!(!looks_like_markdown("#hashtag and more"));
+    }
+}
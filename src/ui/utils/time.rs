@@ -0,0 +1,95 @@
+use chrono::{DateTime, Datelike, Local};
+use std::time::SystemTime;
+
+/// Format a SystemTime as a relative or absolute timestamp.
+///
+/// Recent times are relative ("5 mins ago", "Yesterday", "3 days ago"),
+/// anything older than a week shows the date, including the year when it
+/// isn't the current one.
+pub fn format_timestamp(time: &SystemTime) -> String {
+    format_timestamp_at(time, &SystemTime::now())
+}
+
+/// Format `time` relative to `now`.
+fn format_timestamp_at(time: &SystemTime, now: &SystemTime) -> String {
+    let secs = now.duration_since(*time).map(|d| d.as_secs()).unwrap_or(0);
+
+    if secs < 60 {
+        return "Just now".to_string();
+    } else if secs < 3600 {
+        let mins = secs / 60;
+        return format!("{} min{} ago", mins, if mins > 1 { "s" } else { "" });
+    }
+
+    let time: DateTime<Local> = (*time).into();
+    let now: DateTime<Local> = (*now).into();
+    let days = (now.date_naive() - time.date_naive()).num_days();
+
+    if days == 0 {
+        let hours = secs / 3600;
+        format!("{} hour{} ago", hours, if hours > 1 { "s" } else { "" })
+    } else if days == 1 {
+        "Yesterday".to_string()
+    } else if days < 7 {
+        format!("{} days ago", days)
+    } else if time.year() == now.year() {
+        time.format("%b %-d").to_string()
+    } else {
+        time.format("%b %-d, %Y").to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::time::Duration;
+
+    const HOUR: u64 = 3600;
+    const DAY: u64 = 24 * HOUR;
+
+    /// Noon on the given local date, far enough from midnight that DST
+    /// changes don't move the offsets below onto another day.
+    fn noon(year: i32, month: u32, day: u32) -> SystemTime {
+        Local
+            .with_ymd_and_hms(year, month, day, 12, 0, 0)
+            .unwrap()
+            .into()
+    }
+
+    fn ago(now: SystemTime, secs: u64) -> String {
+        format_timestamp_at(&(now - Duration::from_secs(secs)), &now)
+    }
+
+    #[test]
+    fn test_minutes_and_hours() {
+        let now = noon(2024, 3, 14);
+        assert_eq!(ago(now, 10), "Just now");
+        assert_eq!(ago(now, 60), "1 min ago");
+        assert_eq!(ago(now, 45 * 60), "45 mins ago");
+        assert_eq!(ago(now, HOUR), "1 hour ago");
+        assert_eq!(ago(now, 5 * HOUR), "5 hours ago");
+    }
+
+    #[test]
+    fn test_days() {
+        let now = noon(2024, 3, 14);
+        assert_eq!(ago(now, DAY), "Yesterday");
+        assert_eq!(ago(now, 2 * DAY), "2 days ago");
+        assert_eq!(ago(now, 6 * DAY), "6 days ago");
+    }
+
+    #[test]
+    fn test_absolute_dates() {
+        let now = noon(2024, 3, 14);
+        assert_eq!(ago(now, 10 * DAY), "Mar 4");
+        assert_eq!(ago(now, 100 * DAY), "Dec 5, 2023");
+    }
+
+    #[test]
+    fn test_future_time_is_just_now() {
+        let now = noon(2024, 3, 14);
+        let later = now + Duration::from_secs(HOUR);
+        assert_eq!(format_timestamp_at(&later, &now), "Just now");
+    }
+}
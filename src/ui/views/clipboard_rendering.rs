@@ -1,13 +1,81 @@
 //! Rendering functions for clipboard history view.
 
 use crate::assets::PhosphorIcon;
-use crate::clipboard::{ClipboardContent, ClipboardItem};
+use crate::clipboard::file_preview::{BinarySummary, FileContent, FileReadState, hex_dump};
+use crate::clipboard::ocr::OcrState;
+use crate::clipboard::{ClipboardContent, ClipboardItem, ClipboardSelection, copy_to_clipboard};
+use crate::items::file_icon;
+use crate::search::open_url;
+use crate::ui::markdown::render_markdown_with_id;
 use crate::ui::theme::theme;
-use crate::ui::utils::color::{Color, parse_color};
-use gpui::{Div, ElementId, SharedString, Stateful, div, img, prelude::*, px, svg};
+use crate::ui::utils::color::{Color, WCAG_AA_NORMAL_TEXT, find_color};
+use crate::ui::utils::{
+    DecodedBase64, LineRange, StructuredText, code_language, decode_base64, format_bytes,
+    format_text_stats, format_timestamp, html_to_markdown, looks_like_markdown, looks_like_svg,
+    parse_structured_text, parse_url, qr_code_image,
+};
+use crate::ui::views::{render_action_indicator, render_pin_badge};
+use gpui::{
+    App, Div, ElementId, Entity, Pixels, Point, ScrollHandle, SharedString, Stateful, Window, div,
+    img, prelude::*, px, svg,
+};
+use std::cell::Cell;
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
-use std::time::SystemTime;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+
+/// Maximum number of bytes of text shown in the preview panel. The preview
+/// scrolls, this only guards against rendering huge files.
+const MAX_TEXT_PREVIEW_BYTES: usize = 100_000;
+
+/// Maximum size of copied HTML converted for the rich text preview. Larger
+/// documents are shown as plain text.
+const MAX_HTML_PREVIEW_BYTES: usize = 200_000;
+
+/// How long a copied color code stays highlighted.
+const COPY_FLASH_DURATION: Duration = Duration::from_millis(600);
+
+/// PNG encoded preview images, keyed by clipboard item id.
+///
+/// Encoding happens once per item instead of on every render, and the images
+/// are released again by [`release_image_previews`].
+static IMAGE_PREVIEWS: LazyLock<Mutex<HashMap<u64, Arc<gpui::Image>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+thread_local! {
+    /// Scroll position of text previews, reset when another item is previewed.
+    static PREVIEW_SCROLL: PreviewScroll = PreviewScroll::default();
+}
+
+/// Scroll handle of the text preview and the item it shows.
+#[derive(Default)]
+struct PreviewScroll {
+    handle: ScrollHandle,
+    item_id: Cell<Option<u64>>,
+}
+
+/// QR codes of clipboard items, keyed by clipboard item id.
+static QR_CODES: LazyLock<Mutex<HashMap<u64, Arc<gpui::Image>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Downscaled list thumbnails of clipboard images, keyed by clipboard item id.
+static THUMBNAILS: LazyLock<Mutex<HashMap<u64, Arc<gpui::Image>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Decoded base64 content of clipboard text, keyed by clipboard item id.
+///
+/// Text that isn't base64 is cached as `None`, so list rows don't try to
+/// decode it again on every render.
+static DECODED_BASE64: LazyLock<Mutex<HashMap<u64, Option<Arc<DecodedBase64>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Number of characters of the raw string shown in base64 previews.
+const MAX_RAW_BASE64_CHARS: usize = 300;
+
+/// Length of the shorter side of list thumbnails in pixels.
+const THUMBNAIL_SIZE: u32 = 64;
 
 /// Render a clipboard item in the list.
 pub fn render_clipboard_item(item: &ClipboardItem, selected: bool, row: usize) -> Stateful<Div> {
@@ -19,14 +87,26 @@ pub fn render_clipboard_item(item: &ClipboardItem, selected: bool, row: usize) -
         t.item_background
     };
 
-    // Format timestamp
-    let timestamp_str = format_timestamp(&item.timestamp);
+    // Format timestamp (tagged with the source for primary selection items)
+    let mut timestamp_str = format_timestamp(&item.timestamp);
+    if item.source == ClipboardSelection::Primary {
+        timestamp_str.push_str(" · ");
+        timestamp_str.push_str(item.source.label());
+    }
+    for tag in &item.tags {
+        timestamp_str.push_str(" #");
+        timestamp_str.push_str(tag);
+    }
 
     // Get preview text
     let preview = get_item_preview(item);
 
+    // Enter copies rich text as plain text, Ctrl+Enter keeps its formatting
+    let is_rich_text = matches!(item.content, ClipboardContent::RichText { .. });
+
     div()
         .id(ElementId::NamedInteger("clipboard-item".into(), row as u64))
+        .relative()
         .ml(px(0.0))
         .mr(t.item_margin_x)
         .my(t.item_margin_y)
@@ -38,8 +118,12 @@ pub fn render_clipboard_item(item: &ClipboardItem, selected: bool, row: usize) -
         .flex_row()
         .items_center()
         .gap_2()
-        // Icon (type-specific)
-        .child(render_item_icon(item))
+        // Icon (type-specific), with a pin badge for pinned items
+        .child(
+            render_item_icon(item)
+                .relative()
+                .when(item.pinned, |icon| icon.child(render_pin_badge())),
+        )
         // Content: preview text and timestamp
         .child(
             div()
@@ -52,7 +136,7 @@ pub fn render_clipboard_item(item: &ClipboardItem, selected: bool, row: usize) -
                 .child(
                     div()
                         .w_full()
-                        .text_sm()
+                        .text_size(t.item_title_font_size)
                         .line_height(t.item_title_line_height)
                         .text_color(t.item_title_color)
                         .whitespace_nowrap()
@@ -63,7 +147,7 @@ pub fn render_clipboard_item(item: &ClipboardItem, selected: bool, row: usize) -
                 .child(
                     div()
                         .w_full()
-                        .text_xs()
+                        .text_size(t.item_description_font_size)
                         .h(t.layout.item_description_height)
                         .text_color(t.item_description_color)
                         .whitespace_nowrap()
@@ -72,6 +156,9 @@ pub fn render_clipboard_item(item: &ClipboardItem, selected: bool, row: usize) -
                         .child(SharedString::from(timestamp_str)),
                 ),
         )
+        .when(selected && is_rich_text, |el| {
+            el.child(render_action_indicator("Plain text · Ctrl+↵ formatted"))
+        })
 }
 
 /// Get preview text for a clipboard item.
@@ -86,7 +173,7 @@ fn render_item_icon(item: &ClipboardItem) -> Div {
     // Check if this is text content
     if let ClipboardContent::Text(text) = &item.content {
         // Check if it's a color
-        if let Some(color) = parse_color(text) {
+        if let Some(color) = find_color(text) {
             let (h, s, l) = color.to_hsl();
             // Render a small colored circle with background box
             return div()
@@ -114,90 +201,84 @@ fn render_item_icon(item: &ClipboardItem) -> Div {
                 );
         }
 
+        // Check if it's a web URL
+        if parse_url(text).is_some() {
+            return render_icon_container(PhosphorIcon::Link);
+        }
+
+        // Check if it's Wi-Fi credentials, a location or a contact
+        if let Some(structured) = parse_structured_text(text) {
+            return render_icon_container(structured_icon(&structured));
+        }
+
+        // Check if it's base64 encoded content
+        if decoded_base64(item.id, text).is_some() {
+            return render_icon_container(PhosphorIcon::Code);
+        }
+
+        // Show SVG markup as the image it draws
+        if looks_like_svg(text)
+            && let Some(image) = svg_image(item.id, text)
+        {
+            return render_thumbnail(image);
+        }
+
         // Check if it's a file:// URL
         if let Some(path) = parse_file_url(text) {
-            if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-                let ext_lower = ext.to_lowercase();
-                if matches!(
-                    ext_lower.as_str(),
-                    "png" | "jpg" | "jpeg" | "gif" | "webp" | "svg"
-                ) {
-                    return render_icon_container(PhosphorIcon::FileImage);
-                } else if matches!(
-                    ext_lower.as_str(),
-                    "txt"
-                        | "md"
-                        | "rs"
-                        | "py"
-                        | "js"
-                        | "ts"
-                        | "json"
-                        | "yaml"
-                        | "yml"
-                        | "toml"
-                        | "html"
-                        | "css"
-                        | "sh"
-                ) {
-                    return render_icon_container(PhosphorIcon::FileText);
-                } else {
-                    return render_icon_container(PhosphorIcon::File);
-                }
+            if is_image_file(&path) {
+                return render_thumbnail(path);
             }
-            return render_icon_container(PhosphorIcon::File);
+            return render_icon_container(file_icon(&path));
         }
 
         // Default to clipboard text icon
         return render_icon_container(PhosphorIcon::ClipboardText);
     }
 
+    // Show a thumbnail for images
+    if let ClipboardContent::Image {
+        width,
+        height,
+        rgba_bytes,
+    } = &item.content
+        && let Some(thumbnail) = thumbnail_image(item.id, *width, *height, rgba_bytes)
+    {
+        return render_thumbnail(thumbnail);
+    }
+    if let ClipboardContent::FilePaths(paths) = &item.content
+        && let [path] = paths.as_slice()
+        && is_image_file(path)
+    {
+        return render_thumbnail(path.clone());
+    }
+
     // Determine icon based on content type
     let icon = match &item.content {
         ClipboardContent::Text(_) => PhosphorIcon::ClipboardText, // Already handled above
         ClipboardContent::Image { .. } => PhosphorIcon::Image,
-        ClipboardContent::FilePaths(paths) => {
-            if paths.len() == 1 {
-                // Check file extension for specific icons
-                if let Some(ext) = paths[0].extension().and_then(|e| e.to_str()) {
-                    let ext_lower = ext.to_lowercase();
-                    if matches!(
-                        ext_lower.as_str(),
-                        "png" | "jpg" | "jpeg" | "gif" | "webp" | "svg"
-                    ) {
-                        PhosphorIcon::FileImage
-                    } else if matches!(
-                        ext_lower.as_str(),
-                        "txt"
-                            | "md"
-                            | "rs"
-                            | "py"
-                            | "js"
-                            | "ts"
-                            | "json"
-                            | "yaml"
-                            | "yml"
-                            | "toml"
-                            | "html"
-                            | "css"
-                            | "sh"
-                    ) {
-                        PhosphorIcon::FileText
-                    } else {
-                        PhosphorIcon::File
-                    }
-                } else {
-                    PhosphorIcon::File
-                }
-            } else {
-                PhosphorIcon::File
-            }
-        }
+        ClipboardContent::FilePaths(paths) => match paths.as_slice() {
+            [path] => file_icon(path),
+            _ => PhosphorIcon::File,
+        },
         ClipboardContent::RichText { .. } => PhosphorIcon::ClipboardText,
     };
 
     render_icon_container(icon)
 }
 
+/// Render an image thumbnail cropped to the icon box.
+fn render_thumbnail(source: impl Into<gpui::ImageSource>) -> Div {
+    let t = theme();
+    div().w(t.icon_size).h(t.icon_size).flex_shrink_0().child(
+        div()
+            .size_full()
+            .rounded_sm()
+            .overflow_hidden()
+            .bg(t.icon_placeholder_background)
+            .child(img(source).size_full().object_fit(gpui::ObjectFit::Cover)),
+    )
+}
+
 /// Render icon container matching main item style.
 fn render_icon_container(icon: PhosphorIcon) -> Div {
     let t = theme();
@@ -218,28 +299,21 @@ fn render_icon_container(icon: PhosphorIcon) -> Div {
         )
 }
 
-/// Format a SystemTime as a relative or absolute timestamp.
-fn format_timestamp(time: &SystemTime) -> String {
-    let now = SystemTime::now();
-    if let Ok(duration) = now.duration_since(*time) {
-        let secs = duration.as_secs();
-        if secs < 60 {
-            return "Just now".to_string();
-        } else if secs < 3600 {
-            let mins = secs / 60;
-            return format!("{} min{} ago", mins, if mins > 1 { "s" } else { "" });
-        } else if secs < 86400 {
-            let hours = secs / 3600;
-            return format!("{} hour{} ago", hours, if hours > 1 { "s" } else { "" });
-        }
-    }
-
-    // Fall back to a simple format
-    "Earlier".to_string()
-}
-
-/// Render the preview panel for the selected clipboard item.
-pub fn render_preview_panel(item: Option<&ClipboardItem>) -> Div {
+/// Render the preview panel for the selected clipboard item, or its QR code
+/// when `show_qr_code` is set.
+///
+/// Images show the text recognized in them instead when `ocr` is given.
+/// Text files (see `preview_file_path`) show `file`, which is read in the
+/// background, or a loading message until it's given.
+pub fn render_preview_panel(
+    item: Option<&ClipboardItem>,
+    show_qr_code: bool,
+    line_range: Option<LineRange>,
+    ocr: Option<&OcrState>,
+    file: Option<&FileReadState>,
+    window: &mut Window,
+    cx: &mut App,
+) -> Div {
     let t = theme();
 
     let panel = div()
@@ -255,163 +329,122 @@ pub fn render_preview_panel(item: Option<&ClipboardItem>) -> Div {
     let Some(item) = item else {
         return panel.child(
             div()
-                .text_sm()
+                .text_size(t.item_title_font_size)
                 .text_color(t.empty_state_color)
                 .child(SharedString::from("No selection")),
         );
     };
 
+    // Start each item's preview at the top
+    PREVIEW_SCROLL.with(|scroll| {
+        if scroll.item_id.replace(Some(item.id)) != Some(item.id) {
+            scroll.handle.set_offset(Point::default());
+        }
+    });
+
+    if show_qr_code {
+        return render_qr_code_preview(panel, item);
+    }
+
     match &item.content {
         ClipboardContent::Text(text) => {
             // Check if this is a color string
-            if let Some(color) = parse_color(text) {
-                return render_color_preview(panel, &color);
+            if let Some(color) = find_color(text) {
+                return render_color_preview(panel, &color, window, cx);
             }
 
             // Check if this is a file:// URL
             if let Some(path) = parse_file_url(text) {
                 // Treat it as a file path
-                if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-                    let ext_lower = ext.to_lowercase();
-                    if matches!(
-                        ext_lower.as_str(),
-                        "png" | "jpg" | "jpeg" | "gif" | "webp" | "svg"
-                    ) {
-                        // Render as image
-                        return panel.child(
-                            img(path)
-                                .w_full()
-                                .h_full()
-                                .object_fit(gpui::ObjectFit::Contain),
-                        );
-                    } else if matches!(
-                        ext_lower.as_str(),
-                        "txt"
-                            | "md"
-                            | "rs"
-                            | "py"
-                            | "js"
-                            | "ts"
-                            | "json"
-                            | "yaml"
-                            | "yml"
-                            | "toml"
-                            | "html"
-                            | "css"
-                            | "sh"
-                    ) {
-                        // Try to read and display file content
-                        if let Ok(content) = fs::read_to_string(&path) {
-                            let preview_content = if content.len() > 10000 {
-                                format!(
-                                    "{}...\n\n[Content truncated - {} bytes total]",
-                                    &content[..10000],
-                                    content.len()
-                                )
-                            } else {
-                                content
-                            };
-
-                            return panel.items_start().child(
-                                div()
-                                    .w_full()
-                                    .text_sm()
-                                    .text_color(t.item_title_color)
-                                    .child(SharedString::from(preview_content)),
-                            );
-                        }
-                    }
+                if is_image_file(&path) {
+                    let caption = image_file_caption(&path);
+                    return render_image_with_caption(panel, path, caption);
                 }
+
+                // Display the file content, or a summary of binary files
+                return render_file_read(panel, &path, file, line_range, window, cx);
+            }
+
+            // Render SVG markup as an image
+            if looks_like_svg(text)
+                && let Some(image) = svg_image(item.id, text)
+            {
+                let caption = format!("SVG · {}", format_bytes(text.len() as u64));
+                return render_image_with_caption(panel, image, Some(caption));
+            }
+
+            // Render web URLs as a clickable link
+            if let Some(url) = parse_url(text) {
+                return render_url_preview(panel, url);
+            }
+
+            // Break down Wi-Fi credentials, locations and contacts
+            if let Some(structured) = parse_structured_text(text) {
+                return render_structured_preview(panel, &structured, window, cx);
+            }
+
+            // Show decoded base64 content
+            if let Some(decoded) = decoded_base64(item.id, text) {
+                return render_base64_preview(panel, item.id, text, &decoded);
+            }
+
+            // Pretty-print JSON
+            if let Some(json) = pretty_print_json(text) {
+                return render_json_preview(panel, json, window, cx);
+            }
+
+            // Render markdown with formatting
+            if looks_like_markdown(text) {
+                let panel = render_markdown_preview(panel, text, window, cx);
+                return with_text_stats(panel, text);
             }
 
             // Show full text with wrapping
-            panel.items_start().child(
-                div()
-                    .w_full()
-                    .text_sm()
-                    .text_color(t.item_title_color)
-                    .child(SharedString::from(text.clone())),
-            )
+            let preview = TextFilePreview::new(text.clone(), None);
+            let panel = render_text_preview(panel, preview, window, cx);
+            with_text_stats(panel, text)
         }
         ClipboardContent::Image {
             width,
             height,
             rgba_bytes,
-        } => {
+        } => match ocr {
+            Some(OcrState::Done(text)) => {
+                let preview = TextFilePreview::new(text.clone(), None);
+                let panel = render_text_preview(panel, preview, window, cx);
+                with_footer(
+                    panel,
+                    format!("Recognized text · {}", format_text_stats(text)),
+                )
+            }
+            Some(state) => {
+                let message = match state {
+                    OcrState::Failed(message) => message.as_str(),
+                    _ => "Recognizing text...",
+                };
+                render_message(panel, message)
+            }
             // Try to render the image
-            render_image_preview_full(panel, *width, *height, rgba_bytes)
-        }
+            None => render_image_preview_full(panel, item.id, *width, *height, rgba_bytes),
+        },
         ClipboardContent::FilePaths(paths) => {
             if paths.len() == 1 {
                 let path = &paths[0];
 
                 // Check if it's an image file
-                if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-                    let ext_lower = ext.to_lowercase();
-                    if matches!(
-                        ext_lower.as_str(),
-                        "png" | "jpg" | "jpeg" | "gif" | "webp" | "svg"
-                    ) {
-                        // Try to load and display the image
-                        return panel.child(
-                            img(path.clone())
-                                .w_full()
-                                .h_full()
-                                .object_fit(gpui::ObjectFit::Contain),
-                        );
-                    } else if matches!(
-                        ext_lower.as_str(),
-                        "txt"
-                            | "md"
-                            | "rs"
-                            | "py"
-                            | "js"
-                            | "ts"
-                            | "json"
-                            | "yaml"
-                            | "yml"
-                            | "toml"
-                            | "html"
-                            | "css"
-                            | "sh"
-                    ) {
-                        // Try to read and display file content
-                        if let Ok(content) = fs::read_to_string(path) {
-                            // Limit content size to prevent performance issues
-                            let preview_content = if content.len() > 10000 {
-                                format!(
-                                    "{}...\n\n[Content truncated - {} bytes total]",
-                                    &content[..10000],
-                                    content.len()
-                                )
-                            } else {
-                                content
-                            };
-
-                            return panel.items_start().child(
-                                div()
-                                    .w_full()
-                                    .text_sm()
-                                    .text_color(t.item_title_color)
-                                    .child(SharedString::from(preview_content)),
-                            );
-                        }
-                    }
+                if is_image_file(path) {
+                    let caption = image_file_caption(path);
+                    return render_image_with_caption(panel, path.clone(), caption);
                 }
 
-                // Fallback: show file path
-                panel.items_start().child(
-                    div()
-                        .text_sm()
-                        .text_color(t.item_description_color)
-                        .child(SharedString::from(path.to_string_lossy().to_string())),
-                )
+                // Display the file content, or a summary of binary files
+                render_file_read(panel, path, file, line_range, window, cx)
             } else {
                 // Multiple files: show list
                 panel.items_start().child(
                     div()
                         .w_full()
-                        .text_sm()
+                        .text_size(t.item_title_font_size)
                         .text_color(t.item_title_color)
                         .child(SharedString::from(
                             paths
@@ -423,62 +456,790 @@ pub fn render_preview_panel(item: Option<&ClipboardItem>) -> Div {
                 )
             }
         }
-        ClipboardContent::RichText { plain, .. } => {
-            // Show plain text version
-            panel.items_start().child(
-                div()
-                    .w_full()
-                    .text_sm()
-                    .text_color(t.item_title_color)
-                    .child(SharedString::from(plain.clone())),
+        ClipboardContent::RichText { plain, html } => {
+            if html.len() <= MAX_HTML_PREVIEW_BYTES
+                && let Some(markdown) = html_to_markdown(html)
+            {
+                let preview = TextFilePreview::new(markdown, Some("markdown"));
+                let panel = render_text_preview(panel, preview, window, cx);
+                return with_text_stats(panel, plain);
+            }
+
+            // Fall back to the plain text version
+            let preview = TextFilePreview::new(plain.clone(), None);
+            let panel = render_text_preview(panel, preview, window, cx);
+            with_text_stats(panel, plain)
+        }
+    }
+}
+
+/// Text content prepared for the preview panel.
+struct TextFilePreview {
+    /// Content, truncated to `MAX_TEXT_PREVIEW_BYTES`
+    content: String,
+    /// Total size of the content in bytes, set when it was truncated
+    truncated_from: Option<usize>,
+    /// Highlighting language, `None` for plain text
+    language: Option<&'static str>,
+}
+
+impl TextFilePreview {
+    /// Prepare content for previewing, truncating it if needed.
+    fn new(mut content: String, language: Option<&'static str>) -> Self {
+        // Limit content size to prevent performance issues
+        let total = content.len();
+        let mut truncated_from = None;
+        if total > MAX_TEXT_PREVIEW_BYTES {
+            let mut end = MAX_TEXT_PREVIEW_BYTES;
+            while !content.is_char_boundary(end) {
+                end -= 1;
+            }
+            content.truncate(end);
+            truncated_from = Some(total);
+        }
+
+        Self {
+            content,
+            truncated_from,
+            language,
+        }
+    }
+}
+
+/// Check whether a file is previewed as an image, judging by its extension.
+fn is_image_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| {
+            matches!(
+                ext.to_lowercase().as_str(),
+                "png" | "jpg" | "jpeg" | "gif" | "webp" | "svg"
             )
+        })
+}
+
+/// Get the file whose content the preview of an item shows, if any.
+///
+/// This is a `file://` URL or a single copied file that isn't an image,
+/// which is read in the background for `render_preview_panel`.
+pub fn preview_file_path(item: &ClipboardItem) -> Option<PathBuf> {
+    let path = match &item.content {
+        ClipboardContent::Text(text) => parse_file_url(text)?,
+        ClipboardContent::FilePaths(paths) if paths.len() == 1 => paths[0].clone(),
+        _ => return None,
+    };
+    (!is_image_file(&path)).then_some(path)
+}
+
+/// Read a text file, for copying its contents.
+///
+/// Returns `None` if the file is not a text file or can't be read.
+pub fn read_text_file(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok()
+}
+
+/// Render a file read in the background, or why it's not shown yet.
+fn render_file_read(
+    panel: Div,
+    path: &Path,
+    state: Option<&FileReadState>,
+    line_range: Option<LineRange>,
+    window: &mut Window,
+    cx: &mut App,
+) -> Div {
+    match state {
+        Some(FileReadState::Done(FileContent::Text(content))) => {
+            render_text_file_preview(panel, path, content, line_range, window, cx)
         }
+        Some(FileReadState::Done(FileContent::Binary(summary))) => {
+            render_binary_summary(panel, path, summary)
+        }
+        Some(FileReadState::Failed(message)) => render_message(panel, message),
+        Some(FileReadState::Loading) | None => render_message(panel, "Loading..."),
     }
 }
 
+/// Render a summary of a binary file: its kind, name, MIME type and size,
+/// and a hex dump of its first bytes.
+fn render_binary_summary(panel: Div, path: &Path, summary: &BinarySummary) -> Div {
+    let t = theme();
+    let name = path
+        .file_name()
+        .map_or_else(|| path.to_string_lossy(), |name| name.to_string_lossy());
+
+    let panel = panel
+        .flex_col()
+        .items_start()
+        .gap_2()
+        .child(
+            div()
+                .flex_shrink_0()
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(
+                    div()
+                        .px_1p5()
+                        .rounded_sm()
+                        .bg(t.item_background_selected)
+                        .text_size(t.item_description_font_size)
+                        .text_color(t.item_description_color)
+                        .child(SharedString::from(summary.kind)),
+                )
+                .child(
+                    div()
+                        .text_size(t.item_title_font_size)
+                        .text_color(t.item_title_color)
+                        .child(SharedString::from(name.to_string())),
+                ),
+        )
+        .child(scrollable(
+            div()
+                .w_full()
+                .whitespace_nowrap()
+                .text_size(t.item_description_font_size)
+                .text_color(t.item_title_color)
+                .font_family(t.markdown.code_font_family)
+                .child(SharedString::from(hex_dump(&summary.head))),
+        ));
+    with_footer(
+        panel,
+        format!("{} · {}", summary.mime, format_bytes(summary.size)),
+    )
+}
+
+/// Render a short message in place of the preview.
+fn render_message(panel: Div, message: &str) -> Div {
+    let t = theme();
+    panel.child(
+        div()
+            .text_size(t.item_title_font_size)
+            .text_color(t.item_description_color)
+            .child(SharedString::from(message.to_string())),
+    )
+}
+
+/// Render text file content, or only the lines picked with a `:10-20` query.
+///
+/// The footer tells how to copy the contents or pick lines.
+fn render_text_file_preview(
+    panel: Div,
+    path: &Path,
+    content: &str,
+    line_range: Option<LineRange>,
+    window: &mut Window,
+    cx: &mut App,
+) -> Div {
+    let language = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .and_then(code_language);
+    let line_count = content.lines().count();
+
+    let (content, footer) = match line_range.and_then(|range| range.clamp(line_count)) {
+        Some(range) => {
+            let lines = range.select(content).unwrap_or_default().to_string();
+            let footer = if range.start == range.end {
+                format!("Line {} of {} · Alt+C to copy it", range.start, line_count)
+            } else {
+                format!(
+                    "Lines {}-{} of {} · Alt+C to copy them",
+                    range.start, range.end, line_count
+                )
+            };
+            (lines, footer)
+        }
+        None if line_range.is_some() => {
+            (String::new(), format!("The file has {} lines", line_count))
+        }
+        None => (
+            content.to_string(),
+            format!(
+                "{} lines · Alt+C to copy the contents, :10-20 to pick lines",
+                line_count
+            ),
+        ),
+    };
+
+    let panel = render_text_preview(panel, TextFilePreview::new(content, language), window, cx);
+    with_footer(panel, footer)
+}
+
+/// Render pretty-printed JSON with a badge confirming the detection.
+fn render_json_preview(panel: Div, json: String, window: &mut Window, cx: &mut App) -> Div {
+    let t = theme();
+    let preview = TextFilePreview::new(json, Some("json"));
+
+    panel
+        .flex_col()
+        .items_start()
+        .gap_2()
+        .child(
+            div()
+                .flex_shrink_0()
+                .px_1p5()
+                .rounded_sm()
+                .bg(t.item_background_selected)
+                .text_size(t.item_description_font_size)
+                .text_color(t.item_description_color)
+                .child(SharedString::from("JSON")),
+        )
+        .child(render_text_preview(
+            div().w_full().flex_1().min_h_0(),
+            preview,
+            window,
+            cx,
+        ))
+}
+
+/// Render text file content, syntax highlighted when the language is known.
+fn render_text_preview(
+    panel: Div,
+    preview: TextFilePreview,
+    window: &mut Window,
+    cx: &mut App,
+) -> Div {
+    let t = theme();
+    let truncated_note = preview
+        .truncated_from
+        .map(|total| format!("[Content truncated - {} bytes total]", total));
+
+    if preview.language == Some("markdown") {
+        let mut markdown = preview.content;
+        if let Some(note) = truncated_note {
+            markdown.push_str(&format!("\n\n*{}*", note));
+        }
+        return render_markdown_preview(panel, &markdown, window, cx);
+    }
+
+    let Some(language) = preview.language else {
+        let preview_content = match truncated_note {
+            Some(note) => format!("{}...\n\n{}", preview.content, note),
+            None => preview.content,
+        };
+
+        return panel.flex_col().items_start().child(scrollable(
+            div()
+                .w_full()
+                .text_size(t.item_title_font_size)
+                .text_color(t.item_title_color)
+                .child(SharedString::from(preview_content)),
+        ));
+    };
+
+    // Render as a fenced code block so it goes through the same theme-aware
+    // highlighter as code in AI responses. The fence must be longer than any
+    // backtick run inside the code.
+    let longest_run = preview
+        .content
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    let markdown = format!("{fence}{language}\n{}\n{fence}", preview.content);
+
+    panel.flex_col().items_start().child(scrollable(
+        div()
+            .w_full()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(render_markdown_with_id(
+                "clipboard-code-preview",
+                &markdown,
+                window,
+                cx,
+            ))
+            .when_some(truncated_note, |this, note| {
+                this.child(
+                    div()
+                        .text_size(t.item_description_font_size)
+                        .text_color(t.item_description_color)
+                        .child(SharedString::from(note)),
+                )
+            }),
+    ))
+}
+
+/// Wrap text preview content so that it scrolls within the preview column.
+fn scrollable(content: Div) -> Stateful<Div> {
+    let handle = PREVIEW_SCROLL.with(|scroll| scroll.handle.clone());
+    div()
+        .id("clipboard-preview-scroll")
+        .w_full()
+        .flex_1()
+        .min_h_0()
+        .overflow_y_scroll()
+        .track_scroll(&handle)
+        .child(content)
+}
+
+/// Scroll the text preview down by `delta` (up when negative).
+pub fn scroll_preview(delta: Pixels) {
+    PREVIEW_SCROLL.with(|scroll| {
+        let handle = &scroll.handle;
+        let mut offset = handle.offset();
+        let max = handle.max_offset().height;
+        offset.y = (offset.y - delta).clamp(-max, px(0.0));
+        handle.set_offset(offset);
+    });
+}
+
 /// Render an image from raw RGBA bytes in the preview panel.
-fn render_image_preview_full(panel: Div, width: usize, height: usize, rgba_bytes: &[u8]) -> Div {
-    use image::{ImageBuffer, ImageFormat, Rgba};
-    use std::io::Cursor;
-    use std::sync::Arc;
+fn render_image_preview_full(
+    panel: Div,
+    item_id: u64,
+    width: usize,
+    height: usize,
+    rgba_bytes: &[u8],
+) -> Div {
+    if let Some(gpui_image) = preview_image(item_id, width, height, rgba_bytes) {
+        // Use the in-memory image directly
+        let caption = image_caption(width as u32, height as u32, gpui_image.bytes().len() as u64);
+        return render_image_with_caption(panel, gpui_image, Some(caption));
+    }
+
+    // Fallback: show error message
+    panel.child(render_image_unavailable())
+}
+
+/// Render the message shown in place of an image that can't be decoded.
+fn render_image_unavailable() -> Div {
+    let t = theme();
+    div()
+        .text_size(t.item_title_font_size)
+        .text_color(t.item_description_color)
+        .child(SharedString::from("[Image preview unavailable]"))
+}
+
+/// Render the content of an item as a QR code scaled to fit the panel.
+fn render_qr_code_preview(panel: Div, item: &ClipboardItem) -> Div {
     let t = theme();
 
-    // Create ImageBuffer from raw RGBA pixel data
-    if let Some(img_buffer) =
-        ImageBuffer::<Rgba<u8>, _>::from_raw(width as u32, height as u32, rgba_bytes.to_vec())
-    {
-        // Encode image as PNG in memory
-        let mut png_bytes = Vec::new();
-        let mut cursor = Cursor::new(&mut png_bytes);
+    let qr_code = match &item.content {
+        ClipboardContent::Image { .. } => Err("Images can't be shown as QR codes".to_string()),
+        _ => qr_code(item.id, &item.full_content()),
+    };
+    match qr_code {
+        Ok(image) => render_image_with_caption(panel, image, Some("QR code".to_string())),
+        Err(message) => panel.child(
+            div()
+                .text_size(t.item_title_font_size)
+                .text_color(t.item_description_color)
+                .child(SharedString::from(message)),
+        ),
+    }
+}
+
+/// Get the QR code of a clipboard item, creating it on first use.
+fn qr_code(item_id: u64, text: &str) -> Result<Arc<gpui::Image>, String> {
+    let mut qr_codes = QR_CODES.lock().unwrap();
+    if let Some(image) = qr_codes.get(&item_id) {
+        return Ok(image.clone());
+    }
+
+    let image = encode_png(&qr_code_image(text)?).ok_or("Can't encode QR code")?;
+    qr_codes.insert(item_id, image.clone());
+    Ok(image)
+}
+
+/// Get the PNG encoded preview image for a clipboard item, encoding it on
+/// first use.
+fn preview_image(
+    item_id: u64,
+    width: usize,
+    height: usize,
+    rgba_bytes: &[u8],
+) -> Option<Arc<gpui::Image>> {
+    cached_preview(item_id, || {
+        // Create ImageBuffer from raw RGBA pixel data
+        let img_buffer =
+            image::RgbaImage::from_raw(width as u32, height as u32, rgba_bytes.to_vec())?;
+        encode_png(&img_buffer)
+    })
+}
+
+/// Get the image drawn by the SVG markup of a clipboard item.
+///
+/// The markup is only parsed when the image is rendered, so malformed SVG
+/// shows the fallback of the image element instead.
+fn svg_image(item_id: u64, text: &str) -> Option<Arc<gpui::Image>> {
+    cached_preview(item_id, || {
+        Some(Arc::new(gpui::Image::from_bytes(
+            gpui::ImageFormat::Svg,
+            text.as_bytes().to_vec(),
+        )))
+    })
+}
+
+/// Get the preview image of a clipboard item from the cache, creating it with
+/// `create` on first use.
+fn cached_preview(
+    item_id: u64,
+    create: impl FnOnce() -> Option<Arc<gpui::Image>>,
+) -> Option<Arc<gpui::Image>> {
+    let mut previews = IMAGE_PREVIEWS.lock().unwrap();
+    if let Some(image) = previews.get(&item_id) {
+        return Some(image.clone());
+    }
+
+    let image = create()?;
+    previews.insert(item_id, image.clone());
+    Some(image)
+}
+
+/// Get the decoded base64 content of clipboard text, decoding it on first use.
+fn decoded_base64(item_id: u64, text: &str) -> Option<Arc<DecodedBase64>> {
+    DECODED_BASE64
+        .lock()
+        .unwrap()
+        .entry(item_id)
+        .or_insert_with(|| decode_base64(text).map(Arc::new))
+        .clone()
+}
+
+/// Get the downscaled list thumbnail for a clipboard image, creating it on
+/// first use.
+fn thumbnail_image(
+    item_id: u64,
+    width: usize,
+    height: usize,
+    rgba_bytes: &[u8],
+) -> Option<Arc<gpui::Image>> {
+    let mut thumbnails = THUMBNAILS.lock().unwrap();
+    if let Some(image) = thumbnails.get(&item_id) {
+        return Some(image.clone());
+    }
+
+    let (width, height) = (width as u32, height as u32);
+    let img_buffer = image::RgbaImage::from_raw(width, height, rgba_bytes.to_vec())?;
+
+    // Scale the shorter side down to the thumbnail size, the icon box crops the rest
+    let scale = (THUMBNAIL_SIZE as f32 / width.min(height).max(1) as f32).min(1.0);
+    let thumbnail = image::imageops::thumbnail(
+        &img_buffer,
+        ((width as f32 * scale) as u32).max(1),
+        ((height as f32 * scale) as u32).max(1),
+    );
+
+    let image = encode_png(&thumbnail)?;
+    thumbnails.insert(item_id, image.clone());
+    Some(image)
+}
+
+/// Encode an RGBA buffer as an in-memory PNG image.
+fn encode_png(buffer: &image::RgbaImage) -> Option<Arc<gpui::Image>> {
+    let mut png_bytes = Vec::new();
+    buffer
+        .write_to(
+            &mut std::io::Cursor::new(&mut png_bytes),
+            image::ImageFormat::Png,
+        )
+        .ok()?;
+
+    Some(Arc::new(gpui::Image::from_bytes(
+        gpui::ImageFormat::Png,
+        png_bytes,
+    )))
+}
 
-        if img_buffer.write_to(&mut cursor, ImageFormat::Png).is_ok() {
-            // Create GPUI Image from PNG bytes and wrap in Arc
-            let gpui_image = Arc::new(gpui::Image::from_bytes(gpui::ImageFormat::Png, png_bytes));
+/// Release the preview images, QR codes, thumbnails and decoded base64
+/// content created while the clipboard view was open.
+///
+/// gpui keeps every rendered image in its asset cache for the lifetime of the
+/// app, so without this each previewed image would stay in memory until the
+/// daemon exits.
+pub fn release_image_previews(cx: &mut App) {
+    DECODED_BASE64.lock().unwrap().clear();
+
+    let mut images = Vec::new();
+    for cache in [&IMAGE_PREVIEWS, &QR_CODES, &THUMBNAILS] {
+        let cached = std::mem::take(&mut *cache.lock().unwrap());
+        images.extend(cached.into_values());
+    }
+    if images.is_empty() {
+        return;
+    }
 
-            // Use the in-memory image directly
-            return panel.child(
-                img(gpui_image)
+    tracing::debug!(count = images.len(), "Releasing clipboard preview images");
+    for image in images {
+        image.remove_asset(cx);
+    }
+}
+
+/// Render an image filling the panel, with an optional caption below it.
+///
+/// Images that fail to load (e.g. malformed SVG) show a message instead.
+fn render_image_with_caption(
+    panel: Div,
+    source: impl Into<gpui::ImageSource>,
+    caption: Option<String>,
+) -> Div {
+    let t = theme();
+
+    panel
+        .flex_col()
+        .gap_2()
+        .child(
+            img(source)
+                .w_full()
+                .flex_1()
+                .min_h_0()
+                .object_fit(gpui::ObjectFit::Contain)
+                .with_fallback(|| render_image_unavailable().into_any_element()),
+        )
+        .when_some(caption, |panel, caption| {
+            panel.child(
+                div()
+                    .flex_shrink_0()
+                    .text_size(t.item_description_font_size)
+                    .text_color(t.item_description_color)
+                    .child(SharedString::from(caption)),
+            )
+        })
+}
+
+/// Build the dimensions and size caption for an image file.
+///
+/// Returns `None` if the file can't be read.
+fn image_file_caption(path: &Path) -> Option<String> {
+    let size = fs::metadata(path).ok()?.len();
+    match image::image_dimensions(path) {
+        Ok((width, height)) => Some(image_caption(width, height, size)),
+        // Formats the image crate can't decode (e.g. SVG) only get a size
+        Err(_) => Some(format_bytes(size)),
+    }
+}
+
+/// Format an image caption like "1920 × 1080 · 2.4 MB".
+fn image_caption(width: u32, height: u32, bytes: u64) -> String {
+    format!("{} × {} · {}", width, height, format_bytes(bytes))
+}
+
+/// Render base64 text with its decoded content.
+fn render_base64_preview(panel: Div, item_id: u64, raw: &str, decoded: &DecodedBase64) -> Div {
+    let t = theme();
+
+    let (label, content) = match decoded {
+        DecodedBase64::Text(text) => (
+            format!(
+                "Base64 · decoded text ({})",
+                format_bytes(text.len() as u64)
+            ),
+            div()
+                .w_full()
+                .text_size(t.item_title_font_size)
+                .text_color(t.item_title_color)
+                .child(SharedString::from(text.clone()))
+                .into_any_element(),
+        ),
+        DecodedBase64::Png(bytes) | DecodedBase64::Jpeg(bytes) => {
+            let size = bytes.len() as u64;
+            let format = if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+                gpui::ImageFormat::Jpeg
+            } else {
+                gpui::ImageFormat::Png
+            };
+            let image = cached_preview(item_id, || {
+                Some(Arc::new(gpui::Image::from_bytes(format, bytes.clone())))
+            });
+            let content = match image {
+                Some(image) => img(image)
                     .w_full()
                     .h_full()
-                    .object_fit(gpui::ObjectFit::Contain),
-            );
+                    .object_fit(gpui::ObjectFit::Contain)
+                    .into_any_element(),
+                None => div().into_any_element(),
+            };
+            (
+                format!("Base64 · decoded image ({})", format_bytes(size)),
+                content,
+            )
         }
+    };
+
+    // Only show the start of long raw strings
+    let raw = raw.trim();
+    let raw_preview = match raw.char_indices().nth(MAX_RAW_BASE64_CHARS) {
+        Some((end, _)) => format!("{}…", &raw[..end]),
+        None => raw.to_string(),
+    };
+
+    panel
+        .flex_col()
+        .items_start()
+        .gap_2()
+        .child(
+            div()
+                .flex_shrink_0()
+                .text_size(t.item_description_font_size)
+                .text_color(t.item_description_color)
+                .child(SharedString::from(label)),
+        )
+        .child(
+            div()
+                .w_full()
+                .flex_1()
+                .min_h_0()
+                .overflow_hidden()
+                .child(content),
+        )
+        .child(
+            div()
+                .w_full()
+                .flex_shrink_0()
+                .text_size(t.item_description_font_size)
+                .text_color(t.item_description_color)
+                .child(SharedString::from(raw_preview)),
+        )
+}
+
+/// Render a web URL as a link that opens in the browser when clicked.
+fn render_url_preview(panel: Div, url: &str) -> Div {
+    let t = theme();
+    let url = url.to_string();
+
+    panel.flex_col().gap_2().child(
+        div()
+            .id("clipboard-url-preview")
+            .max_w_full()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_2()
+            .cursor_pointer()
+            .text_size(t.item_title_font_size)
+            .text_color(t.item_title_color)
+            .hover(|style| style.underline())
+            .child(
+                svg()
+                    .path(PhosphorIcon::Link.path())
+                    .size_4()
+                    .flex_shrink_0()
+                    .text_color(t.item_description_color),
+            )
+            .child(div().min_w_0().child(SharedString::from(url.clone())))
+            .on_click(move |_, _window, _cx| {
+                if let Err(e) = open_url(&url) {
+                    tracing::warn!(%e, "Failed to open URL");
+                }
+            }),
+    )
+}
+
+/// Get the icon of Wi-Fi, location or contact text.
+fn structured_icon(structured: &StructuredText) -> PhosphorIcon {
+    match structured {
+        StructuredText::Wifi { .. } => PhosphorIcon::WifiHigh,
+        StructuredText::Geo { .. } => PhosphorIcon::MapPin,
+        StructuredText::Contact(_) => PhosphorIcon::User,
+    }
+}
+
+/// Render the fields of Wi-Fi, location or contact text, each copied on click.
+/// Locations also get a link to the map.
+fn render_structured_preview(
+    panel: Div,
+    structured: &StructuredText,
+    window: &mut Window,
+    cx: &mut App,
+) -> Div {
+    let t = theme();
+    let copied = window.use_keyed_state("clipboard-field-copied", cx, |_, _| None::<&'static str>);
+
+    let title = match structured {
+        StructuredText::Wifi { .. } => "Wi-Fi network",
+        StructuredText::Geo { .. } => "Location",
+        StructuredText::Contact(_) => "Contact",
+    };
+
+    let mut fields = div().flex().flex_col().gap(t.clipboard.color_code_gap);
+    for (label, value) in structured.fields() {
+        fields = fields.child(render_copyable_row(
+            label,
+            value.clone(),
+            value,
+            &copied,
+            cx,
+        ));
     }
 
-    // Fallback: show error message
-    panel.child(
+    panel
+        .flex_col()
+        .items_start()
+        .gap_2()
+        .child(
+            div()
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap_2()
+                .text_size(t.item_description_font_size)
+                .text_color(t.item_description_color)
+                .child(
+                    svg()
+                        .path(structured_icon(structured).path())
+                        .size_4()
+                        .flex_shrink_0()
+                        .text_color(t.item_description_color),
+                )
+                .child(SharedString::from(title)),
+        )
+        .child(fields)
+        .when_some(structured.map_url(), |panel, url| {
+            panel.child(render_url_preview(div(), &url))
+        })
+}
+
+/// Render markdown with headings, emphasis, lists and code blocks formatted.
+fn render_markdown_preview(panel: Div, text: &str, window: &mut Window, cx: &mut App) -> Div {
+    panel
+        .flex_col()
+        .items_start()
+        .child(scrollable(div().w_full().child(render_markdown_with_id(
+            "clipboard-markdown-preview",
+            text,
+            window,
+            cx,
+        ))))
+}
+
+/// Add a footer with the character and word count of `text` below the
+/// preview content.
+fn with_text_stats(panel: Div, text: &str) -> Div {
+    with_footer(panel, format_text_stats(text))
+}
+
+/// Add a footer line below the preview content.
+fn with_footer(panel: Div, footer: String) -> Div {
+    let t = theme();
+
+    panel.flex_col().gap_2().child(
         div()
-            .text_sm()
+            .flex_shrink_0()
+            .text_size(t.item_description_font_size)
             .text_color(t.item_description_color)
-            .child(SharedString::from("[Image preview unavailable]")),
+            .child(SharedString::from(footer)),
     )
 }
 
 /// Render a color preview with swatch and color codes.
-fn render_color_preview(panel: Div, color: &Color) -> Div {
+///
+/// Clicking a color code copies that representation to the clipboard.
+fn render_color_preview(panel: Div, color: &Color, window: &mut Window, cx: &mut App) -> Div {
     let t = theme();
     let (h, s, l) = color.to_hsl();
 
+    // Row that was just copied, highlighted briefly as confirmation
+    let copied = window.use_keyed_state("clipboard-color-copied", cx, |_, _| None::<&'static str>);
+
+    let rgb_copy = if color.a == 255 {
+        color.to_rgb_string()
+    } else {
+        color.to_rgba_string()
+    };
+
     panel
         .flex_col()
         .items_center()
@@ -505,63 +1266,153 @@ fn render_color_preview(panel: Div, color: &Color) -> Div {
                 .flex()
                 .flex_col()
                 .gap(t.clipboard.color_code_gap)
-                .child(
-                    // HEX
-                    div()
-                        .flex()
-                        .flex_row()
-                        .gap(t.clipboard.color_code_gap)
-                        .child(
-                            div()
-                                .w(t.clipboard.color_label_width)
-                                .text_xs()
-                                .text_color(t.item_description_color)
-                                .child(SharedString::from("HEX")),
-                        )
-                        .child(
-                            div()
-                                .text_sm()
-                                .text_color(t.item_title_color)
-                                .child(SharedString::from(color.to_hex())),
-                        ),
-                )
-                .child(
-                    // RGB
-                    div()
-                        .flex()
-                        .flex_row()
-                        .gap(t.clipboard.color_code_gap)
-                        .child(
-                            div()
-                                .w(t.clipboard.color_label_width)
-                                .text_xs()
-                                .text_color(t.item_description_color)
-                                .child(SharedString::from("RGB")),
-                        )
-                        .child(div().text_sm().text_color(t.item_title_color).child(
-                            SharedString::from(format!("{}, {}, {}", color.r, color.g, color.b)),
-                        )),
-                )
-                .child(
-                    // HSL
-                    div()
-                        .flex()
-                        .flex_row()
-                        .gap(t.clipboard.color_code_gap)
-                        .child(
-                            div()
-                                .w(t.clipboard.color_label_width)
-                                .text_xs()
-                                .text_color(t.item_description_color)
-                                .child(SharedString::from("HSL")),
-                        )
-                        .child(
-                            div()
-                                .text_sm()
-                                .text_color(t.item_title_color)
-                                .child(SharedString::from(format!("{}°, {}%, {}%", h, s, l))),
-                        ),
-                ),
+                .child(render_copyable_row(
+                    "HEX",
+                    color.to_hex(),
+                    color.to_hex(),
+                    &copied,
+                    cx,
+                ))
+                .child(render_copyable_row(
+                    "RGB",
+                    format!("{}, {}, {}", color.r, color.g, color.b),
+                    rgb_copy,
+                    &copied,
+                    cx,
+                ))
+                .child(render_copyable_row(
+                    "HSL",
+                    format!("{}°, {}%, {}%", h, s, l),
+                    color.to_hsl_string(),
+                    &copied,
+                    cx,
+                )),
+        )
+        .child(
+            // Accessibility check against plain black and white text
+            div()
+                .flex()
+                .flex_col()
+                .gap(t.clipboard.color_code_gap)
+                .child(render_color_info_row(
+                    "LUM",
+                    format!("{:.3}", color.relative_luminance()),
+                ))
+                .child(render_contrast_row(
+                    "WHITE",
+                    color.contrast_ratio(&Color::from_rgb(255, 255, 255)),
+                ))
+                .child(render_contrast_row(
+                    "BLACK",
+                    color.contrast_ratio(&Color::from_rgb(0, 0, 0)),
+                )),
+        )
+}
+
+/// Render a labeled row with a value, laid out like the color code rows.
+fn render_color_info_row(label: &'static str, display: String) -> Div {
+    let t = theme();
+    div()
+        .flex()
+        .flex_row()
+        .items_center()
+        .gap(t.clipboard.color_code_gap)
+        .px_1()
+        .child(
+            div()
+                .w(t.clipboard.color_label_width)
+                .text_size(t.item_description_font_size)
+                .text_color(t.item_description_color)
+                .child(SharedString::from(label)),
+        )
+        .child(
+            div()
+                .text_size(t.item_title_font_size)
+                .text_color(t.item_title_color)
+                .child(SharedString::from(display)),
+        )
+}
+
+/// Render the contrast ratio against a text color, with a badge saying
+/// whether it passes WCAG AA for normal text.
+fn render_contrast_row(label: &'static str, ratio: f32) -> Div {
+    let t = theme();
+    let passes = ratio >= WCAG_AA_NORMAL_TEXT;
+    let badge_color = if passes {
+        t.item_title_color
+    } else {
+        t.ai.error_title_color
+    };
+
+    render_color_info_row(label, format!("{:.2}:1", ratio)).child(
+        div()
+            .px_1()
+            .rounded_sm()
+            .border_1()
+            .border_color(badge_color)
+            .text_size(t.item_description_font_size)
+            .text_color(badge_color)
+            .child(SharedString::from(if passes { "AA" } else { "AA fail" })),
+    )
+}
+
+/// Render a clickable labeled row that copies `copy_text` when clicked.
+fn render_copyable_row(
+    label: &'static str,
+    display: String,
+    copy_text: String,
+    copied: &Entity<Option<&'static str>>,
+    cx: &App,
+) -> Stateful<Div> {
+    let t = theme();
+    let is_copied = *copied.read(cx) == Some(label);
+    let copied = copied.clone();
+
+    div()
+        .id(label)
+        .flex()
+        .flex_row()
+        .gap(t.clipboard.color_code_gap)
+        .px_1()
+        .rounded_sm()
+        .cursor_pointer()
+        .hover(|style| style.bg(t.item_background_selected))
+        .when(is_copied, |row| row.bg(t.item_background_selected))
+        .on_click(move |_, _window, cx| {
+            if let Err(e) = copy_to_clipboard(&copy_text) {
+                tracing::error!(%e, "Failed to copy to clipboard");
+                return;
+            }
+
+            copied.update(cx, |copied, cx| {
+                *copied = Some(label);
+                cx.notify();
+            });
+
+            let copied = copied.clone();
+            cx.spawn(async move |cx: &mut gpui::AsyncApp| {
+                cx.background_executor().timer(COPY_FLASH_DURATION).await;
+                let _ = copied.update(cx, |copied, cx| {
+                    if *copied == Some(label) {
+                        *copied = None;
+                        cx.notify();
+                    }
+                });
+            })
+            .detach();
+        })
+        .child(
+            div()
+                .w(t.clipboard.color_label_width)
+                .text_size(t.item_description_font_size)
+                .text_color(t.item_description_color)
+                .child(SharedString::from(label)),
+        )
+        .child(
+            div()
+                .text_size(t.item_title_font_size)
+                .text_color(t.item_title_color)
+                .child(SharedString::from(display)),
         )
 }
 
@@ -5,38 +5,123 @@
 
 use crate::assets::PhosphorIcon;
 use crate::items::{DisplayItem, IconProvider, ListItem};
+use crate::ui::icon_cache::{CachedIcon, cached_icon};
 use crate::ui::theme::theme;
-use gpui::{Div, ElementId, SharedString, Stateful, div, img, prelude::*, px, svg};
+use crate::ui::utils::char_indices_to_ranges;
+use gpui::{
+    Div, ElementId, FontWeight, HighlightStyle, SharedString, Stateful, StyledText, div, img,
+    prelude::*, px, svg,
+};
 use std::path::PathBuf;
 
 /// Render any list item based on its type.
 /// This is the main dispatch function for item rendering.
-pub fn render_item(item: &ListItem, selected: bool, row: usize) -> Stateful<Div> {
+///
+/// `matches` holds the character indices of the name matched by the query,
+/// which are highlighted.
+pub fn render_item(
+    item: &ListItem,
+    selected: bool,
+    row: usize,
+    matches: &[usize],
+) -> Stateful<Div> {
     match item {
-        ListItem::Application(app) => render_application(app, selected, row),
-        ListItem::Window(win) => render_window(win, selected, row),
-        ListItem::Action(act) => render_action(act, selected, row),
-        ListItem::Submenu(sub) => render_submenu(sub, selected, row),
+        ListItem::Application(app) => render_application(app, selected, row, matches),
+        ListItem::Window(win) => render_window(win, selected, row, matches),
+        ListItem::Action(act) => render_action(act, selected, row, matches),
+        ListItem::Submenu(sub) => render_submenu(sub, selected, row, matches),
         ListItem::Calculator(calc) => render_calculator(calc, selected, row),
         ListItem::Search(search) => render_search(search, selected, row),
+        ListItem::Snippet(snippet) => render_snippet(snippet, selected, row, matches),
+        ListItem::File(file) => render_file(file, selected, row),
         ListItem::Ai(ai) => render_ai(ai, selected, row),
         ListItem::Theme(theme) => crate::ui::views::render_theme_item(theme, selected, row),
     }
 }
 
+/// Render an item as a cell of the grid layout, its icon above the name.
+pub fn render_grid_item(item: &ListItem, selected: bool, row: usize) -> Stateful<Div> {
+    let theme = theme();
+
+    let icon = match item {
+        ListItem::Application(app) => render_icon(app.icon_path.as_ref(), app.fallback_icon())
+            .relative()
+            .when(app.pinned, |icon| icon.child(render_pin_badge())),
+        ListItem::Window(win) => render_icon(win.icon_path.as_ref(), PhosphorIcon::AppWindow),
+        ListItem::Action(act) => {
+            render_phosphor_icon(act.icon_name().and_then(PhosphorIcon::from_name))
+        }
+        ListItem::Submenu(sub) => {
+            render_phosphor_icon(sub.icon_name().and_then(PhosphorIcon::from_name))
+        }
+        ListItem::File(file) => render_phosphor_icon(Some(file.icon())),
+        ListItem::Search(search) => render_phosphor_icon(Some(search.icon())),
+        ListItem::Snippet(snippet) => render_phosphor_icon(Some(snippet.icon())),
+        ListItem::Ai(ai) => render_phosphor_icon(Some(ai.icon())),
+        ListItem::Calculator(_) | ListItem::Theme(_) => render_phosphor_icon(None),
+    };
+
+    let bg_color = if selected {
+        theme.item_background_selected
+    } else {
+        theme.item_background
+    };
+
+    div()
+        .id(ElementId::NamedInteger("grid-item".into(), row as u64))
+        .flex_1()
+        .min_w_0()
+        .my(theme.item_margin_y)
+        .px(theme.item_padding_x)
+        .py(theme.item_padding_y)
+        .bg(bg_color)
+        .rounded(theme.item_border_radius)
+        .overflow_hidden()
+        .flex()
+        .flex_col()
+        .items_center()
+        .gap_1()
+        .child(icon)
+        .child(
+            div()
+                .w_full()
+                .text_center()
+                .text_size(theme.item_description_font_size)
+                .text_color(theme.item_title_color)
+                .whitespace_nowrap()
+                .overflow_hidden()
+                .text_ellipsis()
+                .child(SharedString::from(item.name().to_string())),
+        )
+}
+
 /// Render an application item.
 fn render_application(
     app: &crate::items::ApplicationItem,
     selected: bool,
     row: usize,
+    matches: &[usize],
 ) -> Stateful<Div> {
-    let mut item = item_container(row, selected)
-        .child(render_icon(app.icon_path.as_ref()))
-        .child(render_text_content(
+    // The command line stands in for a missing description
+    let description = app.description.as_deref().filter(|desc| !desc.is_empty());
+    let text_content = if crate::config::config().show_exec_command || description.is_none() {
+        build_text_content(
             &app.name,
-            app.description.as_deref(),
+            matches,
+            Some(render_description(&app.exec).font_family(theme().markdown.code_font_family)),
             selected,
-        ));
+        )
+    } else {
+        render_matched_text_content(&app.name, matches, description, selected)
+    };
+
+    let mut item = item_container(row, selected)
+        .child(
+            render_icon(app.icon_path.as_ref(), app.fallback_icon())
+                .relative()
+                .when(app.pinned, |icon| icon.child(render_pin_badge())),
+        )
+        .child(text_content);
 
     if selected {
         item = item.child(render_action_indicator("Open"));
@@ -46,11 +131,17 @@ fn render_application(
 }
 
 /// Render a window item.
-fn render_window(win: &crate::items::WindowItem, selected: bool, row: usize) -> Stateful<Div> {
+fn render_window(
+    win: &crate::items::WindowItem,
+    selected: bool,
+    row: usize,
+    matches: &[usize],
+) -> Stateful<Div> {
     let mut item = item_container(row, selected)
-        .child(render_icon(win.icon_path.as_ref()))
-        .child(render_text_content(
+        .child(render_icon(win.icon_path.as_ref(), PhosphorIcon::AppWindow))
+        .child(render_matched_text_content(
             &win.title,
+            matches,
             Some(&win.description),
             selected,
         ));
@@ -63,12 +154,18 @@ fn render_window(win: &crate::items::WindowItem, selected: bool, row: usize) ->
 }
 
 /// Render an action item.
-fn render_action(act: &crate::items::ActionItem, selected: bool, row: usize) -> Stateful<Div> {
+fn render_action(
+    act: &crate::items::ActionItem,
+    selected: bool,
+    row: usize,
+    matches: &[usize],
+) -> Stateful<Div> {
     let icon = act.icon_name().and_then(PhosphorIcon::from_name);
     let mut item = item_container(row, selected)
         .child(render_phosphor_icon(icon))
-        .child(render_text_content(
+        .child(render_matched_text_content(
             &act.name,
+            matches,
             act.description.as_deref(),
             selected,
         ));
@@ -81,12 +178,18 @@ fn render_action(act: &crate::items::ActionItem, selected: bool, row: usize) ->
 }
 
 /// Render a submenu item.
-fn render_submenu(sub: &crate::items::SubmenuItem, selected: bool, row: usize) -> Stateful<Div> {
+fn render_submenu(
+    sub: &crate::items::SubmenuItem,
+    selected: bool,
+    row: usize,
+    matches: &[usize],
+) -> Stateful<Div> {
     let icon = sub.icon_name().and_then(PhosphorIcon::from_name);
     let mut item = item_container(row, selected)
         .child(render_phosphor_icon(icon))
-        .child(render_text_content(
+        .child(render_matched_text_content(
             &sub.name,
+            matches,
             sub.description.as_deref(),
             selected,
         ));
@@ -105,6 +208,7 @@ fn render_submenu(sub: &crate::items::SubmenuItem, selected: bool, row: usize) -
 /// - A custom "=" icon in a colored circle
 /// - The expression as muted smaller text
 /// - The result (or error) with "= " prefix in larger text
+/// - Other representations of the result below it, the copied one in bold
 fn render_calculator(
     calc: &crate::items::CalculatorItem,
     selected: bool,
@@ -186,7 +290,7 @@ fn render_calculator_content(calc: &crate::items::CalculatorItem, selected: bool
 
     let max_width = theme.max_text_width(px(crate::config::window_width()), selected);
 
-    div()
+    let content = div()
         .h(theme.item_content_height)
         .max_w(max_width)
         .flex()
@@ -196,14 +300,49 @@ fn render_calculator_content(calc: &crate::items::CalculatorItem, selected: bool
         .child(
             div()
                 .w_full()
-                .text_sm()
+                .text_size(theme.item_title_font_size)
                 .line_height(theme.item_title_line_height)
                 .text_color(result_color)
                 .whitespace_nowrap()
                 .overflow_hidden()
                 .text_ellipsis()
                 .child(SharedString::from(calc.display_result.clone())),
-        )
+        );
+
+    if calc.alternatives.is_empty() {
+        return content;
+    }
+
+    // Bold the representation that gets copied
+    let mut breakdown = String::new();
+    let mut copied_range = None;
+    for (idx, alternative) in calc.alternatives.iter().enumerate() {
+        if idx > 0 {
+            breakdown.push_str("  ·  ");
+        }
+        if calc.format_index == idx + 1 {
+            copied_range = Some(breakdown.len()..breakdown.len() + alternative.len());
+        }
+        breakdown.push_str(alternative);
+    }
+    let highlight = HighlightStyle {
+        font_weight: Some(FontWeight::BOLD),
+        ..Default::default()
+    };
+    let breakdown = StyledText::new(SharedString::from(breakdown))
+        .with_highlights(copied_range.map(|range| (range, highlight)));
+
+    content.child(
+        div()
+            .w_full()
+            .text_size(theme.item_description_font_size)
+            .h(theme.layout.item_description_height)
+            .text_color(theme.item_description_color)
+            .whitespace_nowrap()
+            .overflow_hidden()
+            .text_ellipsis()
+            .child(breakdown),
+    )
 }
 
 /// Render a search item.
@@ -219,6 +358,46 @@ fn render_search(search: &crate::items::SearchItem, selected: bool, row: usize)
     item
 }
 
+/// Render a text snippet, its first line below the name.
+fn render_snippet(
+    snippet: &crate::items::SnippetItem,
+    selected: bool,
+    row: usize,
+    matches: &[usize],
+) -> Stateful<Div> {
+    let mut item = item_container(row, selected)
+        .child(render_phosphor_icon(Some(snippet.icon())))
+        .child(render_matched_text_content(
+            &snippet.name,
+            matches,
+            snippet.description(),
+            selected,
+        ));
+
+    if selected {
+        item = item.child(render_action_indicator("Copy"));
+    }
+
+    item
+}
+
+/// Render a file search result.
+fn render_file(file: &crate::items::FileItem, selected: bool, row: usize) -> Stateful<Div> {
+    let mut item = item_container(row, selected)
+        .child(render_phosphor_icon(Some(file.icon())))
+        .child(render_text_content(
+            &file.name,
+            Some(&file.description),
+            selected,
+        ));
+
+    if selected {
+        item = item.child(render_action_indicator("Open"));
+    }
+
+    item
+}
+
 /// Render an AI item.
 fn render_ai(ai: &crate::items::AiItem, selected: bool, row: usize) -> Stateful<Div> {
     let mut item = item_container(row, selected)
@@ -232,6 +411,47 @@ fn render_ai(ai: &crate::items::AiItem, selected: bool, row: usize) -> Stateful<
     item
 }
 
+/// Render the raw metadata of an item for the inspector panel.
+pub fn render_inspector(item: Option<&ListItem>) -> Stateful<Div> {
+    let theme = theme();
+    let panel = div()
+        .id("item-inspector")
+        .size_full()
+        .p(theme.item_padding_x)
+        .flex()
+        .flex_col()
+        .gap_2()
+        .overflow_y_scroll();
+
+    let Some(item) = item else {
+        return panel.items_center().justify_center().child(
+            div()
+                .text_sm()
+                .text_color(theme.empty_state_color)
+                .child("No item selected"),
+        );
+    };
+
+    panel.children(item.metadata().into_iter().map(|(label, value)| {
+        div()
+            .flex()
+            .flex_col()
+            .child(
+                div()
+                    .text_size(theme.item_description_font_size)
+                    .text_color(theme.item_description_color)
+                    .child(SharedString::from(label)),
+            )
+            .child(
+                div()
+                    .text_size(theme.item_description_font_size)
+                    .text_color(theme.item_title_color)
+                    .font_family(theme.markdown.code_font_family)
+                    .child(SharedString::from(value)),
+            )
+    }))
+}
+
 /// Create the base container for a list item with selection styling.
 pub fn item_container(row: usize, selected: bool) -> Stateful<Div> {
     let theme = theme();
@@ -258,8 +478,11 @@ pub fn item_container(row: usize, selected: bool) -> Stateful<Div> {
         .gap_2()
 }
 
-/// Render an icon from a file path, with fallback placeholder.
-pub fn render_icon(icon_path: Option<&PathBuf>) -> Div {
+/// Render an icon from a file path.
+///
+/// The `fallback` icon is shown when there's no path or the file can't be
+/// loaded.
+pub fn render_icon(icon_path: Option<&PathBuf>, fallback: PhosphorIcon) -> Div {
     let theme = theme();
     let size = theme.icon_size;
 
@@ -272,22 +495,38 @@ pub fn render_icon(icon_path: Option<&PathBuf>) -> Div {
         .justify_center();
 
     if let Some(path) = icon_path {
-        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-        if matches!(ext, "png" | "jpg" | "jpeg" | "svg") {
-            return icon_container.child(img(path.clone()).w(size).h(size).rounded_sm());
+        match cached_icon(path) {
+            Some(CachedIcon::Loaded(image)) => {
+                return icon_container.child(
+                    img(image)
+                        .w(size)
+                        .h(size)
+                        .rounded_sm()
+                        .with_fallback(move || {
+                            render_phosphor_icon(Some(fallback)).into_any_element()
+                        }),
+                );
+            }
+            Some(CachedIcon::Failed) => {}
+            // Not loaded by the background prewarm yet, let gpui load the file
+            None => {
+                let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+                if matches!(ext, "png" | "jpg" | "jpeg" | "svg") {
+                    return icon_container.child(
+                        img(path.clone())
+                            .w(size)
+                            .h(size)
+                            .rounded_sm()
+                            .with_fallback(move || {
+                                render_phosphor_icon(Some(fallback)).into_any_element()
+                            }),
+                    );
+                }
+            }
         }
     }
 
-    // Fallback: show a subtle placeholder
-    icon_container
-        .bg(theme.icon_placeholder_background)
-        .rounded_sm()
-        .child(
-            div()
-                .text_sm()
-                .text_color(theme.icon_placeholder_color)
-                .child(SharedString::from("?")),
-        )
+    render_phosphor_icon(Some(fallback))
 }
 
 /// Render a Phosphor icon from embedded SVG assets.
@@ -325,17 +564,61 @@ pub fn render_phosphor_icon(icon: Option<PhosphorIcon>) -> Div {
 
 /// Render the text content (title and optional description).
 pub fn render_text_content(name: &str, description: Option<&str>, selected: bool) -> Div {
+    render_matched_text_content(name, &[], description, selected)
+}
+
+/// Render the text content with the matched characters of the title in bold.
+fn render_matched_text_content(
+    name: &str,
+    matches: &[usize],
+    description: Option<&str>,
+    selected: bool,
+) -> Div {
+    build_text_content(name, matches, description.map(render_description), selected)
+}
+
+/// Render the description line below an item title.
+fn render_description(description: &str) -> Div {
     let theme = theme();
+    div()
+        .w_full()
+        .text_size(theme.item_description_font_size)
+        .h(theme.layout.item_description_height) // Fixed height to fit descenders
+        .text_color(theme.item_description_color)
+        .whitespace_nowrap()
+        .overflow_hidden()
+        .text_ellipsis()
+        .child(SharedString::from(description.to_string()))
+}
+
+/// Build the title with its matched characters in bold above a description.
+fn build_text_content(
+    name: &str,
+    matches: &[usize],
+    description: Option<Div>,
+    selected: bool,
+) -> Div {
+    let theme = theme();
+
+    let highlight = HighlightStyle {
+        font_weight: Some(FontWeight::BOLD),
+        ..Default::default()
+    };
+    let name_text = StyledText::new(SharedString::from(name.to_string())).with_highlights(
+        char_indices_to_ranges(name, matches)
+            .into_iter()
+            .map(|range| (range, highlight)),
+    );
 
     let name_element = div()
         .w_full()
-        .text_sm()
+        .text_size(theme.item_title_font_size)
         .line_height(theme.item_title_line_height)
         .text_color(theme.item_title_color)
         .whitespace_nowrap()
         .overflow_hidden()
         .text_ellipsis()
-        .child(SharedString::from(name.to_string()));
+        .child(name_text);
 
     let max_width = theme.max_text_width(px(crate::config::window_width()), selected);
 
@@ -349,23 +632,31 @@ pub fn render_text_content(name: &str, description: Option<&str>, selected: bool
 
     content = content.child(name_element);
 
-    if let Some(desc) = description {
-        let description_element = div()
-            .w_full()
-            .text_xs()
-            .h(theme.layout.item_description_height) // Fixed height to fit descenders
-            .text_color(theme.item_description_color)
-            .whitespace_nowrap()
-            .overflow_hidden()
-            .text_ellipsis()
-            .child(SharedString::from(desc.to_string()));
-
+    if let Some(description_element) = description {
         content = content.child(description_element);
     }
 
     content
 }
 
+/// Render the small pin badge overlaid on the icon of pinned items.
+pub fn render_pin_badge() -> Div {
+    let theme = theme();
+    div()
+        .absolute()
+        .top(px(-4.0))
+        .right(px(-4.0))
+        .p(px(1.0))
+        .rounded_full()
+        .bg(theme.item_background_selected)
+        .child(
+            svg()
+                .path(PhosphorIcon::PushPin.path())
+                .size_3()
+                .text_color(theme.item_title_color),
+        )
+}
+
 /// Render the action indicator shown on selected items.
 pub fn render_action_indicator(label: &str) -> Div {
     let theme = theme();
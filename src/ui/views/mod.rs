@@ -13,7 +13,7 @@ pub use ai_view::AiResponseView;
 pub use clipboard_rendering::render_clipboard_item;
 pub use emoji_rendering::{render_emoji_cell, render_emoji_row};
 pub use item_rendering::{
-    item_container, render_action_indicator, render_icon, render_item, render_phosphor_icon,
-    render_text_content,
+    item_container, render_action_indicator, render_grid_item, render_icon, render_inspector,
+    render_item, render_phosphor_icon, render_pin_badge, render_text_content,
 };
 pub use theme_rendering::render_theme_item;
@@ -0,0 +1,3 @@
+//! Helpers shared across modules.
+
+pub mod state_file;
@@ -0,0 +1,116 @@
+//! JSON state files in the data directory (`~/.local/share/zlaunch`).
+//!
+//! State is only readable by the user (the directory is 0700, files 0600)
+//! and written through a temporary file, so a crash never leaves a
+//! truncated file behind.
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs::{DirBuilder, OpenOptions, Permissions};
+use std::io::Write;
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+
+/// Get the directory state is persisted in.
+pub fn data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|p| p.join("zlaunch"))
+}
+
+/// Load a state file from the data directory.
+///
+/// `what` describes the state in log messages. A missing or corrupt file
+/// results in the default value.
+pub fn load<T: DeserializeOwned + Default>(file: &str, what: &str) -> T {
+    match data_dir() {
+        Some(dir) => load_from(&dir, file, what),
+        None => T::default(),
+    }
+}
+
+/// Write a state file to the data directory.
+pub fn save<T: Serialize + ?Sized>(file: &str, value: &T) -> anyhow::Result<()> {
+    match data_dir() {
+        Some(dir) => save_to(&dir, file, value),
+        None => Ok(()),
+    }
+}
+
+/// Load a state file from the given directory.
+pub fn load_from<T: DeserializeOwned + Default>(dir: &Path, file: &str, what: &str) -> T {
+    let path = dir.join(file);
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) => {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                tracing::warn!(%e, "Failed to read {} at {:?}", what, path);
+            }
+            return T::default();
+        }
+    };
+
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        tracing::warn!(%e, "Corrupt {} at {:?}, starting empty", what, path);
+        T::default()
+    })
+}
+
+/// Write a state file to the given directory.
+pub fn save_to<T: Serialize + ?Sized>(dir: &Path, file: &str, value: &T) -> anyhow::Result<()> {
+    create_private_dir(dir)?;
+    let path = dir.join(file);
+    let tmp_path = dir.join(format!("{}.tmp", file));
+    write_private_file(&tmp_path, serde_json::to_string(value)?.as_bytes())?;
+    std::fs::rename(&tmp_path, &path)?;
+    Ok(())
+}
+
+/// Create a directory only the user can access (0700), tightening it if it
+/// already exists.
+pub fn create_private_dir(dir: &Path) -> std::io::Result<()> {
+    DirBuilder::new().recursive(true).mode(0o700).create(dir)?;
+    std::fs::set_permissions(dir, Permissions::from_mode(0o700))
+}
+
+/// Write a file only the user can read (0600).
+pub fn write_private_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    // The mode only applies to new files
+    file.set_permissions(Permissions::from_mode(0o600))?;
+    file.write_all(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_round_trip() {
+        let dir =
+            std::env::temp_dir().join(format!("zlaunch-test-state-file-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let state = HashMap::from([("firefox.desktop".to_string(), 3u32)]);
+        save_to(&dir, "state.json", &state).unwrap();
+        assert_eq!(
+            load_from::<HashMap<String, u32>>(&dir, "state.json", "state"),
+            state
+        );
+
+        let mode = |path: &Path| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&dir), 0o700);
+        assert_eq!(mode(&dir.join("state.json")), 0o600);
+        assert!(!dir.join("state.json.tmp").exists());
+
+        std::fs::write(dir.join("state.json"), "{ not json").unwrap();
+        assert!(load_from::<HashMap<String, u32>>(&dir, "state.json", "state").is_empty());
+        assert!(load_from::<Vec<String>>(&dir, "missing.json", "state").is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}